@@ -0,0 +1,222 @@
+//! Compares the rotations of the element-storage backends supported by the crate.
+//!
+//! Each backend holds the same values and is rotated with the same random triples, so the
+//! measurements only differ in the cost of accessing the elements through the backend.
+//! Backends of optional dependencies are included when their features are enabled, e.g.
+//! with `cargo bench --bench backends --features
+//! unsafe,indexmap,slab,tinyvec,typed-index-collections`.
+
+use criterion::measurement::WallTime;
+use criterion::{
+    black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion, Throughput,
+};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::{get_indexes, get_values};
+
+/// The number of values in each backend.
+const LEN: usize = 100;
+
+/// The number of rotations per iteration.
+const ROTATIONS: usize = 100;
+
+/// Benchmarks the left and right rotations of a backend in a group named after it.
+///
+/// The backend is created by `setup` from the values of [`get_values`] and then rotated
+/// with the triples of [`get_indexes`].
+fn bench_backend<S>(
+    c: &mut Criterion,
+    name: &str,
+    setup: impl Fn(Vec<u64>) -> S,
+    bca: impl Fn(&mut S, usize, usize, usize),
+    cab: impl Fn(&mut S, usize, usize, usize),
+) {
+    let indexes = get_indexes(42, LEN, ROTATIONS);
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Elements(indexes.len() as u64));
+    bench_direction(&mut group, "bca", &indexes, &setup, bca);
+    bench_direction(&mut group, "cab", &indexes, &setup, cab);
+    group.finish();
+}
+
+fn bench_direction<S>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    direction: &str,
+    indexes: &[(usize, usize, usize)],
+    setup: impl Fn(Vec<u64>) -> S,
+    rotate: impl Fn(&mut S, usize, usize, usize),
+) {
+    group.bench_function(direction, |bencher| {
+        let mut backend = black_box(setup(get_values(LEN)));
+        bencher.iter(|| {
+            for &(a, b, c) in indexes {
+                rotate(&mut backend, a, b, c)
+            }
+        })
+    });
+}
+
+pub fn std_backends(c: &mut Criterion) {
+    bench_backend(
+        c,
+        "vec",
+        |values| values,
+        |vec, a, b, c| swap3::swap3_bca_slice(vec, a, b, c),
+        |vec, a, b, c| swap3::swap3_cab_slice(vec, a, b, c),
+    );
+
+    bench_backend(
+        c,
+        "array",
+        |values| <[u64; LEN]>::try_from(values).unwrap(),
+        |array, a, b, c| swap3::swap3_bca_slice(array, a, b, c),
+        |array, a, b, c| swap3::swap3_cab_slice(array, a, b, c),
+    );
+
+    bench_backend(
+        c,
+        "circular",
+        |values| <[u64; LEN]>::try_from(values).unwrap(),
+        |array, a, b, c| swap3::swap3_bca_circular(array, LEN / 2, a, b, c),
+        |array, a, b, c| swap3::swap3_cab_circular(array, LEN / 2, a, b, c),
+    );
+
+    bench_backend(
+        c,
+        "ring_buffer",
+        |values| {
+            // Wraps the logical positions around the end of the storage.
+            let mut ring = swap3::RingBuffer::<u64, LEN>::new();
+            for &value in &values[..LEN / 2] {
+                ring.push_back(value).unwrap();
+                ring.pop_front();
+            }
+            for value in values {
+                ring.push_back(value).unwrap();
+            }
+            ring
+        },
+        |ring, a, b, c| ring.rotate_left(a, b, c),
+        |ring, a, b, c| ring.rotate_right(a, b, c),
+    );
+
+    bench_backend(
+        c,
+        "vecdeque",
+        |values| {
+            // Wraps the elements around the end of the ring buffer.
+            let mut deque = VecDeque::with_capacity(LEN);
+            let (front, back) = values.split_at(LEN / 2);
+            deque.extend(back);
+            for &value in front.iter().rev() {
+                deque.push_front(value);
+            }
+            deque
+        },
+        swap3::swap3_bca_vecdeque,
+        swap3::swap3_cab_vecdeque,
+    );
+
+    bench_backend(
+        c,
+        "arc",
+        Arc::<[u64]>::from,
+        swap3::swap3_bca_arc,
+        swap3::swap3_cab_arc,
+    );
+
+    bench_backend(
+        c,
+        "btreemap",
+        |values| values.into_iter().enumerate().collect::<BTreeMap<_, _>>(),
+        |map, a, b, c| swap3::swap3_bca_btreemap(map, &a, &b, &c).unwrap(),
+        |map, a, b, c| swap3::swap3_cab_btreemap(map, &a, &b, &c).unwrap(),
+    );
+
+    bench_backend(
+        c,
+        "refcells",
+        |values| values.into_iter().map(RefCell::new).collect::<Vec<_>>(),
+        |cells, a, b, c| swap3::swap3_bca_refcells(&cells[a], &cells[b], &cells[c]),
+        |cells, a, b, c| swap3::swap3_cab_refcells(&cells[a], &cells[b], &cells[c]),
+    );
+
+    bench_backend(
+        c,
+        "mutexes",
+        |values| values.into_iter().map(Mutex::new).collect::<Vec<_>>(),
+        |mutexes, a, b, c| swap3::swap3_bca_mutexes(mutexes, a, b, c),
+        |mutexes, a, b, c| swap3::swap3_cab_mutexes(mutexes, a, b, c),
+    );
+}
+
+pub fn optional_backends(c: &mut Criterion) {
+    #[cfg(feature = "unsafe")]
+    bench_backend(
+        c,
+        "unsafecells",
+        |values| {
+            values
+                .into_iter()
+                .map(std::cell::UnsafeCell::new)
+                .collect::<Vec<_>>()
+        },
+        // SAFETY: The cells are distinct, and their contents are not borrowed or shared.
+        |cells, a, b, c| unsafe { swap3::swap3_bca_unsafecells(&cells[a], &cells[b], &cells[c]) },
+        // SAFETY: See above.
+        |cells, a, b, c| unsafe { swap3::swap3_cab_unsafecells(&cells[a], &cells[b], &cells[c]) },
+    );
+
+    #[cfg(feature = "indexmap")]
+    bench_backend(
+        c,
+        "indexmap",
+        |values| {
+            values
+                .into_iter()
+                .enumerate()
+                .collect::<indexmap::IndexMap<_, _>>()
+        },
+        swap3::swap3_bca_indexmap,
+        swap3::swap3_cab_indexmap,
+    );
+
+    #[cfg(feature = "slab")]
+    bench_backend(
+        c,
+        "slab",
+        |values| values.into_iter().enumerate().collect::<slab::Slab<_>>(),
+        |slab, a, b, c| swap3::swap3_bca_slab(slab, a, b, c).unwrap(),
+        |slab, a, b, c| swap3::swap3_cab_slab(slab, a, b, c).unwrap(),
+    );
+
+    #[cfg(feature = "tinyvec")]
+    bench_backend(
+        c,
+        "arrayvec",
+        |values| {
+            values
+                .into_iter()
+                .collect::<tinyvec::ArrayVec<[u64; LEN]>>()
+        },
+        swap3::swap3_bca_arrayvec,
+        swap3::swap3_cab_arrayvec,
+    );
+
+    #[cfg(feature = "typed-index-collections")]
+    bench_backend(
+        c,
+        "tivec",
+        typed_index_collections::TiVec::<usize, u64>::from,
+        swap3::swap3_bca_tivec,
+        swap3::swap3_cab_tivec,
+    );
+
+    let _ = c;
+}
+
+criterion_group!(benches, std_backends, optional_backends);
+criterion_main!(benches);
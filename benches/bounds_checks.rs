@@ -0,0 +1,56 @@
+//! Compares the safe, unsafe and unchecked rotations on identical inputs in order to
+//! attribute the cost of a rotation to the bounds and distinctness checks versus
+//! the swaps themselves.
+//!
+//! The indices are passed through [`black_box`] on every call so that the compiler cannot
+//! hoist the checks out of the loop or prove them redundant.
+
+use criterion::measurement::WallTime;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use swap3::slice;
+
+mod common;
+use common::{get_indexes, get_values};
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let indexes = get_indexes(42, 100, 100);
+    let mut group = c.benchmark_group("bounds_checks");
+
+    bench(&mut group, "bca_safe", &indexes, slice::bca_safe);
+    bench(&mut group, "bca_unsafe", &indexes, slice::bca_unsafe);
+    bench(&mut group, "bca_unchecked", &indexes, |data, a, b, c| {
+        // SAFETY: The indices are in bounds and pairwise distinct.
+        unsafe { slice::bca_unchecked(data, a, b, c) }
+    });
+
+    bench(&mut group, "cab_safe", &indexes, slice::cab_safe);
+    bench(&mut group, "cab_unsafe", &indexes, slice::cab_unsafe);
+    bench(&mut group, "cab_unchecked", &indexes, |data, a, b, c| {
+        // SAFETY: The indices are in bounds and pairwise distinct.
+        unsafe { slice::cab_unchecked(data, a, b, c) }
+    });
+
+    group.finish();
+}
+
+fn bench<F>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    indexes: &[(usize, usize, usize)],
+    rotate: F,
+) where
+    F: Fn(&mut [u64], usize, usize, usize),
+{
+    group.bench_function(name, |bencher| {
+        let mut values = get_values(100);
+        bencher.iter(|| {
+            for &indexes in indexes {
+                let (a, b, c) = black_box(indexes);
+                rotate(&mut values, a, b, c)
+            }
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+//! Input generators shared by the benchmarks.
+
+// Not every benchmark uses every generator.
+#![allow(dead_code)]
+
+use rand::prelude::*;
+
+/// Returns `len` distinct values.
+pub fn get_values(len: usize) -> Vec<u64> {
+    (0..len as u64).map(|v| v + 1000).collect()
+}
+
+/// Returns `count` random triples of pairwise distinct indices below `len`.
+pub fn get_indexes(seed: u64, len: usize, count: usize) -> Vec<(usize, usize, usize)> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count)
+        .map(|_| {
+            let a = rng.gen_range(0..len);
+            let mut b = rng.gen_range(0..len);
+            while b == a {
+                b = rng.gen_range(0..len);
+            }
+            let mut c = rng.gen_range(0..len);
+            while c == a || c == b {
+                c = rng.gen_range(0..len);
+            }
+            (a, b, c)
+        })
+        .collect()
+}
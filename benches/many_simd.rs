@@ -0,0 +1,65 @@
+//! Compares the scalar and SIMD gather/scatter bulk rotations on identical inputs.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::prelude::*;
+
+mod common;
+use common::get_values;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let disjoint = get_disjoint_indexes(42);
+    let random = get_random_indexes(42);
+    let mut group = c.benchmark_group("many_simd");
+
+    for (suffix, indexes) in [("disjoint", &disjoint), ("random", &random)] {
+        group.bench_function(format!("bca_scalar_{suffix}"), |b| {
+            let mut values = get_values(1200);
+            b.iter(|| swap3::swap3_bca_slice_many(&mut values, black_box(indexes)))
+        });
+        group.bench_function(format!("bca_simd_{suffix}"), |b| {
+            let mut values = get_values(1200);
+            b.iter(|| swap3::swap3_bca_slice_many_simd(&mut values, black_box(indexes)))
+        });
+        group.bench_function(format!("cab_scalar_{suffix}"), |b| {
+            let mut values = get_values(1200);
+            b.iter(|| swap3::swap3_cab_slice_many(&mut values, black_box(indexes)))
+        });
+        group.bench_function(format!("cab_simd_{suffix}"), |b| {
+            let mut values = get_values(1200);
+            b.iter(|| swap3::swap3_cab_slice_many_simd(&mut values, black_box(indexes)))
+        });
+    }
+
+    group.finish();
+}
+
+/// Batches of four triples never share an index, so every batch takes the SIMD path.
+fn get_disjoint_indexes(seed: u64) -> Vec<(usize, usize, usize)> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut indexes = Vec::new();
+    for _ in 0..100 {
+        let mut positions: Vec<usize> = (0..1200).collect();
+        positions.shuffle(&mut rng);
+        indexes.extend(
+            positions
+                .chunks_exact(3)
+                .take(4)
+                .map(|t| (t[0], t[1], t[2])),
+        );
+    }
+    indexes
+}
+
+/// Random triples, where batches occasionally share an index and fall back to scalar.
+fn get_random_indexes(seed: u64) -> Vec<(usize, usize, usize)> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..400)
+        .map(|_| {
+            let t: Vec<usize> = (0..1200).choose_multiple(&mut rng, 3);
+            (t[0], t[1], t[2])
+        })
+        .collect()
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+//! Compares the plain and prefetching rotations on a buffer much larger than the caches,
+//! using random triples of far-apart indices so that nearly every access misses the cache.
+//!
+//! Throughput is reported in rotations per second, i.e. one element corresponds to one
+//! rotated triple of values.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+mod common;
+use common::{get_indexes, get_values};
+
+/// The number of values in the benchmarked slice, i.e. 256 MiB of `u64` values.
+const LEN: usize = 32 << 20;
+
+/// The number of rotations per iteration, touching more cache lines than the caches hold.
+const ROTATIONS: usize = 1 << 20;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let indexes = get_indexes(42, LEN, ROTATIONS);
+    let mut values = get_values(LEN);
+    let mut group = c.benchmark_group("prefetch");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(indexes.len() as u64));
+
+    group.bench_function("bca_plain", |bencher| {
+        bencher.iter(|| {
+            for &indexes in &indexes {
+                let (a, b, c) = black_box(indexes);
+                swap3::swap3_bca_slice(&mut values, a, b, c)
+            }
+        })
+    });
+
+    group.bench_function("bca_prefetch", |bencher| {
+        bencher.iter(|| {
+            for &indexes in &indexes {
+                let (a, b, c) = black_box(indexes);
+                swap3::swap3_bca_slice_prefetch(&mut values, a, b, c)
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -1,12 +1,27 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use rand::prelude::*;
+//! Benchmarks of the slice rotations.
+//!
+//! Throughput is reported in rotations per second, i.e. one element corresponds to one
+//! rotated triple of values.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use swap3::slice;
 
-pub fn criterion_benchmark(c: &mut Criterion) {
-    let indexes = get_indexes(42);
+mod common;
+use common::{get_indexes, get_values};
+
+/// The number of values in the benchmarked slices.
+const LEN: usize = 100;
+
+/// The batch sizes for the bulk rotations.
+const BATCH_SIZES: [usize; 4] = [1, 16, 256, 4096];
+
+pub fn single_benchmark(c: &mut Criterion) {
+    let indexes = get_indexes(42, LEN, 100);
+    let mut group = c.benchmark_group("single");
+    group.throughput(Throughput::Elements(indexes.len() as u64));
 
-    c.bench_function("bca_safe", |bencher| {
-        let mut values = black_box(get_values());
+    group.bench_function("bca_safe", |bencher| {
+        let mut values = black_box(get_values(LEN));
         bencher.iter(|| {
             for (a, b, c) in indexes.iter().cloned() {
                 slice::bca_safe(&mut values, a, b, c)
@@ -15,8 +30,8 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     });
 
     #[cfg(feature = "unsafe")]
-    c.bench_function("bca_unsafe", |bencher| {
-        let mut values = black_box(get_values());
+    group.bench_function("bca_unsafe", |bencher| {
+        let mut values = black_box(get_values(LEN));
         bencher.iter(|| {
             for (a, b, c) in indexes.iter().cloned() {
                 slice::bca_unsafe(&mut values, a, b, c)
@@ -24,8 +39,18 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         })
     });
 
-    c.bench_function("cab_safe", |bencher| {
-        let mut values = black_box(get_values());
+    #[cfg(feature = "unsafe")]
+    group.bench_function("bca_single_temp", |bencher| {
+        let mut values = black_box(get_values(LEN));
+        bencher.iter(|| {
+            for (a, b, c) in indexes.iter().cloned() {
+                slice::bca_single_temp(&mut values, a, b, c)
+            }
+        })
+    });
+
+    group.bench_function("cab_safe", |bencher| {
+        let mut values = black_box(get_values(LEN));
         bencher.iter(|| {
             for (a, b, c) in indexes.iter().cloned() {
                 slice::cab_safe(&mut values, a, b, c)
@@ -34,37 +59,56 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     });
 
     #[cfg(feature = "unsafe")]
-    c.bench_function("cab_unsafe", |bencher| {
-        let mut values = black_box(get_values());
+    group.bench_function("cab_unsafe", |bencher| {
+        let mut values = black_box(get_values(LEN));
         bencher.iter(|| {
             for (a, b, c) in indexes.iter().cloned() {
                 slice::cab_unsafe(&mut values, a, b, c)
             }
         })
     });
-}
-
-fn get_values() -> Vec<u64> {
-    (0..100).map(|v| v + 1000).collect()
-}
 
-fn get_indexes(seed: u64) -> Vec<(usize, usize, usize)> {
-    let mut rng = StdRng::seed_from_u64(seed);
-    (0..100)
-        .map(|_| {
-            let a = rng.gen_range(0..100);
-            let mut b = rng.gen_range(0..100);
-            while b == a {
-                b = rng.gen_range(0..100);
-            }
-            let mut c = rng.gen_range(0..100);
-            while c == a || c == b {
-                c = rng.gen_range(0..100);
+    #[cfg(feature = "unsafe")]
+    group.bench_function("cab_single_temp", |bencher| {
+        let mut values = black_box(get_values(LEN));
+        bencher.iter(|| {
+            for (a, b, c) in indexes.iter().cloned() {
+                slice::cab_single_temp(&mut values, a, b, c)
             }
-            (a, b, c)
         })
-        .collect()
+    });
+
+    group.finish();
+}
+
+pub fn many_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("many");
+
+    for batch_size in BATCH_SIZES {
+        let indexes = get_indexes(42, LEN, batch_size);
+        group.throughput(Throughput::Elements(batch_size as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("bca", batch_size),
+            &indexes,
+            |bencher, indexes| {
+                let mut values = black_box(get_values(LEN));
+                bencher.iter(|| swap3::swap3_bca_slice_many(&mut values, indexes))
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("cab", batch_size),
+            &indexes,
+            |bencher, indexes| {
+                let mut values = black_box(get_values(LEN));
+                bencher.iter(|| swap3::swap3_cab_slice_many(&mut values, indexes))
+            },
+        );
+    }
+
+    group.finish();
 }
 
-criterion_group!(benches, criterion_benchmark);
+criterion_group!(benches, single_benchmark, many_benchmark);
 criterion_main!(benches);
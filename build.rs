@@ -0,0 +1,31 @@
+use std::env;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(has_core_error)");
+    println!("cargo:rustc-check-cfg=cfg(has_get_disjoint_mut)");
+    println!("cargo:rustc-check-cfg=cfg(kani)");
+    println!("cargo:rustc-check-cfg=cfg(loom)");
+
+    let minor = rustc_minor_version();
+
+    // `core::error::Error` was stabilized in Rust 1.81.
+    if minor.is_some_and(|minor| minor >= 81) {
+        println!("cargo:rustc-cfg=has_core_error");
+    }
+
+    // `<[T]>::get_disjoint_mut` was stabilized in Rust 1.86.
+    if minor.is_some_and(|minor| minor >= 86) {
+        println!("cargo:rustc-cfg=has_get_disjoint_mut");
+    }
+}
+
+/// Determines the minor version of the compiler, e.g. `86` for Rust 1.86.0.
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    let mut parts = version.strip_prefix("rustc 1.")?.split('.');
+    parts.next()?.parse().ok()
+}
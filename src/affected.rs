@@ -0,0 +1,214 @@
+/// Rotates three values to the left and returns the positions whose values changed.
+///
+/// Unlike [`swap3_bca_slice`](crate::swap3_bca_slice), the indices may coincide. The rotation
+/// is performed as the swaps of `a` with `b` and then `b` with `c`, which moves values without
+/// cloning them. Writing `x`, `y` and `z` for the original values, this results in:
+///
+/// | Indices       | `data[a]` | `data[b]` | `data[c]` | Affected positions |
+/// |---------------|-----------|-----------|-----------|--------------------|
+/// | all distinct  | `y`       | `z`       | `x`       | `a`, `b`, `c`      |
+/// | `a == b`      | `z`       | `z`       | `x`       | `a`, `c`           |
+/// | `b == c`      | `y`       | `x`       | `x`       | `a`, `b`           |
+/// | `a == c`      | `x`       | `y`       | `x`       | none               |
+/// | all equal     | `x`       | `x`       | `x`       | none               |
+///
+/// Each affected position is yielded once, in the order of the arguments. Positions that are
+/// rotated back to their original value, as for `a == c`, are not affected.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Returns
+///
+/// An iterator over the positions that were assigned the value of another position.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20, 30, 40];
+/// let affected: Vec<_> = swap3::swap3_bca_slice_affected(&mut vec, 3, 0, 1).collect();
+/// assert_eq!(vec, &[20, 40, 30, 10]);
+/// assert_eq!(affected, [3, 0, 1]);
+///
+/// let affected: Vec<_> = swap3::swap3_bca_slice_affected(&mut vec, 2, 2, 0).collect();
+/// assert_eq!(vec, &[30, 40, 20, 10]);
+/// assert_eq!(affected, [2, 0]);
+/// ```
+pub fn swap3_bca_slice_affected<T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+) -> impl Iterator<Item = usize> {
+    swap_tracked(data, [a, b, c], [(a, b), (b, c)])
+}
+
+/// Rotates three values to the right and returns the positions whose values changed.
+///
+/// Unlike [`swap3_cab_slice`](crate::swap3_cab_slice), the indices may coincide. The rotation
+/// is performed as the swaps of `b` with `c` and then `a` with `b`, which moves values without
+/// cloning them. Writing `x`, `y` and `z` for the original values, this results in:
+///
+/// | Indices       | `data[a]` | `data[b]` | `data[c]` | Affected positions |
+/// |---------------|-----------|-----------|-----------|--------------------|
+/// | all distinct  | `z`       | `x`       | `y`       | `a`, `b`, `c`      |
+/// | `a == b`      | `z`       | `z`       | `x`       | `a`, `c`           |
+/// | `b == c`      | `y`       | `x`       | `x`       | `a`, `b`           |
+/// | `a == c`      | `x`       | `y`       | `x`       | none               |
+/// | all equal     | `x`       | `x`       | `x`       | none               |
+///
+/// Each affected position is yielded once, in the order of the arguments. Positions that are
+/// rotated back to their original value, as for `a == c`, are not affected.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Returns
+///
+/// An iterator over the positions that were assigned the value of another position.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20, 30, 40];
+/// let affected: Vec<_> = swap3::swap3_cab_slice_affected(&mut vec, 3, 0, 1).collect();
+/// assert_eq!(vec, &[40, 10, 30, 20]);
+/// assert_eq!(affected, [3, 0, 1]);
+/// ```
+pub fn swap3_cab_slice_affected<T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+) -> impl Iterator<Item = usize> {
+    swap_tracked(data, [a, b, c], [(b, c), (a, b)])
+}
+
+/// Applies the swaps while tracking the original position of the value at each index, and
+/// returns the distinct indices holding a value from elsewhere.
+fn swap_tracked<T>(
+    data: &mut [T],
+    indices: [usize; 3],
+    swaps: [(usize, usize); 2],
+) -> impl Iterator<Item = usize> {
+    let len = data.len();
+    if indices.iter().any(|&index| index >= len) {
+        let [a, b, c] = indices;
+        crate::disjoint::invalid_indices(len, a, b, c);
+    }
+
+    // `origins[k]` is the original position of the value now at `indices[k]`. Coinciding
+    // indices share a position and hence always agree on its origin.
+    let mut origins = indices;
+    let origin_at = |origins: &[usize; 3], index: usize| {
+        let k = indices.iter().position(|&i| i == index);
+        origins[k.expect("swaps only involve tracked indices")]
+    };
+    for (i, j) in swaps {
+        data.swap(i, j);
+        let (from_i, from_j) = (origin_at(&origins, i), origin_at(&origins, j));
+        for (index, origin) in indices.iter().zip(&mut origins) {
+            if *index == i {
+                *origin = from_j;
+            } else if *index == j {
+                *origin = from_i;
+            }
+        }
+    }
+
+    let mut affected = [None; 3];
+    for k in 0..3 {
+        if origins[k] != indices[k] && !indices[..k].contains(&indices[k]) {
+            affected[k] = Some(indices[k]);
+        }
+    }
+    affected.into_iter().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rotate_bca(indices: [usize; 3]) -> ([char; 3], [Option<usize>; 3]) {
+        let mut data = ['x', 'y', 'z'];
+        let [a, b, c] = indices;
+        let mut affected = [None; 3];
+        for (slot, position) in affected
+            .iter_mut()
+            .zip(swap3_bca_slice_affected(&mut data, a, b, c))
+        {
+            *slot = Some(position);
+        }
+        (data, affected)
+    }
+
+    #[test]
+    fn test_distinct() {
+        assert_eq!(
+            rotate_bca([0, 1, 2]),
+            (['y', 'z', 'x'], [Some(0), Some(1), Some(2)])
+        );
+        let mut data = [1, 2, 3, 4, 5];
+        let mut affected = swap3_cab_slice_affected(&mut data, 4, 2, 0);
+        assert_eq!(
+            (affected.next(), affected.next(), affected.next()),
+            (Some(4), Some(2), Some(0))
+        );
+        assert_eq!(affected.next(), None);
+        assert_eq!(data, [3, 2, 5, 4, 1]);
+    }
+
+    #[test]
+    fn test_coinciding() {
+        // `a == b`: the values at `a` and `c` are swapped.
+        assert_eq!(
+            rotate_bca([1, 1, 2]),
+            (['x', 'z', 'y'], [Some(1), Some(2), None])
+        );
+        // `b == c`: the values at `a` and `b` are swapped.
+        assert_eq!(
+            rotate_bca([0, 2, 2]),
+            (['z', 'y', 'x'], [Some(0), Some(2), None])
+        );
+        // `a == c`: the swaps cancel out.
+        assert_eq!(rotate_bca([0, 1, 0]), (['x', 'y', 'z'], [None; 3]));
+        assert_eq!(rotate_bca([1, 1, 1]), (['x', 'y', 'z'], [None; 3]));
+    }
+
+    #[test]
+    fn test_matches_table() {
+        // The results for coinciding indices are the same in both directions.
+        for indices in [[1, 1, 2], [0, 2, 2], [0, 1, 0], [2, 2, 2]] {
+            let mut bca = ['x', 'y', 'z'];
+            let mut cab = bca;
+            let [a, b, c] = indices;
+            let left =
+                swap3_bca_slice_affected(&mut bca, a, b, c).fold(0, |acc, i| acc * 10 + i + 1);
+            let right =
+                swap3_cab_slice_affected(&mut cab, a, b, c).fold(0, |acc, i| acc * 10 + i + 1);
+            assert_eq!((bca, left), (cab, right), "{indices:?}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_out_of_bounds() {
+        let _ = swap3_bca_slice_affected(&mut [1, 2, 3], 3, 3, 0);
+    }
+}
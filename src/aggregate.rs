@@ -0,0 +1,140 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values to the left and returns the change of a position-weighted sum.
+///
+/// For the sum `Σ weight(i) * data[i]` over all indices, only the terms of the three rotated
+/// positions change. The returned delta is computed from these alone, so a maintained sum can
+/// be updated by adding it without recomputing the sum. `weight` is called once for each of
+/// the three indices.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `weight` - The weight of each index.
+///
+/// ## Returns
+///
+/// The change of the weighted sum caused by the rotation, subject to floating-point rounding.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `weight` is not called in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![1.0, 2.0, 3.0, 4.0];
+/// let weight = |i: usize| i as f64;
+/// let mut sum: f64 = vec.iter().enumerate().map(|(i, x)| weight(i) * x).sum();
+///
+/// sum += swap3::swap3_bca_slice_agg(&mut vec, 0, 1, 3, weight);
+/// assert_eq!(vec, &[2.0, 4.0, 3.0, 1.0]);
+/// assert_eq!(sum, 4.0 + 6.0 + 3.0);
+/// ```
+pub fn swap3_bca_slice_agg<T, W>(data: &mut [T], a: usize, b: usize, c: usize, weight: W) -> f64
+where
+    T: Copy + Into<f64>,
+    W: Fn(usize) -> f64,
+{
+    swap3_bca_slice(data, a, b, c);
+    delta(data, [a, b, c], [2, 0, 1], weight)
+}
+
+/// Rotates three values to the right and returns the change of a position-weighted sum.
+///
+/// For the sum `Σ weight(i) * data[i]` over all indices, only the terms of the three rotated
+/// positions change. The returned delta is computed from these alone, so a maintained sum can
+/// be updated by adding it without recomputing the sum. `weight` is called once for each of
+/// the three indices.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `weight` - The weight of each index.
+///
+/// ## Returns
+///
+/// The change of the weighted sum caused by the rotation, subject to floating-point rounding.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `weight` is not called in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![1u8, 2, 3, 4];
+/// let delta = swap3::swap3_cab_slice_agg(&mut vec, 0, 1, 3, |i| i as f64);
+/// assert_eq!(vec, &[4, 1, 3, 2]);
+/// assert_eq!(delta, (1.0 + 3.0 * 2.0) - (2.0 + 3.0 * 4.0));
+/// ```
+pub fn swap3_cab_slice_agg<T, W>(data: &mut [T], a: usize, b: usize, c: usize, weight: W) -> f64
+where
+    T: Copy + Into<f64>,
+    W: Fn(usize) -> f64,
+{
+    swap3_cab_slice(data, a, b, c);
+    delta(data, [a, b, c], [1, 2, 0], weight)
+}
+
+/// Computes the change of the weighted sum from the rotated values.
+///
+/// As the rotation only permutes the values, the value formerly at `indices[i]` is the one
+/// now at `indices[previous[i]]`.
+fn delta<T, W>(data: &[T], indices: [usize; 3], previous: [usize; 3], weight: W) -> f64
+where
+    T: Copy + Into<f64>,
+    W: Fn(usize) -> f64,
+{
+    let values: [f64; 3] = indices.map(|i| data[i].into());
+    (0..3)
+        .map(|i| weight(indices[i]) * (values[i] - values[previous[i]]))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weighted_sum(data: &[i32], weight: impl Fn(usize) -> f64) -> f64 {
+        data.iter()
+            .enumerate()
+            .map(|(i, &x)| weight(i) * f64::from(x))
+            .sum()
+    }
+
+    #[test]
+    fn test_delta_matches_recomputation() {
+        let weight = |i: usize| (i * i) as f64 + 0.5;
+        let mut data = [3, -1, 4, 1, -5, 9, 2];
+        let mut sum = weighted_sum(&data, weight);
+        for (a, b, c) in [(0, 3, 6), (5, 1, 2), (4, 0, 3), (6, 5, 4)] {
+            sum += swap3_bca_slice_agg(&mut data, a, b, c, weight);
+            assert_eq!(sum, weighted_sum(&data, weight));
+            sum += swap3_cab_slice_agg(&mut data, b, a, c, weight);
+            assert_eq!(sum, weighted_sum(&data, weight));
+        }
+    }
+
+    #[test]
+    fn test_uniform_weights_are_unaffected() {
+        let mut data = [1.5f32, -2.25, 8.0, 0.125];
+        assert_eq!(swap3_bca_slice_agg(&mut data, 0, 1, 2, |_| 1.0), 0.0);
+        assert_eq!(swap3_cab_slice_agg(&mut data, 3, 1, 2, |_| 2.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_overlapping() {
+        let mut data = [1, 2, 3];
+        swap3_bca_slice_agg(&mut data, 0, 1, 1, |_| panic!("weight must not be called"));
+    }
+}
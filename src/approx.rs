@@ -0,0 +1,274 @@
+use ::approx::RelativeEq;
+use core::fmt::Debug;
+
+/// Asserts that `after` is `before` rotated to the left at `a`, `b` and `c`, within the
+/// default tolerances of [`RelativeEq`].
+///
+/// This is intended for tests of numeric algorithms built on the rotations, in which the
+/// rotated data is recomputed or transformed and thus only approximately equal. The element
+/// at `a` must approximately equal `before[b]`, the one at `b` must equal `before[c]`, the
+/// one at `c` must equal `before[a]`, and all other elements must equal their counterparts
+/// in `before`.
+///
+/// ## Arguments
+///
+/// * `before` - The data before the rotation.
+/// * `after` - The data after the rotation.
+/// * `a` - The first index, expected to hold the value of `before[b]`.
+/// * `b` - The second index, expected to hold the value of `before[c]`.
+/// * `c` - The third index, expected to hold the value of `before[a]`.
+///
+/// ## Panics
+///
+/// Panics if any element of `after` differs from its expected value, if the slices differ
+/// in length, or if the indices are out of bounds or not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let before = [0.1 + 0.2, 1.0, 2.0, 3.0];
+/// let mut after = before;
+/// swap3::swap3_bca_slice(&mut after, 0, 1, 3);
+/// after[3] = 0.3;
+/// swap3::assert_swap3_bca(&before, &after, 0, 1, 3);
+/// ```
+#[track_caller]
+#[cfg_attr(docsrs, doc(cfg(feature = "approx")))]
+pub fn assert_swap3_bca<T>(before: &[T], after: &[T], a: usize, b: usize, c: usize)
+where
+    T: RelativeEq + Debug,
+    T::Epsilon: Clone,
+{
+    assert_swap3_bca_with(
+        before,
+        after,
+        a,
+        b,
+        c,
+        T::default_epsilon(),
+        T::default_max_relative(),
+    );
+}
+
+/// Asserts that `after` is `before` rotated to the right at `a`, `b` and `c`, within the
+/// default tolerances of [`RelativeEq`].
+///
+/// This is intended for tests of numeric algorithms built on the rotations, in which the
+/// rotated data is recomputed or transformed and thus only approximately equal. The element
+/// at `a` must approximately equal `before[c]`, the one at `b` must equal `before[a]`, the
+/// one at `c` must equal `before[b]`, and all other elements must equal their counterparts
+/// in `before`.
+///
+/// ## Arguments
+///
+/// * `before` - The data before the rotation.
+/// * `after` - The data after the rotation.
+/// * `a` - The first index, expected to hold the value of `before[c]`.
+/// * `b` - The second index, expected to hold the value of `before[a]`.
+/// * `c` - The third index, expected to hold the value of `before[b]`.
+///
+/// ## Panics
+///
+/// Panics if any element of `after` differs from its expected value, if the slices differ
+/// in length, or if the indices are out of bounds or not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let before = [0.0_f32, 1.0, 2.0];
+/// let after = [2.0, 0.0, 1.0 + f32::EPSILON];
+/// swap3::assert_swap3_cab(&before, &after, 0, 1, 2);
+/// ```
+#[track_caller]
+#[cfg_attr(docsrs, doc(cfg(feature = "approx")))]
+pub fn assert_swap3_cab<T>(before: &[T], after: &[T], a: usize, b: usize, c: usize)
+where
+    T: RelativeEq + Debug,
+    T::Epsilon: Clone,
+{
+    assert_swap3_cab_with(
+        before,
+        after,
+        a,
+        b,
+        c,
+        T::default_epsilon(),
+        T::default_max_relative(),
+    );
+}
+
+/// Asserts that `after` is `before` rotated to the left at `a`, `b` and `c`, within the
+/// given tolerances.
+///
+/// See [`assert_swap3_bca`] for the expected values.
+///
+/// ## Arguments
+///
+/// * `before` - The data before the rotation.
+/// * `after` - The data after the rotation.
+/// * `a` - The first index, expected to hold the value of `before[b]`.
+/// * `b` - The second index, expected to hold the value of `before[c]`.
+/// * `c` - The third index, expected to hold the value of `before[a]`.
+/// * `epsilon` - The absolute tolerance for values close to each other.
+/// * `max_relative` - The relative tolerance for values far apart.
+///
+/// ## Panics
+///
+/// Panics if any element of `after` differs from its expected value, if the slices differ
+/// in length, or if the indices are out of bounds or not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let before = [1.0, 2.0, 3.0];
+/// let after = [2.001, 3.0, 1.0];
+/// swap3::assert_swap3_bca_with(&before, &after, 0, 1, 2, 0.0, 1e-3);
+/// ```
+#[track_caller]
+#[cfg_attr(docsrs, doc(cfg(feature = "approx")))]
+pub fn assert_swap3_bca_with<T>(
+    before: &[T],
+    after: &[T],
+    a: usize,
+    b: usize,
+    c: usize,
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) where
+    T: RelativeEq + Debug,
+    T::Epsilon: Clone,
+{
+    assert_moved(
+        before,
+        after,
+        [(a, b), (b, c), (c, a)],
+        epsilon,
+        max_relative,
+    );
+}
+
+/// Asserts that `after` is `before` rotated to the right at `a`, `b` and `c`, within the
+/// given tolerances.
+///
+/// See [`assert_swap3_cab`] for the expected values.
+///
+/// ## Arguments
+///
+/// * `before` - The data before the rotation.
+/// * `after` - The data after the rotation.
+/// * `a` - The first index, expected to hold the value of `before[c]`.
+/// * `b` - The second index, expected to hold the value of `before[a]`.
+/// * `c` - The third index, expected to hold the value of `before[b]`.
+/// * `epsilon` - The absolute tolerance for values close to each other.
+/// * `max_relative` - The relative tolerance for values far apart.
+///
+/// ## Panics
+///
+/// Panics if any element of `after` differs from its expected value, if the slices differ
+/// in length, or if the indices are out of bounds or not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let before = [1.0, 2.0, 3.0];
+/// let after = [3.0, 1.0, 2.001];
+/// swap3::assert_swap3_cab_with(&before, &after, 0, 1, 2, 0.0, 1e-3);
+/// ```
+#[track_caller]
+#[cfg_attr(docsrs, doc(cfg(feature = "approx")))]
+pub fn assert_swap3_cab_with<T>(
+    before: &[T],
+    after: &[T],
+    a: usize,
+    b: usize,
+    c: usize,
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) where
+    T: RelativeEq + Debug,
+    T::Epsilon: Clone,
+{
+    assert_moved(
+        before,
+        after,
+        [(a, c), (b, a), (c, b)],
+        epsilon,
+        max_relative,
+    );
+}
+
+/// Asserts that each `(target, source)` pair of `moves` holds the value of `before[source]`
+/// at `after[target]`, and that all other elements are unchanged.
+#[track_caller]
+fn assert_moved<T>(
+    before: &[T],
+    after: &[T],
+    moves: [(usize, usize); 3],
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) where
+    T: RelativeEq + Debug,
+    T::Epsilon: Clone,
+{
+    assert_eq!(
+        before.len(),
+        after.len(),
+        "the slices must have the same len, but the len before is {} and after is {}",
+        before.len(),
+        after.len()
+    );
+    let [(a, _), (b, _), (c, _)] = moves;
+    crate::disjoint::assert_indices(before.len(), a, b, c);
+
+    for (index, actual) in after.iter().enumerate() {
+        let source = moves
+            .iter()
+            .find(|&&(target, _)| target == index)
+            .map_or(index, |&(_, source)| source);
+        let expected = &before[source];
+        assert!(
+            actual.relative_eq(expected, epsilon.clone(), max_relative.clone()),
+            "the element at index {index} is {actual:?}, but expected {expected:?} from index {source}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recomputed_rotation() {
+        let before: [f64; 5] = [0.1, 0.2, 0.3, 0.4, 0.5];
+        let mut after = before.map(|x| x.sqrt());
+        crate::swap3_bca_slice(&mut after, 4, 0, 2);
+        assert_swap3_bca(&before, &after.map(|x| x * x), 4, 0, 2);
+
+        crate::swap3_cab_slice(&mut after, 4, 0, 2);
+        crate::swap3_cab_slice(&mut after, 4, 0, 2);
+        assert_swap3_cab(&before, &after.map(|x| x * x), 4, 0, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the element at index 0 is 3.0, but expected 2.0 from index 1")]
+    fn test_wrong_direction() {
+        assert_swap3_bca(&[1.0, 2.0, 3.0], &[3.0, 1.0, 2.0], 0, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the element at index 3 is 4.5, but expected 4.0 from index 3")]
+    fn test_other_element_changed() {
+        assert_swap3_cab(&[1.0, 2.0, 3.0, 4.0], &[3.0, 1.0, 2.0, 4.5], 0, 1, 2);
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let before = [100.0, 200.0, 300.0];
+        let after = [201.0, 300.0, 100.0];
+        assert_swap3_bca_with(&before, &after, 0, 1, 2, 0.0, 0.01);
+        let result = std::panic::catch_unwind(|| {
+            assert_swap3_bca_with(&before, &after, 0, 1, 2, 0.0, 0.001);
+        });
+        assert!(result.is_err());
+    }
+}
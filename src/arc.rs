@@ -0,0 +1,119 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+/// Rotates three values of a shared slice to the left, cloning it if necessary.
+///
+/// If `arc` is the only reference to the slice, it is rotated in place without allocating.
+/// Otherwise, the elements are cloned into a newly allocated slice, which is then
+/// rotated and stored in `arc`; other references keep observing the original values.
+///
+/// ## Arguments
+///
+/// * `arc` - The shared slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `arc[b]`.
+/// * `b` - The second index, to be assigned with the value of `arc[c]`.
+/// * `c` - The third index, to be assigned with the value of `arc[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// The indices are validated before any elements are cloned.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// let mut data: Arc<[i32]> = Arc::from([50, 10, 90, 25, 30, 75]);
+/// let shared = Arc::clone(&data);
+/// swap3::swap3_bca_arc(&mut data, 0, 1, 4);
+/// assert_eq!(&*data, &[10, 30, 90, 25, 50, 75]);
+/// assert_eq!(&*shared, &[50, 10, 90, 25, 30, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_bca_arc<T: Clone>(arc: &mut Arc<[T]>, a: usize, b: usize, c: usize) {
+    swap3_bca_slice(make_mut(arc, a, b, c), a, b, c);
+}
+
+/// Rotates three values of a shared slice to the right, cloning it if necessary.
+///
+/// If `arc` is the only reference to the slice, it is rotated in place without allocating.
+/// Otherwise, the elements are cloned into a newly allocated slice, which is then
+/// rotated and stored in `arc`; other references keep observing the original values.
+///
+/// ## Arguments
+///
+/// * `arc` - The shared slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `arc[c]`.
+/// * `b` - The second index, to be assigned with the value of `arc[a]`.
+/// * `c` - The third index, to be assigned with the value of `arc[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// The indices are validated before any elements are cloned.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// let mut data: Arc<[i32]> = Arc::from([50, 10, 90, 25, 30, 75]);
+/// let shared = Arc::clone(&data);
+/// swap3::swap3_cab_arc(&mut data, 0, 1, 4);
+/// assert_eq!(&*data, &[30, 50, 90, 25, 10, 75]);
+/// assert_eq!(&*shared, &[50, 10, 90, 25, 30, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_cab_arc<T: Clone>(arc: &mut Arc<[T]>, a: usize, b: usize, c: usize) {
+    swap3_cab_slice(make_mut(arc, a, b, c), a, b, c);
+}
+
+/// Provides mutable access to the slice, cloning it first if it is shared.
+fn make_mut<T: Clone>(arc: &mut Arc<[T]>, a: usize, b: usize, c: usize) -> &mut [T] {
+    crate::disjoint::assert_indices(arc.len(), a, b, c);
+
+    if Arc::get_mut(arc).is_none() {
+        *arc = arc.iter().cloned().collect::<Vec<_>>().into();
+    }
+    Arc::get_mut(arc).expect("the slice is uniquely owned")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_rotates_in_place() {
+        let mut data: Arc<[String]> = Arc::from(["a".into(), "b".into(), "c".into()]);
+        let ptr = data.as_ptr();
+        swap3_bca_arc(&mut data, 0, 1, 2);
+        assert_eq!(&*data, ["b", "c", "a"]);
+        swap3_cab_arc(&mut data, 0, 1, 2);
+        assert_eq!(&*data, ["a", "b", "c"]);
+        assert_eq!(data.as_ptr(), ptr, "a unique slice must not be reallocated");
+    }
+
+    #[test]
+    fn test_shared_clones() {
+        let mut data: Arc<[i32]> = Arc::from([1, 2, 3, 4]);
+        let shared = Arc::clone(&data);
+        swap3_bca_arc(&mut data, 3, 2, 0);
+        assert!(!Arc::ptr_eq(&data, &shared));
+        assert_eq!(&*data, [4, 2, 1, 3]);
+        assert_eq!(&*shared, [1, 2, 3, 4]);
+        assert_eq!(Arc::strong_count(&data), 1);
+    }
+
+    #[test]
+    fn test_invalid_indices_do_not_clone() {
+        let mut data: Arc<[i32]> = Arc::from([1, 2, 3]);
+        let shared = Arc::clone(&data);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_cab_arc(&mut data, 0, 1, 3);
+        }));
+        assert!(result.is_err());
+        assert!(Arc::ptr_eq(&data, &shared));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{swap3_bca_slice, swap3_cab_slice, Rotation};
+use core::marker::PhantomData;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The arrangement of three values reached by a sequence of rotations, tracked at the
+/// type level by [`Arranged`].
+///
+/// Rotations of three values form a cyclic group of order three, so every sequence of
+/// rotations composes to one of [`Unrotated`], [`RotatedLeft`] and [`RotatedRight`]. The
+/// associated types compute the arrangement after a further transition.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Arrangement: sealed::Sealed {
+    /// The original positions of the values now at `a`, `b` and `c`, where `0`, `1` and `2`
+    /// refer to the values originally at `a`, `b` and `c`, respectively.
+    const ORIGINS: [usize; 3];
+
+    /// The arrangement after an additional rotation to the left.
+    type Left: Arrangement;
+
+    /// The arrangement after an additional rotation to the right.
+    type Right: Arrangement;
+
+    /// The arrangement undoing this one.
+    type Inverse: Arrangement;
+
+    /// The arrangement after additionally applying the arrangement `N`.
+    type Then<N: Arrangement>: Arrangement;
+
+    /// The single rotation establishing this arrangement, if any.
+    #[doc(hidden)]
+    const ROTATION: Option<Rotation>;
+}
+
+/// The original arrangement `abc`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Unrotated;
+
+/// The arrangement `bca`, as established by a rotation to the left.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RotatedLeft;
+
+/// The arrangement `cab`, as established by a rotation to the right.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RotatedRight;
+
+impl sealed::Sealed for Unrotated {}
+impl sealed::Sealed for RotatedLeft {}
+impl sealed::Sealed for RotatedRight {}
+
+impl Arrangement for Unrotated {
+    const ORIGINS: [usize; 3] = [0, 1, 2];
+    type Left = RotatedLeft;
+    type Right = RotatedRight;
+    type Inverse = Unrotated;
+    type Then<N: Arrangement> = N;
+    const ROTATION: Option<Rotation> = None;
+}
+
+impl Arrangement for RotatedLeft {
+    const ORIGINS: [usize; 3] = [1, 2, 0];
+    type Left = RotatedRight;
+    type Right = Unrotated;
+    type Inverse = RotatedRight;
+    type Then<N: Arrangement> = N::Left;
+    const ROTATION: Option<Rotation> = Some(Rotation::Left);
+}
+
+impl Arrangement for RotatedRight {
+    const ORIGINS: [usize; 3] = [2, 0, 1];
+    type Left = Unrotated;
+    type Right = RotatedLeft;
+    type Inverse = RotatedLeft;
+    type Then<N: Arrangement> = N::Right;
+    const ROTATION: Option<Rotation> = Some(Rotation::Right);
+}
+
+/// Three positions of a slice whose pending rotations are tracked in the type parameter `P`.
+///
+/// The transitions only change the type and compile to nothing; [`commit`](Arranged::commit)
+/// applies the accumulated arrangement using at most a single rotation. By naming the
+/// expected arrangement in a type annotation or function signature, the compiler enforces
+/// that a sequence of rotations composes to it.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::{Arranged, RotatedLeft};
+///
+/// let mut vec = vec![10, 20, 30, 40];
+/// let arranged: Arranged<'_, _, RotatedLeft> = Arranged::new(&mut vec, 0, 1, 3)
+///     .rotate_right()
+///     .rotate_right();
+/// arranged.commit();
+/// assert_eq!(vec, &[20, 40, 30, 10]);
+/// ```
+///
+/// Sequences composing to a different arrangement are rejected:
+///
+/// ```compile_fail
+/// use swap3::{Arranged, RotatedLeft};
+///
+/// let mut vec = vec![10, 20, 30, 40];
+/// let arranged: Arranged<'_, _, RotatedLeft> = Arranged::new(&mut vec, 0, 1, 3)
+///     .rotate_left()
+///     .rotate_right();
+/// ```
+#[derive(Debug)]
+#[must_use = "the rotations are only applied by `commit`"]
+pub struct Arranged<'a, T, P: Arrangement = Unrotated> {
+    data: &'a mut [T],
+    indices: [usize; 3],
+    _arrangement: PhantomData<P>,
+}
+
+impl<'a, T> Arranged<'a, T, Unrotated> {
+    /// Starts tracking the rotations of three values of a slice.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to swap.
+    /// * `a` - The first index.
+    /// * `b` - The second index.
+    /// * `c` - The third index.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+    pub fn new(data: &'a mut [T], a: usize, b: usize, c: usize) -> Self {
+        crate::disjoint::assert_indices(data.len(), a, b, c);
+        Self {
+            data,
+            indices: [a, b, c],
+            _arrangement: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, P: Arrangement> Arranged<'a, T, P> {
+    /// Adds a rotation to the left.
+    #[inline(always)]
+    pub fn rotate_left(self) -> Arranged<'a, T, P::Left> {
+        self.transition()
+    }
+
+    /// Adds a rotation to the right.
+    #[inline(always)]
+    pub fn rotate_right(self) -> Arranged<'a, T, P::Right> {
+        self.transition()
+    }
+
+    /// Adds the rotations undoing the ones tracked so far.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use swap3::{Arranged, Unrotated};
+    ///
+    /// let mut vec = vec![10, 20, 30];
+    /// let arranged = Arranged::new(&mut vec, 0, 1, 2).rotate_left();
+    /// let arranged: Arranged<'_, _, Unrotated> = arranged.undo();
+    /// arranged.commit();
+    /// assert_eq!(vec, &[10, 20, 30]);
+    /// ```
+    #[inline(always)]
+    pub fn undo(self) -> Arranged<'a, T, P::Then<P::Inverse>> {
+        self.then::<P::Inverse>()
+    }
+
+    /// Adds the rotations establishing the arrangement `N`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use swap3::{Arranged, RotatedLeft, RotatedRight};
+    ///
+    /// let mut vec = vec![10, 20, 30];
+    /// let arranged: Arranged<'_, _, RotatedRight> = Arranged::new(&mut vec, 0, 1, 2)
+    ///     .then::<RotatedLeft>()
+    ///     .then::<RotatedLeft>();
+    /// arranged.commit();
+    /// assert_eq!(vec, &[30, 10, 20]);
+    /// ```
+    #[inline(always)]
+    pub fn then<N: Arrangement>(self) -> Arranged<'a, T, P::Then<N>> {
+        self.transition()
+    }
+
+    /// Returns the indices of the tracked positions.
+    #[inline(always)]
+    pub fn indices(&self) -> [usize; 3] {
+        self.indices
+    }
+
+    /// Applies the accumulated arrangement `P` and returns the slice.
+    pub fn commit(self) -> &'a mut [T] {
+        let [a, b, c] = self.indices;
+        match P::ROTATION {
+            None => {}
+            Some(Rotation::Left) => swap3_bca_slice(self.data, a, b, c),
+            Some(Rotation::Right) => swap3_cab_slice(self.data, a, b, c),
+        }
+        self.data
+    }
+
+    #[inline(always)]
+    fn transition<N: Arrangement>(self) -> Arranged<'a, T, N> {
+        Arranged {
+            data: self.data,
+            indices: self.indices,
+            _arrangement: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that the rotation of an arrangement moves the values as its `ORIGINS` say.
+    fn check_origins<P: Arrangement>() {
+        let mut data = [0, 1, 2];
+        Arranged::new(&mut data, 0, 1, 2).then::<P>().commit();
+        assert_eq!(data, P::ORIGINS);
+    }
+
+    #[test]
+    fn test_origins() {
+        check_origins::<Unrotated>();
+        check_origins::<RotatedLeft>();
+        check_origins::<RotatedRight>();
+    }
+
+    #[test]
+    fn test_transitions_match_eager_rotations() {
+        use Rotation::{Left, Right};
+
+        let mut lazy = [1, 2, 3, 4, 5];
+        let mut eager = lazy;
+
+        let arranged: Arranged<'_, _, RotatedLeft> = Arranged::new(&mut lazy, 4, 0, 2)
+            .rotate_left()
+            .rotate_right()
+            .rotate_right()
+            .rotate_left()
+            .rotate_left()
+            .rotate_left()
+            .rotate_right();
+        assert_eq!(arranged.indices(), [4, 0, 2]);
+        arranged.commit();
+
+        for rotation in [Left, Right, Right, Left, Left, Left, Right] {
+            rotation.apply(&mut eager, 4, 0, 2);
+        }
+        assert_eq!(lazy, eager);
+        assert_eq!(lazy, [3, 2, 5, 4, 1]);
+    }
+
+    #[test]
+    fn test_undo() {
+        let mut data = [1, 2, 3];
+        let arranged: Arranged<'_, _, Unrotated> =
+            Arranged::new(&mut data, 0, 1, 2).rotate_right().undo();
+        arranged.commit();
+        assert_eq!(data, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_overlapping() {
+        let mut data = [1, 2, 3];
+        let _ = Arranged::new(&mut data, 0, 2, 2);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{swap3_bca, swap3_cab, Rotation};
+
+/// Rotates three values of a slice in a direction chosen from the values themselves.
+///
+/// `prefer` is called once with the values at `a`, `b` and `c`, in this order, as they are
+/// *before* the rotation. The values are then rotated in the returned direction, i.e. like
+/// [`swap3_bca_slice`](crate::swap3_bca_slice) for [`Rotation::Left`] and like
+/// [`swap3_cab_slice`](crate::swap3_cab_slice) for [`Rotation::Right`].
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index.
+/// * `b` - The second index.
+/// * `c` - The third index.
+/// * `prefer` - The function choosing the direction of the rotation.
+///
+/// ## Returns
+///
+/// The direction in which the values were rotated.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `prefer` is not called in this case.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Rotation;
+///
+/// // Rotates increasing triples to the left, and all others to the right.
+/// let increasing = |a: &i32, b: &i32, c: &i32| {
+///     if a < b && b < c {
+///         Rotation::Left
+///     } else {
+///         Rotation::Right
+///     }
+/// };
+///
+/// let mut vec = vec![10, 20, 30, 40];
+/// assert_eq!(swap3::swap3_auto(&mut vec, 0, 1, 3, increasing), Rotation::Left);
+/// assert_eq!(vec, &[20, 40, 30, 10]);
+///
+/// assert_eq!(swap3::swap3_auto(&mut vec, 0, 1, 3, increasing), Rotation::Right);
+/// assert_eq!(vec, &[10, 20, 30, 40]);
+/// ```
+pub fn swap3_auto<T, F>(data: &mut [T], a: usize, b: usize, c: usize, prefer: F) -> Rotation
+where
+    F: FnOnce(&T, &T, &T) -> Rotation,
+{
+    let [a, b, c] = crate::disjoint::get3_mut(data, a, b, c);
+    let rotation = prefer(a, b, c);
+    match rotation {
+        Rotation::Left => swap3_bca(a, b, c),
+        Rotation::Right => swap3_cab(a, b, c),
+    }
+    rotation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predicate_sees_values_before_rotation() {
+        let mut data = ['x', 'a', 'y', 'b', 'c'];
+        let mut seen = None;
+        let rotation = swap3_auto(&mut data, 1, 3, 4, |a, b, c| {
+            seen = Some([*a, *b, *c]);
+            Rotation::Right
+        });
+        assert_eq!(rotation, Rotation::Right);
+        assert_eq!(seen, Some(['a', 'b', 'c']));
+        assert_eq!(data, ['x', 'c', 'y', 'a', 'b']);
+    }
+
+    #[test]
+    fn test_matches_rotation_apply() {
+        for rotation in [Rotation::Left, Rotation::Right] {
+            let mut expected = [1, 2, 3, 4, 5];
+            rotation.apply(&mut expected, 4, 0, 2);
+            let mut actual = [1, 2, 3, 4, 5];
+            swap3_auto(&mut actual, 4, 0, 2, |_, _, _| rotation);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_invalid_indices_do_not_call_predicate() {
+        let mut data = [1, 2, 3];
+        swap3_auto(&mut data, 0, 2, 2, |_, _, _| unreachable!());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::Rotation;
+
+/// Rotates three keys of a B-tree node, moving their child links along with them.
+///
+/// A node with `n` keys has `n + 1` children, where `children[i]` holds the subtree left of
+/// `keys[i]` and `children[i + 1]` the subtree right of it. Each key is moved together with
+/// its right child, i.e. rotating the keys at `a`, `b` and `c` also rotates the children at
+/// `a + 1`, `b + 1` and `c + 1`; `children[0]` is never moved. This keeps every key paired with
+/// the subtree of keys following it, e.g. when reordering keys during a rebalance.
+///
+/// ## Arguments
+///
+/// * `keys` - The keys of the node.
+/// * `children` - The child links of the node, one more than there are keys.
+/// * `a` - The first key index.
+/// * `b` - The second key index.
+/// * `c` - The third key index.
+/// * `dir` - The direction of the rotation.
+///
+/// ## Panics
+///
+/// Panics if `children` does not have exactly one element more than `keys`, if any of the
+/// indices is out of bounds of `keys`, or if the indices are not pairwise distinct. All
+/// checks are done before either slice is modified.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Rotation;
+///
+/// let mut keys = ['a', 'b', 'c', 'd'];
+/// let mut children = [0, 1, 2, 3, 4];
+///
+/// swap3::rotate_btree_keys(&mut keys, &mut children, 0, 1, 3, Rotation::Left);
+/// assert_eq!(keys, ['b', 'd', 'c', 'a']);
+/// assert_eq!(children, [0, 2, 4, 3, 1]);
+/// ```
+pub fn rotate_btree_keys<K, C>(
+    keys: &mut [K],
+    children: &mut [C],
+    a: usize,
+    b: usize,
+    c: usize,
+    dir: Rotation,
+) {
+    let len = keys.len();
+    assert!(
+        children.len() == len + 1,
+        "a node with {len} keys must have {} children, but got {}",
+        len + 1,
+        children.len()
+    );
+    crate::disjoint::assert_indices(len, a, b, c);
+
+    dir.apply(keys, a, b, c);
+    dir.apply(children, a + 1, b + 1, c + 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that every key is still paired with the child it was created with.
+    fn assert_paired(keys: &[u32], children: &[u32]) {
+        assert_eq!(children[0], 0);
+        for (key, child) in keys.iter().zip(&children[1..]) {
+            assert_eq!(*child, key / 10);
+        }
+    }
+
+    #[test]
+    fn test_rotate_btree_keys() {
+        let mut keys = [10, 20, 30, 40, 50];
+        let mut children = [0, 1, 2, 3, 4, 5];
+
+        rotate_btree_keys(&mut keys, &mut children, 4, 0, 2, Rotation::Left);
+        assert_eq!(keys, [30, 20, 50, 40, 10]);
+        assert_eq!(children, [0, 3, 2, 5, 4, 1]);
+        assert_paired(&keys, &children);
+
+        rotate_btree_keys(&mut keys, &mut children, 4, 0, 2, Rotation::Right);
+        assert_eq!(keys, [10, 20, 30, 40, 50]);
+        assert_eq!(children, [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_last_key_moves_last_child() {
+        let mut keys = [10, 20, 30];
+        let mut children = [0, 1, 2, 3];
+        rotate_btree_keys(&mut keys, &mut children, 0, 1, 2, Rotation::Right);
+        assert_eq!(keys, [30, 10, 20]);
+        assert_eq!(children, [0, 3, 1, 2]);
+        assert_paired(&keys, &children);
+    }
+
+    #[test]
+    #[should_panic(expected = "a node with 3 keys must have 4 children, but got 3")]
+    fn test_child_count_mismatch() {
+        rotate_btree_keys(&mut [1, 2, 3], &mut [0, 1, 2], 0, 1, 2, Rotation::Left);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_key_index_out_of_bounds() {
+        // The index is valid for the children, but not for the keys.
+        let mut children = [0, 1, 2, 3];
+        rotate_btree_keys(&mut [1, 2, 3], &mut children, 0, 1, 3, Rotation::Left);
+    }
+}
@@ -0,0 +1,206 @@
+use alloc::collections::BTreeMap;
+use core::fmt::{Display, Formatter};
+
+/// The error returned by [`swap3_bca_btreemap`] and [`swap3_cab_btreemap`], as well as by
+/// the rotations of keyed containers such as `swap3_bca_slab`.
+///
+/// The `position` of each variant identifies the offending key argument,
+/// i.e. `0` for `ka`, `1` for `kb` and `2` for `kc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub enum MapKeyError {
+    /// The key is not contained in the map.
+    MissingKey {
+        /// The position of the key argument.
+        position: usize,
+    },
+    /// The key was given more than once.
+    DuplicateKey {
+        /// The position of the repeated key argument.
+        position: usize,
+    },
+}
+
+impl Display for MapKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MapKeyError::MissingKey { position } => {
+                write!(
+                    f,
+                    "the key at position {position} is not contained in the map"
+                )
+            }
+            MapKeyError::DuplicateKey { position } => {
+                write!(f, "the key at position {position} was given more than once")
+            }
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for MapKeyError {}
+
+#[cfg(all(not(has_core_error), feature = "std"))]
+impl std::error::Error for MapKeyError {}
+
+/// Rotates the values at three keys of a [`BTreeMap`] to the left.
+///
+/// Only the values are rotated; the keys and hence the order of the map are unaffected.
+///
+/// ## Arguments
+///
+/// * `map` - The map whose values to swap.
+/// * `ka` - The first key, to be assigned with the value at `kb`.
+/// * `kb` - The second key, to be assigned with the value at `kc`.
+/// * `kc` - The third key, to be assigned with the value at `ka`.
+///
+/// ## Errors
+///
+/// Returns [`MapKeyError::MissingKey`] if any of the keys is not contained in the map, or
+/// [`MapKeyError::DuplicateKey`] if the keys are not pairwise distinct. The map is left
+/// unchanged in either case.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// let mut map = BTreeMap::from([("a", 10), ("b", 20), ("c", 30), ("d", 40)]);
+/// swap3::swap3_bca_btreemap(&mut map, &"a", &"b", &"d").unwrap();
+/// assert_eq!(map.into_values().collect::<Vec<_>>(), [20, 40, 30, 10]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_bca_btreemap<K: Ord, V>(
+    map: &mut BTreeMap<K, V>,
+    ka: &K,
+    kb: &K,
+    kc: &K,
+) -> Result<(), MapKeyError> {
+    check_keys(map, [ka, kb, kc])?;
+    rotate(map, ka, kb, kc);
+    Ok(())
+}
+
+/// Rotates the values at three keys of a [`BTreeMap`] to the right.
+///
+/// Only the values are rotated; the keys and hence the order of the map are unaffected.
+///
+/// ## Arguments
+///
+/// * `map` - The map whose values to swap.
+/// * `ka` - The first key, to be assigned with the value at `kc`.
+/// * `kb` - The second key, to be assigned with the value at `ka`.
+/// * `kc` - The third key, to be assigned with the value at `kb`.
+///
+/// ## Errors
+///
+/// Returns [`MapKeyError::MissingKey`] if any of the keys is not contained in the map, or
+/// [`MapKeyError::DuplicateKey`] if the keys are not pairwise distinct. The map is left
+/// unchanged in either case.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use swap3::MapKeyError;
+///
+/// let mut map = BTreeMap::from([("a", 10), ("b", 20), ("c", 30), ("d", 40)]);
+/// swap3::swap3_cab_btreemap(&mut map, &"a", &"b", &"d").unwrap();
+/// assert_eq!(map.values().copied().collect::<Vec<_>>(), [40, 10, 30, 20]);
+///
+/// assert_eq!(
+///     swap3::swap3_cab_btreemap(&mut map, &"a", &"x", &"d"),
+///     Err(MapKeyError::MissingKey { position: 1 })
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_cab_btreemap<K: Ord, V>(
+    map: &mut BTreeMap<K, V>,
+    ka: &K,
+    kb: &K,
+    kc: &K,
+) -> Result<(), MapKeyError> {
+    check_keys(map, [ka, kb, kc])?;
+    // Rotating right is rotating left with the first and last key exchanged.
+    rotate(map, kc, kb, ka);
+    Ok(())
+}
+
+fn check_keys<K: Ord, V>(map: &BTreeMap<K, V>, keys: [&K; 3]) -> Result<(), MapKeyError> {
+    for (position, key) in keys.iter().enumerate() {
+        if !map.contains_key(key) {
+            return Err(MapKeyError::MissingKey { position });
+        }
+        if keys[..position].contains(key) {
+            return Err(MapKeyError::DuplicateKey { position });
+        }
+    }
+    Ok(())
+}
+
+/// Rotates the values at three distinct, contained keys to the left.
+///
+/// As a map cannot hand out three mutable references at once, the value at `ka` is taken
+/// out temporarily and passed along the other two keys.
+fn rotate<K: Ord, V>(map: &mut BTreeMap<K, V>, ka: &K, kb: &K, kc: &K) {
+    const CHECKED: &str = "the keys were checked to be contained";
+    let (key, a) = map.remove_entry(ka).expect(CHECKED);
+    let c = core::mem::replace(map.get_mut(kc).expect(CHECKED), a);
+    let b = core::mem::replace(map.get_mut(kb).expect(CHECKED), c);
+    map.insert(key, b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    fn map() -> BTreeMap<u32, String> {
+        (0..6)
+            .map(|k| (k * 10, String::from(char::from(b'a' + k as u8))))
+            .collect()
+    }
+
+    fn values(map: &BTreeMap<u32, String>) -> Vec<&str> {
+        map.values().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn test_swap3_btreemap() {
+        let mut map = map();
+        assert_eq!(swap3_bca_btreemap(&mut map, &50, &0, &20), Ok(()));
+        assert_eq!(values(&map), ["c", "b", "f", "d", "e", "a"]);
+        assert_eq!(swap3_cab_btreemap(&mut map, &50, &0, &20), Ok(()));
+        assert_eq!(values(&map), ["a", "b", "c", "d", "e", "f"]);
+        assert_eq!(
+            map.keys().copied().collect::<Vec<_>>(),
+            [0, 10, 20, 30, 40, 50]
+        );
+    }
+
+    #[test]
+    fn test_missing_key() {
+        let mut map = map();
+        assert_eq!(
+            swap3_bca_btreemap(&mut map, &0, &10, &15),
+            Err(MapKeyError::MissingKey { position: 2 })
+        );
+        assert_eq!(values(&map), ["a", "b", "c", "d", "e", "f"]);
+    }
+
+    #[test]
+    fn test_duplicate_key() {
+        let mut map = map();
+        assert_eq!(
+            swap3_cab_btreemap(&mut map, &10, &20, &10),
+            Err(MapKeyError::DuplicateKey { position: 2 })
+        );
+        assert_eq!(
+            MapKeyError::DuplicateKey { position: 2 }.to_string(),
+            "the key at position 2 was given more than once"
+        );
+        assert_eq!(values(&map), ["a", "b", "c", "d", "e", "f"]);
+    }
+}
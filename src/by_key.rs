@@ -0,0 +1,154 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates the three elements with the smallest keys to the left, in key order.
+///
+/// The positions `a`, `b` and `c` of the elements with the smallest, second smallest
+/// and third smallest key are determined first; then the element at `a` is assigned
+/// with the value at `b`, the element at `b` with the value at `c` and the element at `c`
+/// with the value at `a`. Ties are broken by position, i.e. of two elements with equal
+/// keys, the one closer to the start of the slice is considered smaller.
+///
+/// The key function is called exactly once per element.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `key` - The function extracting the sort key of an element.
+///
+/// ## Returns
+///
+/// The positions `(a, b, c)` that were rotated, or `None` if the slice has fewer
+/// than three elements, in which case it is left unchanged.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// assert_eq!(swap3::swap3_bca_by_key(&mut vec, |&x| x), Some((1, 3, 4)));
+/// assert_eq!(vec, &[50, 25, 90, 30, 10, 75]);
+/// ```
+pub fn swap3_bca_by_key<T, K, F>(data: &mut [T], key: F) -> Option<(usize, usize, usize)>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let (a, b, c) = smallest_three(data, key)?;
+    swap3_bca_slice(data, a, b, c);
+    Some((a, b, c))
+}
+
+/// Rotates the three elements with the smallest keys to the right, in key order.
+///
+/// The positions `a`, `b` and `c` of the elements with the smallest, second smallest
+/// and third smallest key are determined first; then the element at `a` is assigned
+/// with the value at `c`, the element at `b` with the value at `a` and the element at `c`
+/// with the value at `b`. Ties are broken by position, i.e. of two elements with equal
+/// keys, the one closer to the start of the slice is considered smaller.
+///
+/// The key function is called exactly once per element.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `key` - The function extracting the sort key of an element.
+///
+/// ## Returns
+///
+/// The positions `(a, b, c)` that were rotated, or `None` if the slice has fewer
+/// than three elements, in which case it is left unchanged.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// assert_eq!(swap3::swap3_cab_by_key(&mut vec, |&x| x), Some((1, 3, 4)));
+/// assert_eq!(vec, &[50, 30, 90, 10, 25, 75]);
+/// ```
+pub fn swap3_cab_by_key<T, K, F>(data: &mut [T], key: F) -> Option<(usize, usize, usize)>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let (a, b, c) = smallest_three(data, key)?;
+    swap3_cab_slice(data, a, b, c);
+    Some((a, b, c))
+}
+
+/// Determines the positions of the three smallest keys in a single pass.
+fn smallest_three<T, K, F>(data: &[T], mut key: F) -> Option<(usize, usize, usize)>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    // Kept sorted by key and position, so that earlier positions stay ahead of
+    // later ones on ties, including for entries displaced by a smaller key.
+    let mut smallest: [Option<(K, usize)>; 3] = [None, None, None];
+    for (index, value) in data.iter().enumerate() {
+        let mut candidate = Some((key(value), index));
+        for slot in smallest.iter_mut() {
+            let displaces = match (slot.as_ref(), candidate.as_ref()) {
+                (None, _) => true,
+                (Some(current), Some(new)) => new < current,
+                (Some(_), None) => false,
+            };
+            if displaces {
+                candidate = core::mem::replace(slot, candidate);
+            }
+        }
+    }
+
+    match smallest {
+        [Some((_, a)), Some((_, b)), Some((_, c))] => Some((a, b, c)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_by_key() {
+        let mut data = ["pear", "fig", "banana", "kiwi", "apple"];
+        assert_eq!(swap3_bca_by_key(&mut data, |s| s.len()), Some((1, 0, 3)));
+        assert_eq!(data, ["kiwi", "pear", "banana", "fig", "apple"]);
+        assert_eq!(swap3_cab_by_key(&mut data, |s| s.len()), Some((3, 0, 1)));
+        assert_eq!(data, ["fig", "kiwi", "banana", "pear", "apple"]);
+    }
+
+    #[test]
+    fn test_ties_prefer_earlier_positions() {
+        let mut data = [3, 1, 2, 1, 2, 1];
+        assert_eq!(swap3_bca_by_key(&mut data, |&x| x), Some((1, 3, 5)));
+        let mut data = [2, 2, 2, 2];
+        assert_eq!(swap3_cab_by_key(&mut data, |&x| x), Some((0, 1, 2)));
+    }
+
+    #[test]
+    fn test_fewer_than_three() {
+        let mut calls = 0;
+        let mut data = [2, 1];
+        assert_eq!(
+            swap3_bca_by_key(&mut data, |&x| {
+                calls += 1;
+                x
+            }),
+            None
+        );
+        assert_eq!(data, [2, 1]);
+        assert_eq!(calls, 2);
+        assert_eq!(swap3_cab_by_key(&mut [0u8; 0], |&x| x), None);
+    }
+
+    #[test]
+    fn test_key_called_once_per_element() {
+        let mut calls = 0;
+        let mut data = [5, 4, 3, 2, 1];
+        swap3_bca_by_key(&mut data, |&x| {
+            calls += 1;
+            core::cmp::Reverse(x)
+        });
+        assert_eq!(calls, 5);
+        assert_eq!(data, [4, 3, 5, 2, 1]);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values to the left, validating the indices against a logical capacity.
+///
+/// Only the first `cap` elements of `data` are considered; the rotation behaves like
+/// [`swap3_bca_slice`] on `&mut data[..cap]`. If `cap` is smaller than `data.len()`, the
+/// elements beyond `cap` are never accessed, even if an index would address one of them.
+/// A `cap` larger than `data.len()` is rejected, as it would permit indices past the end
+/// of the slice.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `cap` - The logical capacity the indices must be less than.
+///
+/// ## Panics
+///
+/// Panics if `cap` exceeds `data.len()`, if any of the indices is not less than `cap`,
+/// or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_bca_slice_cap(&mut vec, 0, 1, 3, 4);
+/// assert_eq!(vec, &[10, 25, 90, 50, 30, 75]);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_slice_cap<T>(data: &mut [T], a: usize, b: usize, c: usize, cap: usize) {
+    swap3_bca_slice(within_cap(data, a, b, c, cap), a, b, c);
+}
+
+/// Rotates three values to the right, validating the indices against a logical capacity.
+///
+/// Only the first `cap` elements of `data` are considered; the rotation behaves like
+/// [`swap3_cab_slice`] on `&mut data[..cap]`. If `cap` is smaller than `data.len()`, the
+/// elements beyond `cap` are never accessed, even if an index would address one of them.
+/// A `cap` larger than `data.len()` is rejected, as it would permit indices past the end
+/// of the slice.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `cap` - The logical capacity the indices must be less than.
+///
+/// ## Panics
+///
+/// Panics if `cap` exceeds `data.len()`, if any of the indices is not less than `cap`,
+/// or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_cab_slice_cap(&mut vec, 0, 1, 3, 4);
+/// assert_eq!(vec, &[25, 50, 90, 10, 30, 75]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_slice_cap<T>(data: &mut [T], a: usize, b: usize, c: usize, cap: usize) {
+    swap3_cab_slice(within_cap(data, a, b, c, cap), a, b, c);
+}
+
+#[inline(always)]
+fn within_cap<T>(data: &mut [T], a: usize, b: usize, c: usize, cap: usize) -> &mut [T] {
+    let len = data.len();
+    assert!(
+        cap <= len,
+        "capacity exceeds the slice: the len is {len} but the capacity is {cap}"
+    );
+    for index in [a, b, c] {
+        assert!(
+            index < cap,
+            "index out of bounds: the capacity is {cap} but the index is {index}"
+        );
+    }
+    &mut data[..cap]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_slice_cap() {
+        let mut data = [1, 2, 3, 4, 5];
+        swap3_bca_slice_cap(&mut data, 0, 2, 4, 5);
+        assert_eq!(data, [3, 2, 5, 4, 1]);
+        swap3_cab_slice_cap(&mut data, 0, 2, 4, 5);
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the capacity is 3 but the index is 4")]
+    fn test_index_beyond_cap() {
+        // Index 4 is within the slice, but not the capacity.
+        let mut data = [1, 2, 3, 4, 5];
+        swap3_bca_slice_cap(&mut data, 0, 1, 4, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 5 but the capacity is 6")]
+    fn test_cap_beyond_len() {
+        let mut data = [1, 2, 3, 4, 5];
+        swap3_cab_slice_cap(&mut data, 0, 1, 2, 6);
+    }
+}
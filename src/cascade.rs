@@ -0,0 +1,116 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates every overlapping triple of a slice to the left, sweeping from left to right.
+///
+/// For each window start `i` in `0..data.len() - 2`, in increasing order, the elements at
+/// `i`, `i + 1` and `i + 2` are rotated like [`swap3_bca_slice`]. This mirrors processing
+/// the windows yielded by `tuple_windows::<(_, _, _)>()` of `itertools`, except that each
+/// window observes the effect of the rotations applied before it.
+///
+/// ## Cascade
+///
+/// As the windows overlap, the value moved to the end of one window is picked up by the
+/// next window and moved back again. The net effect is that for four or more elements,
+/// only the first two and the last two elements are exchanged, while all elements in
+/// between return to their original positions:
+///
+/// ```text
+/// [x0, x1, x2, ..., xn-3, xn-2, xn-1] -> [x1, x0, x2, ..., xn-3, xn-1, xn-2]
+/// ```
+///
+/// For exactly three elements, this is a single rotation. Slices with fewer than three
+/// elements are left unchanged.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose windows to rotate.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![0, 1, 2, 3, 4, 5];
+/// swap3::cascade_rotate_left(&mut vec);
+/// assert_eq!(vec, &[1, 0, 2, 3, 5, 4]);
+/// ```
+pub fn cascade_rotate_left<T>(data: &mut [T]) {
+    for i in 0..data.len().saturating_sub(2) {
+        swap3_bca_slice(data, i, i + 1, i + 2);
+    }
+}
+
+/// Rotates every overlapping triple of a slice to the right, sweeping from left to right.
+///
+/// For each window start `i` in `0..data.len() - 2`, in increasing order, the elements at
+/// `i`, `i + 1` and `i + 2` are rotated like [`swap3_cab_slice`]. This mirrors processing
+/// the windows yielded by `tuple_windows::<(_, _, _)>()` of `itertools`, except that each
+/// window observes the effect of the rotations applied before it.
+///
+/// ## Cascade
+///
+/// As the windows overlap, the first two elements are carried along by every rotation
+/// and end up at the end of the slice. The net effect is the same as rotating the whole
+/// slice left by two positions, i.e. `data.rotate_left(2)`:
+///
+/// ```text
+/// [x0, x1, x2, ..., xn-1] -> [x2, ..., xn-1, x0, x1]
+/// ```
+///
+/// Slices with fewer than three elements are left unchanged.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose windows to rotate.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![0, 1, 2, 3, 4, 5];
+/// swap3::cascade_rotate_right(&mut vec);
+/// assert_eq!(vec, &[2, 3, 4, 5, 0, 1]);
+/// ```
+pub fn cascade_rotate_right<T>(data: &mut [T]) {
+    for i in 0..data.len().saturating_sub(2) {
+        swap3_cab_slice(data, i, i + 1, i + 2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cascade_left_closed_form() {
+        for len in 4..32 {
+            let mut data: Vec<usize> = (0..len).collect();
+            cascade_rotate_left(&mut data);
+            let mut expected: Vec<usize> = (0..len).collect();
+            expected.swap(0, 1);
+            expected.swap(len - 2, len - 1);
+            assert_eq!(data, expected, "len {len}");
+        }
+    }
+
+    #[test]
+    fn test_cascade_right_closed_form() {
+        for len in 3..32 {
+            let mut data: Vec<usize> = (0..len).collect();
+            cascade_rotate_right(&mut data);
+            let mut expected: Vec<usize> = (0..len).collect();
+            expected.rotate_left(2);
+            assert_eq!(data, expected, "len {len}");
+        }
+    }
+
+    #[test]
+    fn test_short_slices() {
+        let mut data = [1, 2, 3];
+        cascade_rotate_left(&mut data);
+        assert_eq!(data, [2, 3, 1]);
+
+        let mut data = [1, 2];
+        cascade_rotate_left(&mut data);
+        cascade_rotate_right(&mut data);
+        assert_eq!(data, [1, 2]);
+        cascade_rotate_right(&mut [0u8; 0]);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{swap3_bca, swap3_cab};
+
+/// Rotates the values at the same index of three chunks to the left.
+///
+/// This fits iterating a slice using [`chunks_mut`](slice::chunks_mut) or
+/// [`chunks_exact_mut`](slice::chunks_exact_mut) without computing indices across
+/// chunk boundaries. Since the chunks are disjoint mutable borrows, no aliasing can occur.
+///
+/// ## Arguments
+///
+/// * `c0` - The first chunk, whose element at `idx` is assigned with the value of `c1[idx]`.
+/// * `c1` - The second chunk, whose element at `idx` is assigned with the value of `c2[idx]`.
+/// * `c2` - The third chunk, whose element at `idx` is assigned with the value of `c0[idx]`.
+/// * `idx` - The index within each chunk.
+///
+/// ## Panics
+///
+/// Panics if `idx` is out of bounds for any of the chunks.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 11, 20, 21, 30, 31];
+/// let mut chunks = vec.chunks_exact_mut(2);
+/// let c0 = chunks.next().unwrap();
+/// let c1 = chunks.next().unwrap();
+/// let c2 = chunks.next().unwrap();
+/// swap3::swap3_bca_chunks(c0, c1, c2, 0);
+/// assert_eq!(vec, &[20, 11, 30, 21, 10, 31]);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_chunks<T>(c0: &mut [T], c1: &mut [T], c2: &mut [T], idx: usize) {
+    swap3_bca(&mut c0[idx], &mut c1[idx], &mut c2[idx]);
+}
+
+/// Rotates the values at the same index of three chunks to the right.
+///
+/// This fits iterating a slice using [`chunks_mut`](slice::chunks_mut) or
+/// [`chunks_exact_mut`](slice::chunks_exact_mut) without computing indices across
+/// chunk boundaries. Since the chunks are disjoint mutable borrows, no aliasing can occur.
+///
+/// ## Arguments
+///
+/// * `c0` - The first chunk, whose element at `idx` is assigned with the value of `c2[idx]`.
+/// * `c1` - The second chunk, whose element at `idx` is assigned with the value of `c0[idx]`.
+/// * `c2` - The third chunk, whose element at `idx` is assigned with the value of `c1[idx]`.
+/// * `idx` - The index within each chunk.
+///
+/// ## Panics
+///
+/// Panics if `idx` is out of bounds for any of the chunks.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 11, 20, 21, 30, 31];
+/// let mut chunks = vec.chunks_exact_mut(2);
+/// let c0 = chunks.next().unwrap();
+/// let c1 = chunks.next().unwrap();
+/// let c2 = chunks.next().unwrap();
+/// swap3::swap3_cab_chunks(c0, c1, c2, 1);
+/// assert_eq!(vec, &[10, 31, 20, 11, 30, 21]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_chunks<T>(c0: &mut [T], c1: &mut [T], c2: &mut [T], idx: usize) {
+    swap3_cab(&mut c0[idx], &mut c1[idx], &mut c2[idx]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_bca_chunks() {
+        let mut vec = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut chunks = vec.chunks_mut(3);
+        let (c0, c1, c2) = (
+            chunks.next().unwrap(),
+            chunks.next().unwrap(),
+            chunks.next().unwrap(),
+        );
+        swap3_bca_chunks(c0, c1, c2, 2);
+        assert_eq!(vec, &[1, 2, 6, 4, 5, 9, 7, 8, 3]);
+    }
+
+    #[test]
+    fn test_swap3_cab_chunks() {
+        let mut vec = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut chunks = vec.chunks_mut(3);
+        let (c0, c1, c2) = (
+            chunks.next().unwrap(),
+            chunks.next().unwrap(),
+            chunks.next().unwrap(),
+        );
+        swap3_cab_chunks(c0, c1, c2, 2);
+        assert_eq!(vec, &[1, 2, 9, 4, 5, 3, 7, 8, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_short_chunk() {
+        let mut vec = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut chunks = vec.chunks_mut(3);
+        let (c0, c1, c2) = (
+            chunks.next().unwrap(),
+            chunks.next().unwrap(),
+            chunks.next().unwrap(),
+        );
+        swap3_bca_chunks(c0, c1, c2, 2);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three columns of a row-major matrix to the left.
+///
+/// The matrix is stored as a flat slice of rows of `width` elements each, so that the element
+/// in row `y` and column `x` is located at `data[y * width + x]`. The columns are rotated
+/// row by row in a single sweep over the slice.
+///
+/// ## Arguments
+///
+/// * `data` - The flat slice holding the matrix.
+/// * `width` - The number of columns, i.e. the length of each row.
+/// * `ca` - The first column, to be assigned with the values of column `cb`.
+/// * `cb` - The second column, to be assigned with the values of column `cc`.
+/// * `cc` - The third column, to be assigned with the values of column `ca`.
+///
+/// ## Panics
+///
+/// Panics if any of the columns is not less than `width`, if the columns are not pairwise
+/// distinct, or if the length of `data` is not a multiple of `width`. Nothing is rotated in
+/// this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut table = vec![
+///     1, 2, 3, 4,
+///     5, 6, 7, 8,
+/// ];
+/// swap3::swap3_bca_columns(&mut table, 4, 0, 1, 3);
+/// assert_eq!(table, &[
+///     2, 4, 3, 1,
+///     6, 8, 7, 5,
+/// ]);
+/// ```
+pub fn swap3_bca_columns<T>(data: &mut [T], width: usize, ca: usize, cb: usize, cc: usize) {
+    check_columns(data.len(), width, [ca, cb, cc]);
+    for row in data.chunks_exact_mut(width) {
+        swap3_bca_slice(row, ca, cb, cc);
+    }
+}
+
+/// Rotates three columns of a row-major matrix to the right.
+///
+/// The matrix is stored as a flat slice of rows of `width` elements each, so that the element
+/// in row `y` and column `x` is located at `data[y * width + x]`. The columns are rotated
+/// row by row in a single sweep over the slice.
+///
+/// ## Arguments
+///
+/// * `data` - The flat slice holding the matrix.
+/// * `width` - The number of columns, i.e. the length of each row.
+/// * `ca` - The first column, to be assigned with the values of column `cc`.
+/// * `cb` - The second column, to be assigned with the values of column `ca`.
+/// * `cc` - The third column, to be assigned with the values of column `cb`.
+///
+/// ## Panics
+///
+/// Panics if any of the columns is not less than `width`, if the columns are not pairwise
+/// distinct, or if the length of `data` is not a multiple of `width`. Nothing is rotated in
+/// this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut table = vec![
+///     1, 2, 3, 4,
+///     5, 6, 7, 8,
+/// ];
+/// swap3::swap3_cab_columns(&mut table, 4, 0, 1, 3);
+/// assert_eq!(table, &[
+///     4, 1, 3, 2,
+///     8, 5, 7, 6,
+/// ]);
+/// ```
+pub fn swap3_cab_columns<T>(data: &mut [T], width: usize, ca: usize, cb: usize, cc: usize) {
+    check_columns(data.len(), width, [ca, cb, cc]);
+    for row in data.chunks_exact_mut(width) {
+        swap3_cab_slice(row, ca, cb, cc);
+    }
+}
+
+/// Validates the matrix dimensions and the columns.
+// `usize::is_multiple_of` requires Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+fn check_columns(len: usize, width: usize, columns: [usize; 3]) {
+    for column in columns {
+        assert!(
+            column < width,
+            "column out of bounds: the width is {width} but the column is {column}"
+        );
+    }
+    let [ca, cb, cc] = columns;
+    assert!(
+        ca != cb && cb != cc && ca != cc,
+        "columns must be pairwise distinct, but got {ca}, {cb} and {cc}"
+    );
+    assert!(
+        len % width == 0,
+        "the len is {len}, which is not a multiple of the width {width}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_columns() {
+        let mut matrix: [u32; 15] = core::array::from_fn(|x| x as u32);
+        swap3_bca_columns(&mut matrix, 5, 4, 0, 2);
+        assert_eq!(matrix, [2, 1, 4, 3, 0, 7, 6, 9, 8, 5, 12, 11, 14, 13, 10]);
+        swap3_cab_columns(&mut matrix, 5, 4, 0, 2);
+        assert_eq!(matrix, core::array::from_fn(|x| x as u32));
+    }
+
+    #[test]
+    fn test_empty_matrix() {
+        let mut matrix: [u8; 0] = [];
+        swap3_bca_columns(&mut matrix, 3, 0, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the width is 3 but the column is 3")]
+    fn test_column_out_of_bounds() {
+        swap3_bca_columns(&mut [0; 9], 3, 0, 1, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_duplicate_column() {
+        swap3_cab_columns(&mut [0; 9], 3, 1, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 10, which is not a multiple of the width 3")]
+    fn test_partial_row() {
+        swap3_bca_columns(&mut [0; 10], 3, 0, 1, 2);
+    }
+}
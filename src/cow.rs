@@ -0,0 +1,110 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use alloc::borrow::Cow;
+
+/// Rotates three values of a copy-on-write slice to the left.
+///
+/// If the slice is [`Cow::Borrowed`], it is cloned into an owned `Vec` first, which
+/// allocates and clones every element of the slice, not just the three rotated ones.
+/// An already [`Cow::Owned`] slice is rotated in place without allocating. The indices
+/// are validated before cloning, so invalid indices never cause an allocation.
+///
+/// ## Arguments
+///
+/// * `cow` - The copy-on-write slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `cow[b]`.
+/// * `b` - The second index, to be assigned with the value of `cow[c]`.
+/// * `c` - The third index, to be assigned with the value of `cow[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// let shared = [10, 20, 30, 40];
+/// let mut cow = Cow::Borrowed(&shared[..]);
+/// swap3::swap3_bca_cow(&mut cow, 0, 1, 3);
+/// assert!(matches!(cow, Cow::Owned(_)));
+/// assert_eq!(cow, [20, 40, 30, 10].as_slice());
+/// assert_eq!(shared, [10, 20, 30, 40]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_bca_cow<T: Clone>(cow: &mut Cow<'_, [T]>, a: usize, b: usize, c: usize) {
+    crate::disjoint::assert_indices(cow.len(), a, b, c);
+    swap3_bca_slice(cow.to_mut(), a, b, c);
+}
+
+/// Rotates three values of a copy-on-write slice to the right.
+///
+/// If the slice is [`Cow::Borrowed`], it is cloned into an owned `Vec` first, which
+/// allocates and clones every element of the slice, not just the three rotated ones.
+/// An already [`Cow::Owned`] slice is rotated in place without allocating. The indices
+/// are validated before cloning, so invalid indices never cause an allocation.
+///
+/// ## Arguments
+///
+/// * `cow` - The copy-on-write slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `cow[c]`.
+/// * `b` - The second index, to be assigned with the value of `cow[a]`.
+/// * `c` - The third index, to be assigned with the value of `cow[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// let mut cow: Cow<[i32]> = Cow::Owned(vec![10, 20, 30, 40]);
+/// swap3::swap3_cab_cow(&mut cow, 0, 1, 3);
+/// assert_eq!(cow, [40, 10, 30, 20].as_slice());
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_cab_cow<T: Clone>(cow: &mut Cow<'_, [T]>, a: usize, b: usize, c: usize) {
+    crate::disjoint::assert_indices(cow.len(), a, b, c);
+    swap3_cab_slice(cow.to_mut(), a, b, c);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[test]
+    fn test_owned_is_rotated_in_place() {
+        let mut cow: Cow<[String]> = Cow::Owned(vec!["a".into(), "b".into(), "c".into()]);
+        let ptr = cow.as_ptr();
+        swap3_bca_cow(&mut cow, 0, 1, 2);
+        swap3_bca_cow(&mut cow, 0, 1, 2);
+        assert_eq!(cow.as_ptr(), ptr);
+        assert_eq!(cow, ["c", "a", "b"].map(String::from).as_slice());
+    }
+
+    #[test]
+    fn test_borrowed_is_cloned_once() {
+        let shared = [1, 2, 3, 4];
+        let mut cow = Cow::Borrowed(&shared[..]);
+        swap3_cab_cow(&mut cow, 0, 2, 3);
+        let ptr = cow.as_ptr();
+        assert_ne!(ptr, shared.as_ptr());
+        swap3_cab_cow(&mut cow, 0, 2, 3);
+        assert_eq!(cow.as_ptr(), ptr);
+        assert_eq!(cow, [3, 2, 4, 1].as_slice());
+    }
+
+    #[test]
+    fn test_invalid_indices_do_not_clone() {
+        let shared = [1, 2, 3];
+        let mut cow = Cow::Borrowed(&shared[..]);
+        for (a, b, c) in [(0, 1, 3), (0, 1, 1)] {
+            let result = catch_unwind(AssertUnwindSafe(|| swap3_bca_cow(&mut cow, a, b, c)));
+            assert!(result.is_err());
+            assert!(matches!(cow, Cow::Borrowed(_)));
+        }
+    }
+}
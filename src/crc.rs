@@ -0,0 +1,189 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// The reversed generator polynomial of CRC-32 (ISO-HDLC).
+const POLY: u32 = 0xEDB8_8320;
+
+/// Rotates three bytes of a buffer to the left and updates its CRC-32 accordingly.
+///
+/// The checksum is the CRC-32 (ISO-HDLC) used by zlib, gzip, PNG and Ethernet, e.g. as
+/// computed by the `crc32fast` crate. Instead of recomputing it over the whole buffer, only
+/// the changes of the three moved bytes are folded into `crc`, taking `O(log n)` time each.
+/// This allows keeping the checksum of a buffer in sync while it is transmitted
+/// incrementally.
+///
+/// Rotations do not change order-independent checksums such as plain sums, which is why
+/// this is only needed for checksums like the CRC that depend on the positions of the bytes.
+///
+/// ## Arguments
+///
+/// * `data` - The buffer whose bytes to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `crc` - The CRC-32 of `data`, to be updated to that of the rotated buffer.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `crc` is left unchanged in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut data = *b"123456789";
+/// let mut crc = 0xCBF4_3926;
+/// swap3::swap3_bca_slice_crc(&mut data, 0, 1, 2, &mut crc);
+/// assert_eq!(&data, b"231456789");
+/// assert_eq!(crc, 0xA94C_1454);
+/// ```
+pub fn swap3_bca_slice_crc(data: &mut [u8], a: usize, b: usize, c: usize, crc: &mut u32) {
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+    update(crc, data, [(a, data[b]), (b, data[c]), (c, data[a])]);
+    swap3_bca_slice(data, a, b, c);
+}
+
+/// Rotates three bytes of a buffer to the right and updates its CRC-32 accordingly.
+///
+/// The checksum is the CRC-32 (ISO-HDLC) used by zlib, gzip, PNG and Ethernet, e.g. as
+/// computed by the `crc32fast` crate. Instead of recomputing it over the whole buffer, only
+/// the changes of the three moved bytes are folded into `crc`, taking `O(log n)` time each.
+/// This allows keeping the checksum of a buffer in sync while it is transmitted
+/// incrementally.
+///
+/// Rotations do not change order-independent checksums such as plain sums, which is why
+/// this is only needed for checksums like the CRC that depend on the positions of the bytes.
+///
+/// ## Arguments
+///
+/// * `data` - The buffer whose bytes to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `crc` - The CRC-32 of `data`, to be updated to that of the rotated buffer.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `crc` is left unchanged in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut data = *b"123456789";
+/// let mut crc = 0xCBF4_3926;
+/// swap3::swap3_cab_slice_crc(&mut data, 0, 1, 2, &mut crc);
+/// assert_eq!(&data, b"312456789");
+/// assert_eq!(crc, 0xCDFA_1DF7);
+/// ```
+pub fn swap3_cab_slice_crc(data: &mut [u8], a: usize, b: usize, c: usize, crc: &mut u32) {
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+    update(crc, data, [(a, data[c]), (b, data[a]), (c, data[b])]);
+    swap3_cab_slice(data, a, b, c);
+}
+
+/// Folds the replacement of the bytes at the given indices into `crc`.
+///
+/// The CRC is affine in the message, so replacing the byte at `index` changes it by the
+/// CRC (without initial value and final XOR) of the difference of the bytes followed by the
+/// remaining `len - index - 1` zero bytes. Appending `k` zero bytes multiplies the CRC by
+/// `x^(8k)` modulo the generator polynomial.
+fn update(crc: &mut u32, data: &[u8], replacements: [(usize, u8); 3]) {
+    for (index, value) in replacements {
+        let delta = data[index] ^ value;
+        if delta != 0 {
+            let shift = x_pow_8k(data.len() - index - 1);
+            *crc ^= mul_mod(shift, crc_byte(delta));
+        }
+    }
+}
+
+/// Computes the CRC of a single byte, without initial value and final XOR.
+fn crc_byte(byte: u8) -> u32 {
+    (0..8).fold(u32::from(byte), |crc, _| mul_x(crc))
+}
+
+/// Multiplies a polynomial in reversed representation by `x` modulo the generator.
+#[inline(always)]
+fn mul_x(p: u32) -> u32 {
+    if p & 1 == 0 {
+        p >> 1
+    } else {
+        (p >> 1) ^ POLY
+    }
+}
+
+/// Multiplies two polynomials in reversed representation modulo the generator.
+fn mul_mod(a: u32, mut b: u32) -> u32 {
+    let mut product = 0;
+    for bit in (0..32).rev() {
+        if a & (1 << bit) != 0 {
+            product ^= b;
+        }
+        b = mul_x(b);
+    }
+    product
+}
+
+/// Computes `x^(8k)` modulo the generator in reversed representation.
+fn x_pow_8k(mut k: usize) -> u32 {
+    // In reversed representation, `x^0` is the most significant bit.
+    let mut result = 1 << 31;
+    let mut power = 1 << (31 - 8);
+    while k != 0 {
+        if k & 1 != 0 {
+            result = mul_mod(result, power);
+        }
+        power = mul_mod(power, power);
+        k >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Computes the CRC-32 of `data` bit by bit.
+    fn crc32(data: &[u8]) -> u32 {
+        !data.iter().fold(!0, |crc, &byte| {
+            (0..8).fold(crc ^ u32::from(byte), |crc, _| mul_x(crc))
+        })
+    }
+
+    #[test]
+    fn test_reference_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_matches_recomputation() {
+        let mut data: Vec<u8> = (0..1000_u32).map(|i| (i * 7919 % 251) as u8).collect();
+        let mut crc = crc32(&data);
+        for (a, b, c) in [(0, 1, 2), (999, 0, 500), (3, 998, 4), (10, 20, 30)] {
+            swap3_bca_slice_crc(&mut data, a, b, c, &mut crc);
+            assert_eq!(crc, crc32(&data));
+            swap3_cab_slice_crc(&mut data, c, a, b, &mut crc);
+            assert_eq!(crc, crc32(&data));
+        }
+    }
+
+    #[test]
+    fn test_equal_bytes() {
+        let mut data = *b"aaab";
+        let mut crc = crc32(&data);
+        swap3_bca_slice_crc(&mut data, 0, 1, 2, &mut crc);
+        assert_eq!(crc, crc32(b"aaab"));
+        swap3_cab_slice_crc(&mut data, 1, 2, 3, &mut crc);
+        assert_eq!(crc, crc32(b"abaa"));
+    }
+
+    #[test]
+    fn test_invalid_indices_keep_crc() {
+        let mut crc = 42;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_bca_slice_crc(&mut [1, 2, 3], 0, 1, 3, &mut crc);
+        }));
+        assert!(result.is_err());
+        assert_eq!(crc, 42);
+    }
+}
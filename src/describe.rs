@@ -0,0 +1,112 @@
+use alloc::format;
+use alloc::string::String;
+
+/// Describes a left rotation of the labeled elements at three positions.
+///
+/// This does not modify any data; it renders the positions along with the labels
+/// before and after the rotation, e.g. for golden-file tests of rotation sequences.
+///
+/// ## Arguments
+///
+/// * `labels` - The labels of the elements.
+/// * `a` - The first index, to be assigned with the value of `labels[b]`.
+/// * `b` - The second index, to be assigned with the value of `labels[c]`.
+/// * `c` - The third index, to be assigned with the value of `labels[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds.
+///
+/// ## Example
+///
+/// ```
+/// let labels = ["x", "y", "w", "v", "z"];
+/// assert_eq!(
+///     swap3::describe_swap3_bca(&labels, 0, 1, 4),
+///     "rotate left: positions 0, 1, 4: [x, y, z] -> [y, z, x]"
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn describe_swap3_bca(labels: &[&str], a: usize, b: usize, c: usize) -> String {
+    let [x, y, z] = [labels[a], labels[b], labels[c]];
+    format!("rotate left: positions {a}, {b}, {c}: [{x}, {y}, {z}] -> [{y}, {z}, {x}]")
+}
+
+/// Describes a right rotation of the labeled elements at three positions.
+///
+/// This does not modify any data; it renders the positions along with the labels
+/// before and after the rotation, e.g. for golden-file tests of rotation sequences.
+///
+/// ## Arguments
+///
+/// * `labels` - The labels of the elements.
+/// * `a` - The first index, to be assigned with the value of `labels[c]`.
+/// * `b` - The second index, to be assigned with the value of `labels[a]`.
+/// * `c` - The third index, to be assigned with the value of `labels[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds.
+///
+/// ## Example
+///
+/// ```
+/// let labels = ["x", "y", "w", "v", "z"];
+/// assert_eq!(
+///     swap3::describe_swap3_cab(&labels, 0, 1, 4),
+///     "rotate right: positions 0, 1, 4: [x, y, z] -> [z, x, y]"
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn describe_swap3_cab(labels: &[&str], a: usize, b: usize, c: usize) -> String {
+    let [x, y, z] = [labels[a], labels[b], labels[c]];
+    format!("rotate right: positions {a}, {b}, {c}: [{x}, {y}, {z}] -> [{z}, {x}, {y}]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{swap3_bca_slice, swap3_cab_slice};
+
+    #[test]
+    fn test_describe_matches_rotation() {
+        let mut labels = ["a", "b", "c", "d", "e"];
+        let left = describe_swap3_bca(&labels, 4, 0, 2);
+        swap3_bca_slice(&mut labels, 4, 0, 2);
+        assert_eq!(
+            left,
+            "rotate left: positions 4, 0, 2: [e, a, c] -> [a, c, e]"
+        );
+        assert_eq!([labels[4], labels[0], labels[2]], ["a", "c", "e"]);
+
+        let right = describe_swap3_cab(&labels, 4, 0, 2);
+        swap3_cab_slice(&mut labels, 4, 0, 2);
+        assert_eq!(
+            right,
+            "rotate right: positions 4, 0, 2: [a, c, e] -> [e, a, c]"
+        );
+        assert_eq!(labels, ["a", "b", "c", "d", "e"]);
+    }
+
+    #[test]
+    fn test_describe_sequence() {
+        let labels = ["a", "b", "c", "d"];
+        let trace: alloc::vec::Vec<_> = [(0, 1, 2), (1, 2, 3)]
+            .iter()
+            .map(|&(a, b, c)| describe_swap3_bca(&labels, a, b, c))
+            .collect();
+        assert_eq!(
+            trace,
+            [
+                "rotate left: positions 0, 1, 2: [a, b, c] -> [b, c, a]",
+                "rotate left: positions 1, 2, 3: [b, c, d] -> [c, d, b]",
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_describe_out_of_bounds() {
+        describe_swap3_cab(&["a", "b"], 0, 1, 2);
+    }
+}
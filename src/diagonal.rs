@@ -0,0 +1,150 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three diagonal elements of a square matrix to the left.
+///
+/// The matrix is stored as a flat slice of `n × n` elements, so that the diagonal
+/// element `x` is located at `data[x * n + x]`.
+///
+/// ## Arguments
+///
+/// * `data` - The flat slice holding the matrix.
+/// * `n` - The number of rows and columns of the matrix.
+/// * `i` - The first diagonal index, to be assigned with the value at `j`.
+/// * `j` - The second diagonal index, to be assigned with the value at `k`.
+/// * `k` - The third diagonal index, to be assigned with the value at `i`.
+///
+/// ## Panics
+///
+/// Panics if `data` holds fewer than `n × n` elements, if any of the diagonal indices
+/// is not less than `n`, or if the diagonal indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut matrix = vec![
+///     1, 0, 0,
+///     0, 2, 0,
+///     0, 0, 3,
+/// ];
+/// swap3::swap3_bca_diagonal(&mut matrix, 3, 0, 1, 2);
+/// assert_eq!(matrix, &[
+///     2, 0, 0,
+///     0, 3, 0,
+///     0, 0, 1,
+/// ]);
+/// ```
+pub fn swap3_bca_diagonal<T>(data: &mut [T], n: usize, i: usize, j: usize, k: usize) {
+    let [i, j, k] = diagonal_offsets(data.len(), n, [i, j, k]);
+    swap3_bca_slice(data, i, j, k);
+}
+
+/// Rotates three diagonal elements of a square matrix to the right.
+///
+/// The matrix is stored as a flat slice of `n × n` elements, so that the diagonal
+/// element `x` is located at `data[x * n + x]`.
+///
+/// ## Arguments
+///
+/// * `data` - The flat slice holding the matrix.
+/// * `n` - The number of rows and columns of the matrix.
+/// * `i` - The first diagonal index, to be assigned with the value at `k`.
+/// * `j` - The second diagonal index, to be assigned with the value at `i`.
+/// * `k` - The third diagonal index, to be assigned with the value at `j`.
+///
+/// ## Panics
+///
+/// Panics if `data` holds fewer than `n × n` elements, if any of the diagonal indices
+/// is not less than `n`, or if the diagonal indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut matrix = vec![
+///     1, 0, 0,
+///     0, 2, 0,
+///     0, 0, 3,
+/// ];
+/// swap3::swap3_cab_diagonal(&mut matrix, 3, 0, 1, 2);
+/// assert_eq!(matrix, &[
+///     3, 0, 0,
+///     0, 1, 0,
+///     0, 0, 2,
+/// ]);
+/// ```
+pub fn swap3_cab_diagonal<T>(data: &mut [T], n: usize, i: usize, j: usize, k: usize) {
+    let [i, j, k] = diagonal_offsets(data.len(), n, [i, j, k]);
+    swap3_cab_slice(data, i, j, k);
+}
+
+/// Validates the matrix dimensions and maps the diagonal indices to flat offsets.
+fn diagonal_offsets(len: usize, n: usize, indices: [usize; 3]) -> [usize; 3] {
+    assert!(
+        n.checked_mul(n).is_some_and(|size| size <= len),
+        "matrix out of bounds: the len is {len} but the matrix is {n}×{n}"
+    );
+    for index in indices {
+        assert!(
+            index < n,
+            "diagonal index out of bounds: the matrix is {n}×{n} but the index is {index}"
+        );
+    }
+    let [i, j, k] = indices;
+    assert!(
+        i != j && j != k && i != k,
+        "diagonal indices must be pairwise distinct, but got {i}, {j} and {k}"
+    );
+    // As `x < n` and `n * n` does not overflow, neither does `x * n + x`.
+    indices.map(|x| x * n + x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_diagonal() {
+        let mut matrix: [u32; 16] = core::array::from_fn(|x| x as u32);
+        swap3_bca_diagonal(&mut matrix, 4, 3, 0, 2);
+        assert_eq!(
+            [matrix[15], matrix[0], matrix[10], matrix[5]],
+            [0, 10, 15, 5]
+        );
+        swap3_cab_diagonal(&mut matrix, 4, 3, 0, 2);
+        assert_eq!(matrix, core::array::from_fn(|x| x as u32));
+    }
+
+    #[test]
+    fn test_trailing_elements_ignored() {
+        let mut data = [1, 0, 0, 0, 2, 0, 0, 0, 3, 9];
+        swap3_cab_diagonal(&mut data, 3, 0, 1, 2);
+        assert_eq!(data, [3, 0, 0, 0, 1, 0, 0, 0, 2, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is 3×3 but the index is 3")]
+    fn test_index_out_of_bounds() {
+        let mut matrix = [0; 16];
+        swap3_bca_diagonal(&mut matrix, 3, 0, 1, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 8 but the matrix is 3×3")]
+    fn test_data_too_short() {
+        let mut matrix = [0; 8];
+        swap3_cab_diagonal(&mut matrix, 3, 0, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_duplicate_index() {
+        let mut matrix = [0; 9];
+        swap3_bca_diagonal(&mut matrix, 3, 1, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix out of bounds")]
+    fn test_dimension_overflow() {
+        let mut matrix = [0; 9];
+        swap3_bca_diagonal(&mut matrix, 1 << (usize::BITS / 2), 0, 1, 2);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use alloc::vec::Vec;
+use bytemuck::Pod;
+
+/// A contiguous run of changed bytes, as returned by [`swap3_bca_slice_diff`] and
+/// [`swap3_cab_slice_diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "bytemuck", feature = "alloc"))))]
+pub struct ByteDiff {
+    /// The offset of the first changed byte, in bytes from the start of the slice.
+    pub offset: usize,
+    /// The new values of the changed bytes.
+    pub bytes: Vec<u8>,
+}
+
+impl ByteDiff {
+    /// Writes the changed bytes to a byte buffer, e.g. to replay a journal.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the changed bytes are out of bounds of the buffer.
+    pub fn apply(&self, target: &mut [u8]) {
+        target[self.offset..self.offset + self.bytes.len()].copy_from_slice(&self.bytes);
+    }
+}
+
+/// Rotates three values to the left, returning the bytes that changed.
+///
+/// The returned runs of changed bytes are ordered by offset, are non-overlapping, and
+/// cover exactly the bytes whose value differs after the rotation. Runs spanning the
+/// boundary between adjacent rotated elements are merged. Bytes of the three elements
+/// that are equal before and after the rotation, e.g. because the rotated values share
+/// a common prefix, are not included.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::ByteDiff;
+///
+/// let mut data = [0x0100_u16, 0x0200, 0x0300, 0x0400];
+/// let diff = swap3::swap3_bca_slice_diff(&mut data, 0, 1, 3);
+/// assert_eq!(data, [0x0200, 0x0400, 0x0300, 0x0100]);
+///
+/// // Only the high bytes changed, which come second on little-endian targets.
+/// if cfg!(target_endian = "little") {
+///     assert_eq!(
+///         diff,
+///         [
+///             ByteDiff { offset: 1, bytes: vec![0x02] },
+///             ByteDiff { offset: 3, bytes: vec![0x04] },
+///             ByteDiff { offset: 7, bytes: vec![0x01] },
+///         ]
+///     );
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(all(feature = "bytemuck", feature = "alloc"))))]
+pub fn swap3_bca_slice_diff<T: Pod>(data: &mut [T], a: usize, b: usize, c: usize) -> Vec<ByteDiff> {
+    let old = snapshot(data, a, b, c);
+    swap3_bca_slice(data, a, b, c);
+    diff(data, [a, b, c], old)
+}
+
+/// Rotates three values to the right, returning the bytes that changed.
+///
+/// The returned runs of changed bytes are ordered by offset, are non-overlapping, and
+/// cover exactly the bytes whose value differs after the rotation. Runs spanning the
+/// boundary between adjacent rotated elements are merged. Bytes of the three elements
+/// that are equal before and after the rotation, e.g. because the rotated values share
+/// a common prefix, are not included.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::ByteDiff;
+///
+/// let mut data = [1_u8, 2, 3, 4, 5];
+/// let diff = swap3::swap3_cab_slice_diff(&mut data, 1, 2, 3);
+/// assert_eq!(data, [1, 4, 2, 3, 5]);
+/// assert_eq!(diff, [ByteDiff { offset: 1, bytes: vec![4, 2, 3] }]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(all(feature = "bytemuck", feature = "alloc"))))]
+pub fn swap3_cab_slice_diff<T: Pod>(data: &mut [T], a: usize, b: usize, c: usize) -> Vec<ByteDiff> {
+    let old = snapshot(data, a, b, c);
+    swap3_cab_slice(data, a, b, c);
+    diff(data, [a, b, c], old)
+}
+
+#[inline(always)]
+fn snapshot<T: Pod>(data: &[T], a: usize, b: usize, c: usize) -> [T; 3] {
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+    [data[a], data[b], data[c]]
+}
+
+/// Collects the runs of bytes of the elements at `indices` that differ from `old`.
+fn diff<T: Pod>(data: &[T], indices: [usize; 3], old: [T; 3]) -> Vec<ByteDiff> {
+    let size = core::mem::size_of::<T>();
+    let mut elements = [
+        (indices[0], old[0]),
+        (indices[1], old[1]),
+        (indices[2], old[2]),
+    ];
+    elements.sort_unstable_by_key(|&(index, _)| index);
+
+    let mut runs: Vec<ByteDiff> = Vec::new();
+    for (index, old) in elements {
+        let old = bytemuck::bytes_of(&old);
+        let new = bytemuck::bytes_of(&data[index]);
+        for (i, (&before, &after)) in old.iter().zip(new).enumerate() {
+            if before == after {
+                continue;
+            }
+            let offset = index * size + i;
+            match runs.last_mut() {
+                Some(run) if run.offset + run.bytes.len() == offset => run.bytes.push(after),
+                _ => runs.push(ByteDiff {
+                    offset,
+                    bytes: alloc::vec![after],
+                }),
+            }
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replaying_the_diff() {
+        let mut data: Vec<u32> = (0..8).map(|x| x * 0x0101_0101).collect();
+        let mut journal: Vec<u8> = bytemuck::cast_slice(&data).to_vec();
+        for (i, (a, b, c)) in [(0, 1, 2), (7, 3, 5), (2, 6, 1), (4, 0, 3)]
+            .into_iter()
+            .enumerate()
+        {
+            let diff = if i % 2 == 0 {
+                swap3_bca_slice_diff(&mut data, a, b, c)
+            } else {
+                swap3_cab_slice_diff(&mut data, a, b, c)
+            };
+            for run in &diff {
+                run.apply(&mut journal);
+            }
+            assert_eq!(journal, bytemuck::cast_slice::<u32, u8>(&data));
+        }
+    }
+
+    #[test]
+    fn test_diff_is_minimal() {
+        let mut data = [[7_u8, 1, 7], [7, 2, 7], [7, 3, 7]];
+        let diff = swap3_bca_slice_diff(&mut data, 0, 1, 2);
+        assert_eq!(
+            diff,
+            [
+                ByteDiff {
+                    offset: 1,
+                    bytes: vec![2]
+                },
+                ByteDiff {
+                    offset: 4,
+                    bytes: vec![3]
+                },
+                ByteDiff {
+                    offset: 7,
+                    bytes: vec![1]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_equal_values_produce_no_diff() {
+        let mut data = [5_u64; 4];
+        assert!(swap3_cab_slice_diff(&mut data, 3, 1, 0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_invalid_indices() {
+        let mut data = [1_u16, 2, 3];
+        swap3_bca_slice_diff(&mut data, 0, 2, 2);
+    }
+}
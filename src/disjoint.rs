@@ -0,0 +1,126 @@
+/// Returns mutable references to the elements at three pairwise distinct indices.
+///
+/// Bounds and distinctness are validated in a single pass using `<[T]>::get_disjoint_mut`
+/// where available, or an equivalent fallback on older toolchains.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+#[inline(always)]
+pub(crate) fn get3_mut<T>(data: &mut [T], a: usize, b: usize, c: usize) -> [&mut T; 3] {
+    let len = data.len();
+
+    #[cfg(has_get_disjoint_mut)]
+    let refs = data.get_disjoint_mut([a, b, c]).ok();
+    #[cfg(not(has_get_disjoint_mut))]
+    let refs = get3_mut_fallback(data, a, b, c);
+
+    match refs {
+        Some(refs) => refs,
+        None => invalid_indices(len, a, b, c),
+    }
+}
+
+/// Fallback for `<[T]>::get_disjoint_mut` based on `split_at_mut`.
+#[cfg(any(not(has_get_disjoint_mut), test))]
+fn get3_mut_fallback<T>(data: &mut [T], a: usize, b: usize, c: usize) -> Option<[&mut T; 3]> {
+    let len = data.len();
+    if a >= len || b >= len || c >= len || a == b || b == c || a == c {
+        return None;
+    }
+
+    // Sort the indices while remembering their original positions.
+    let mut order = [(a, 0), (b, 1), (c, 2)];
+    order.sort_unstable();
+    let [(first, _), (second, _), (third, _)] = order;
+
+    let (head, rest) = data.split_at_mut(second);
+    let (middle, tail) = rest.split_at_mut(third - second);
+
+    let mut refs = [None, None, None];
+    refs[order[0].1] = Some(&mut head[first]);
+    refs[order[1].1] = Some(&mut middle[0]);
+    refs[order[2].1] = Some(&mut tail[0]);
+    let [x, y, z] = refs;
+    Some([x?, y?, z?])
+}
+
+/// Panics if the indices are not pairwise distinct, regardless of any bounds.
+#[cfg(any(feature = "unsafe", feature = "alloc"))]
+#[inline(always)]
+pub(crate) fn assert_distinct(a: usize, b: usize, c: usize) {
+    if a == b || b == c || a == c {
+        coinciding_indices(a, b, c);
+    }
+}
+
+/// Panics if any of the indices is out of bounds for a slice of length `len` or if the indices
+/// are not pairwise distinct.
+#[inline(always)]
+pub(crate) fn assert_indices(len: usize, a: usize, b: usize, c: usize) {
+    if crate::error::check_indices(len, a, b, c).is_err() {
+        invalid_indices(len, a, b, c);
+    }
+}
+
+#[cold]
+#[inline(never)]
+pub(crate) fn invalid_indices(len: usize, a: usize, b: usize, c: usize) -> ! {
+    for index in [a, b, c] {
+        if index >= len {
+            panic!("index out of bounds: the len is {len} but the index is {index}");
+        }
+    }
+    coinciding_indices(a, b, c);
+}
+
+#[cold]
+#[inline(never)]
+fn coinciding_indices(a: usize, b: usize, c: usize) -> ! {
+    panic!("indices must be pairwise distinct, but got {a}, {b} and {c}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get3_mut() {
+        let mut data = [1, 2, 3, 4];
+        let [a, b, c] = get3_mut(&mut data, 3, 0, 2);
+        assert_eq!([*a, *b, *c], [4, 1, 3]);
+    }
+
+    #[test]
+    fn test_fallback_matches_get3_mut() {
+        for a in 0..5 {
+            for b in 0..5 {
+                for c in 0..5 {
+                    let mut data = [1, 2, 3, 4];
+                    let expected = if a < 4 && b < 4 && c < 4 && a != b && b != c && a != c {
+                        Some([data[a], data[b], data[c]])
+                    } else {
+                        None
+                    };
+                    let actual =
+                        get3_mut_fallback(&mut data, a, b, c).map(|[x, y, z]| [*x, *y, *z]);
+                    assert_eq!(actual, expected, "with {a}, {b}, {c}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 4 but the index is 4")]
+    fn test_out_of_bounds() {
+        let mut data = [1, 2, 3, 4];
+        get3_mut(&mut data, 0, 4, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "indices must be pairwise distinct, but got 0, 1 and 0")]
+    fn test_overlapping() {
+        let mut data = [1, 2, 3, 4];
+        get3_mut(&mut data, 0, 1, 0);
+    }
+}
@@ -0,0 +1,172 @@
+/// A triple of indices `A`, `B` and `C` known to be pairwise distinct at compile time.
+///
+/// Constructing a `Distinct3` with coinciding indices fails to compile, which rules out
+/// the duplicate-index case for statically known index triples. Applying the rotation
+/// therefore only needs to check the bounds of the indices.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Distinct3;
+///
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// Distinct3::<0, 1, 4>::new().apply_bca(&mut vec);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+/// ```
+///
+/// Coinciding indices are rejected during compilation:
+///
+/// ```compile_fail
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::Distinct3::<0, 1, 0>::new().apply_bca(&mut vec);
+/// ```
+///
+/// This includes triples created through [`Default`]:
+///
+/// ```compile_fail
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::Distinct3::<0, 1, 0>::default().apply_bca(&mut vec);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Distinct3<const A: usize, const B: usize, const C: usize> {
+    _private: (),
+}
+
+impl<const A: usize, const B: usize, const C: usize> Distinct3<A, B, C> {
+    const ASSERT_DISTINCT: () = assert!(
+        A != B && B != C && A != C,
+        "indices must be pairwise distinct"
+    );
+
+    /// Creates the index triple, failing to compile if the indices are not pairwise distinct.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_DISTINCT;
+        Self { _private: () }
+    }
+
+    /// Returns the indices as a tuple `(A, B, C)`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// assert_eq!(swap3::Distinct3::<3, 1, 2>::new().indices(), (3, 1, 2));
+    /// ```
+    #[inline(always)]
+    pub const fn indices(self) -> (usize, usize, usize) {
+        (A, B, C)
+    }
+
+    /// Checks the bounds of the indices before any element is moved.
+    #[inline(always)]
+    fn check_bounds(len: usize) {
+        if A >= len || B >= len || C >= len {
+            crate::disjoint::invalid_indices(len, A, B, C);
+        }
+    }
+
+    /// Rotates the values at the indices to the left.
+    ///
+    /// The element at `A` is assigned with the value of `data[B]`, the element at `B`
+    /// with the value of `data[C]` and the element at `C` with the value of `data[A]`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds. The slice is left unchanged in this case.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut vec = vec![50, 10, 90, 25, 30, 75];
+    /// swap3::Distinct3::<0, 1, 4>::new().apply_bca(&mut vec);
+    /// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+    /// ```
+    #[inline(always)]
+    pub fn apply_bca<T>(self, data: &mut [T]) {
+        Self::check_bounds(data.len());
+        // Both swaps exchange distinct elements, so no distinctness check is needed.
+        data.swap(A, B);
+        data.swap(B, C);
+    }
+
+    /// Rotates the values at the indices to the right.
+    ///
+    /// The element at `A` is assigned with the value of `data[C]`, the element at `B`
+    /// with the value of `data[A]` and the element at `C` with the value of `data[B]`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds. The slice is left unchanged in this case.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut vec = vec![50, 10, 90, 25, 30, 75];
+    /// swap3::Distinct3::<0, 1, 4>::new().apply_cab(&mut vec);
+    /// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+    /// ```
+    #[inline(always)]
+    pub fn apply_cab<T>(self, data: &mut [T]) {
+        Self::check_bounds(data.len());
+        // Both swaps exchange distinct elements, so no distinctness check is needed.
+        data.swap(A, C);
+        data.swap(B, C);
+    }
+}
+
+impl<const A: usize, const B: usize, const C: usize> Default for Distinct3<A, B, C> {
+    /// Creates the index triple, failing to compile if the indices are not pairwise distinct.
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{swap3_bca_slice, swap3_cab_slice};
+
+    const INDICES: Distinct3<4, 0, 2> = Distinct3::new();
+
+    #[test]
+    fn test_matches_slice_rotation() {
+        let mut expected = [1, 2, 3, 4, 5];
+        let mut actual = expected;
+        swap3_bca_slice(&mut expected, 4, 0, 2);
+        INDICES.apply_bca(&mut actual);
+        assert_eq!(actual, expected);
+        swap3_cab_slice(&mut expected, 4, 0, 2);
+        INDICES.apply_cab(&mut actual);
+        assert_eq!(actual, expected);
+        assert_eq!(actual, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut data = ["a", "b", "c", "d"];
+        let indices = Distinct3::<3, 1, 0>::new();
+        assert_eq!(indices.indices(), (3, 1, 0));
+        indices.apply_bca(&mut data);
+        assert_eq!(data, ["d", "a", "c", "b"]);
+        indices.apply_cab(&mut data);
+        assert_eq!(data, ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_out_of_bounds() {
+        Distinct3::<0, 1, 5>::new().apply_cab(&mut [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_out_of_bounds_leaves_data_unchanged() {
+        let mut data = [1, 2, 3];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            Distinct3::<0, 1, 5>::default().apply_bca(&mut data);
+        }));
+        assert!(result.is_err());
+        assert_eq!(data, [1, 2, 3]);
+    }
+}
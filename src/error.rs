@@ -0,0 +1,109 @@
+use core::fmt::{Display, Formatter};
+
+/// The error returned by the checked rotation functions such as [`try_swap3_bca_slice`](crate::try_swap3_bca_slice).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Swap3Error {
+    /// The slice is empty, hence no index can be valid.
+    EmptySlice,
+    /// An index is out of bounds for the slice.
+    IndexOutOfBounds {
+        /// The offending index.
+        index: usize,
+        /// The length of the slice.
+        len: usize,
+    },
+    /// The same index was given more than once.
+    DuplicateIndex {
+        /// The duplicated index.
+        index: usize,
+    },
+}
+
+impl Display for Swap3Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Swap3Error::EmptySlice => write!(f, "the slice is empty"),
+            Swap3Error::IndexOutOfBounds { index, len } => write!(
+                f,
+                "index out of bounds: the len is {len} but the index is {index}"
+            ),
+            Swap3Error::DuplicateIndex { index } => {
+                write!(f, "the index {index} was given more than once")
+            }
+        }
+    }
+}
+
+// `std::error::Error` is a re-export of `core::error::Error` where the latter is available,
+// which makes the error usable in `no_std` builds as well.
+#[cfg(has_core_error)]
+impl core::error::Error for Swap3Error {}
+
+#[cfg(all(not(has_core_error), feature = "std"))]
+impl std::error::Error for Swap3Error {}
+
+/// Ensures that all indices are in bounds for a slice of length `len` and pairwise distinct.
+pub(crate) fn check_indices(len: usize, a: usize, b: usize, c: usize) -> Result<(), Swap3Error> {
+    if len == 0 {
+        return Err(Swap3Error::EmptySlice);
+    }
+    for index in [a, b, c] {
+        if index >= len {
+            return Err(Swap3Error::IndexOutOfBounds { index, len });
+        }
+    }
+    if a == b || a == c {
+        return Err(Swap3Error::DuplicateIndex { index: a });
+    }
+    if b == c {
+        return Err(Swap3Error::DuplicateIndex { index: b });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Swap3Error::EmptySlice.to_string(), "the slice is empty");
+        assert_eq!(
+            Swap3Error::IndexOutOfBounds { index: 3, len: 2 }.to_string(),
+            "index out of bounds: the len is 2 but the index is 3"
+        );
+        assert_eq!(
+            Swap3Error::DuplicateIndex { index: 1 }.to_string(),
+            "the index 1 was given more than once"
+        );
+    }
+
+    #[test]
+    #[cfg(has_core_error)]
+    fn test_error_trait() {
+        fn source(error: &dyn core::error::Error) -> Option<&dyn core::error::Error> {
+            error.source()
+        }
+
+        let error = Swap3Error::DuplicateIndex { index: 1 };
+        assert!(source(&error).is_none());
+
+        let boxed: Box<dyn std::error::Error> = Box::new(error);
+        assert_eq!(boxed.to_string(), "the index 1 was given more than once");
+    }
+
+    #[test]
+    fn test_check_indices() {
+        assert_eq!(check_indices(0, 0, 0, 0), Err(Swap3Error::EmptySlice));
+        assert_eq!(
+            check_indices(3, 0, 3, 1),
+            Err(Swap3Error::IndexOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(
+            check_indices(3, 1, 2, 1),
+            Err(Swap3Error::DuplicateIndex { index: 1 })
+        );
+        assert_eq!(check_indices(3, 0, 2, 1), Ok(()));
+    }
+}
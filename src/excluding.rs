@@ -0,0 +1,117 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values to the left while handing out a fourth one, `held`.
+///
+/// The rotation is applied to the elements at `a`, `b` and `c`, after which a mutable
+/// reference to the element at `held` is returned. Since `held` is guaranteed not to take
+/// part in the rotation, the returned reference can be used to continue working with that
+/// element, e.g. as the cursor of an algorithm that rotates the elements around it.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `held` - The index of the element to return, which must differ from `a`, `b` and `c`.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds, if the indices `a`, `b` and `c` are not
+/// pairwise distinct, or if `held` is one of them. The slice is left unchanged in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// let held = swap3::swap3_bca_excluding(&mut vec, 2, 0, 1, 4);
+/// *held += 1;
+/// assert_eq!(vec, &[10, 30, 91, 25, 50, 75]);
+/// ```
+pub fn swap3_bca_excluding<T>(data: &mut [T], held: usize, a: usize, b: usize, c: usize) -> &mut T {
+    assert_excluded(data.len(), held, a, b, c);
+    swap3_bca_slice(data, a, b, c);
+    &mut data[held]
+}
+
+/// Rotates three values to the right while handing out a fourth one, `held`.
+///
+/// The rotation is applied to the elements at `a`, `b` and `c`, after which a mutable
+/// reference to the element at `held` is returned. Since `held` is guaranteed not to take
+/// part in the rotation, the returned reference can be used to continue working with that
+/// element, e.g. as the cursor of an algorithm that rotates the elements around it.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `held` - The index of the element to return, which must differ from `a`, `b` and `c`.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds, if the indices `a`, `b` and `c` are not
+/// pairwise distinct, or if `held` is one of them. The slice is left unchanged in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// let held = swap3::swap3_cab_excluding(&mut vec, 2, 0, 1, 4);
+/// *held += 1;
+/// assert_eq!(vec, &[30, 50, 91, 25, 10, 75]);
+/// ```
+pub fn swap3_cab_excluding<T>(data: &mut [T], held: usize, a: usize, b: usize, c: usize) -> &mut T {
+    assert_excluded(data.len(), held, a, b, c);
+    swap3_cab_slice(data, a, b, c);
+    &mut data[held]
+}
+
+/// Panics unless all indices are valid and `held` does not take part in the rotation.
+#[inline(always)]
+fn assert_excluded(len: usize, held: usize, a: usize, b: usize, c: usize) {
+    assert!(
+        held < len,
+        "index out of bounds: the len is {len} but the held index is {held}"
+    );
+    assert!(
+        held != a && held != b && held != c,
+        "the held index {held} must not be rotated, but got {a}, {b} and {c}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_held_borrow_outlives_rotations() {
+        let mut data = [1, 2, 3, 4, 5];
+        let cursor = swap3_bca_excluding(&mut data, 0, 2, 3, 4);
+        *cursor *= 10;
+        assert_eq!(data, [10, 2, 4, 5, 3]);
+
+        let cursor = swap3_cab_excluding(&mut data, 0, 2, 3, 4);
+        *cursor /= 10;
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the held index 3 must not be rotated, but got 0, 3 and 4")]
+    fn test_held_in_rotation() {
+        swap3_bca_excluding(&mut [1, 2, 3, 4, 5], 3, 0, 3, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 5 but the held index is 5")]
+    fn test_held_out_of_bounds() {
+        swap3_cab_excluding(&mut [1, 2, 3, 4, 5], 5, 0, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 5 but the index is 7")]
+    fn test_rotated_out_of_bounds() {
+        swap3_cab_excluding(&mut [1, 2, 3, 4, 5], 4, 0, 1, 7);
+    }
+}
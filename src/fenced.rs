@@ -0,0 +1,157 @@
+use crate::sync::{fence, Ordering};
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values to the left between memory fences of the given ordering.
+///
+/// The rotation itself consists of plain, non-atomic moves and is not atomic as a whole;
+/// observers accessing the values concurrently may see any intermediate state. The fences
+/// only control how the rotation is ordered relative to atomic operations of the caller:
+///
+/// * The acquire part of `order` issues a leading fence. If the caller loaded an atomic
+///   before the call, e.g. a flag handing over the buffer, the rotation is ordered after the
+///   writes of whoever released that flag.
+/// * The release part of `order` issues a trailing fence. If the caller stores to an atomic
+///   after the call, even with [`Ordering::Relaxed`], observers acquiring that store also
+///   observe the complete rotation.
+///
+/// Hence [`Ordering::Acquire`] only issues the leading fence, [`Ordering::Release`] only the
+/// trailing fence, and [`Ordering::AcqRel`] and [`Ordering::SeqCst`] both. With
+/// [`Ordering::Relaxed`], no fences are issued. For buffers in memory shared between
+/// processes, the same guarantees hold as long as the other side uses matching atomic
+/// operations on the same memory.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `order` - The ordering of the fences around the rotation.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// let ready = AtomicBool::new(false);
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+///
+/// swap3::swap3_bca_slice_fenced(&mut vec, 0, 1, 4, Ordering::Release);
+/// // Observers acquiring the flag observe the rotated values.
+/// ready.store(true, Ordering::Relaxed);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+/// ```
+pub fn swap3_bca_slice_fenced<T>(data: &mut [T], a: usize, b: usize, c: usize, order: Ordering) {
+    if let Some(order) = leading(order) {
+        fence(order);
+    }
+    swap3_bca_slice(data, a, b, c);
+    if let Some(order) = trailing(order) {
+        fence(order);
+    }
+}
+
+/// Rotates three values to the right between memory fences of the given ordering.
+///
+/// See [`swap3_bca_slice_fenced`] for the placement of the fences and the guarantees
+/// they provide.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `order` - The ordering of the fences around the rotation.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::atomic::Ordering;
+///
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_cab_slice_fenced(&mut vec, 0, 1, 4, Ordering::AcqRel);
+/// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+/// ```
+pub fn swap3_cab_slice_fenced<T>(data: &mut [T], a: usize, b: usize, c: usize, order: Ordering) {
+    if let Some(order) = leading(order) {
+        fence(order);
+    }
+    swap3_cab_slice(data, a, b, c);
+    if let Some(order) = trailing(order) {
+        fence(order);
+    }
+}
+
+/// Returns the ordering of the fence before the rotation, if any.
+#[inline(always)]
+fn leading(order: Ordering) -> Option<Ordering> {
+    match order {
+        Ordering::Relaxed | Ordering::Release => None,
+        Ordering::Acquire | Ordering::AcqRel => Some(Ordering::Acquire),
+        _ => Some(Ordering::SeqCst),
+    }
+}
+
+/// Returns the ordering of the fence after the rotation, if any.
+#[inline(always)]
+fn trailing(order: Ordering) -> Option<Ordering> {
+    match order {
+        Ordering::Relaxed | Ordering::Acquire => None,
+        Ordering::Release | Ordering::AcqRel => Some(Ordering::Release),
+        _ => Some(Ordering::SeqCst),
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::*;
+
+    const ORDERINGS: [Ordering; 5] = [
+        Ordering::Relaxed,
+        Ordering::Acquire,
+        Ordering::Release,
+        Ordering::AcqRel,
+        Ordering::SeqCst,
+    ];
+
+    #[test]
+    fn test_all_orderings() {
+        let mut data = [1, 2, 3, 4];
+        for order in ORDERINGS {
+            swap3_bca_slice_fenced(&mut data, 0, 1, 3, order);
+            assert_eq!(data, [2, 4, 3, 1]);
+            swap3_cab_slice_fenced(&mut data, 0, 1, 3, order);
+            assert_eq!(data, [1, 2, 3, 4]);
+        }
+    }
+
+    #[test]
+    fn test_fence_placement() {
+        let fences = ORDERINGS.map(|order| (leading(order), trailing(order)));
+        assert_eq!(
+            fences,
+            [
+                (None, None),
+                (Some(Ordering::Acquire), None),
+                (None, Some(Ordering::Release)),
+                (Some(Ordering::Acquire), Some(Ordering::Release)),
+                (Some(Ordering::SeqCst), Some(Ordering::SeqCst)),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_out_of_bounds() {
+        swap3_bca_slice_fenced(&mut [1, 2, 3], 0, 1, 3, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,166 @@
+//! Support for exporting rotations to C.
+//!
+//! The [`export_swap3_c`](crate::export_swap3_c) macro generates `#[no_mangle] extern "C"`
+//! functions for a fixed element type, taking a raw pointer, a length and three indices.
+//! Instead of panicking across the FFI boundary, the generated functions report invalid
+//! arguments using the status codes defined in this module.
+//!
+//! ## Example
+//!
+//! ```
+//! swap3::export_swap3_c!(u64, swap3_bca_u64, swap3_cab_u64);
+//!
+//! let mut data = [10_u64, 20, 30, 40];
+//! let status = unsafe { swap3_bca_u64(data.as_mut_ptr(), data.len(), 0, 1, 3) };
+//! assert_eq!(status, swap3::ffi::OK);
+//! assert_eq!(data, [20, 40, 30, 10]);
+//!
+//! let status = unsafe { swap3_cab_u64(data.as_mut_ptr(), data.len(), 0, 1, 4) };
+//! assert_eq!(status, swap3::ffi::ERR_OUT_OF_BOUNDS);
+//! assert_eq!(data, [20, 40, 30, 10]);
+//! ```
+//!
+//! On the C side, the functions above would be declared as
+//!
+//! ```c
+//! int swap3_bca_u64(uint64_t *data, size_t len, size_t a, size_t b, size_t c);
+//! int swap3_cab_u64(uint64_t *data, size_t len, size_t a, size_t b, size_t c);
+//! ```
+
+use crate::Swap3Error;
+use core::ffi::c_int;
+
+/// The status code indicating a successful rotation.
+pub const OK: c_int = 0;
+
+/// The status code indicating that a null pointer was passed for the data.
+pub const ERR_NULL_POINTER: c_int = -1;
+
+/// The status code indicating that an index is out of bounds, including any index into
+/// an empty buffer.
+pub const ERR_OUT_OF_BOUNDS: c_int = -2;
+
+/// The status code indicating that the same index was given more than once.
+pub const ERR_DUPLICATE_INDEX: c_int = -3;
+
+/// Converts the result of a checked rotation into a status code.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::ffi::{status_code, ERR_DUPLICATE_INDEX, OK};
+///
+/// let mut data = [1, 2, 3];
+/// assert_eq!(status_code(swap3::try_swap3_bca_slice(&mut data, 0, 1, 2)), OK);
+/// assert_eq!(
+///     status_code(swap3::try_swap3_bca_slice(&mut data, 0, 1, 1)),
+///     ERR_DUPLICATE_INDEX
+/// );
+/// ```
+pub const fn status_code(result: Result<(), Swap3Error>) -> c_int {
+    match result {
+        Ok(()) => OK,
+        Err(Swap3Error::EmptySlice | Swap3Error::IndexOutOfBounds { .. }) => ERR_OUT_OF_BOUNDS,
+        Err(Swap3Error::DuplicateIndex { .. }) => ERR_DUPLICATE_INDEX,
+    }
+}
+
+/// Generates `#[no_mangle] extern "C"` rotation functions for a fixed element type.
+///
+/// `export_swap3_c!(T, name)` generates a function `name` rotating to the left, i.e. like
+/// [`try_swap3_bca_slice`](crate::try_swap3_bca_slice). `export_swap3_c!(T, left, right)`
+/// additionally generates a function `right` rotating to the right, i.e. like
+/// [`try_swap3_cab_slice`](crate::try_swap3_cab_slice). The generated functions have the
+/// signature
+///
+/// ```text
+/// pub unsafe extern "C" fn name(data: *mut T, len: usize, a: usize, b: usize, c: usize) -> c_int
+/// ```
+///
+/// and return one of the status codes of the [`ffi`](crate::ffi) module. The elements are
+/// left unchanged unless [`OK`](crate::ffi::OK) is returned. `T` should be a type with a
+/// C-compatible representation.
+///
+/// ## Safety
+///
+/// Calling a generated function requires `data` to either be null, or to be valid for
+/// reads and writes of `len` consecutive, properly aligned and initialized values of type
+/// `T` that are not accessed through any other pointer for the duration of the call.
+///
+/// ## Example
+///
+/// ```
+/// swap3::export_swap3_c!(u32, swap3_u32);
+///
+/// let mut data = [1_u32, 2, 3];
+/// assert_eq!(unsafe { swap3_u32(data.as_mut_ptr(), 3, 0, 1, 2) }, swap3::ffi::OK);
+/// assert_eq!(data, [2, 3, 1]);
+///
+/// let status = unsafe { swap3_u32(core::ptr::null_mut(), 3, 0, 1, 2) };
+/// assert_eq!(status, swap3::ffi::ERR_NULL_POINTER);
+/// ```
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+macro_rules! export_swap3_c {
+    ($ty:ty, $left:ident) => {
+        $crate::export_swap3_c!(@export $ty, $left, try_swap3_bca_slice);
+    };
+    ($ty:ty, $left:ident, $right:ident) => {
+        $crate::export_swap3_c!(@export $ty, $left, try_swap3_bca_slice);
+        $crate::export_swap3_c!(@export $ty, $right, try_swap3_cab_slice);
+    };
+    (@export $ty:ty, $name:ident, $rotate:ident) => {
+        /// Rotates three elements of a C array, returning a status code.
+        ///
+        /// # Safety
+        ///
+        /// `data` must either be null, or be valid for reads and writes of `len`
+        /// consecutive, properly aligned and initialized elements that are not
+        /// accessed through any other pointer for the duration of the call.
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(
+            data: *mut $ty,
+            len: usize,
+            a: usize,
+            b: usize,
+            c: usize,
+        ) -> ::core::ffi::c_int {
+            if data.is_null() {
+                return $crate::ffi::ERR_NULL_POINTER;
+            }
+            // SAFETY: `data` is non-null, and the caller guarantees that it is valid for
+            // `len` elements and not aliased.
+            let data = unsafe { ::core::slice::from_raw_parts_mut(data, len) };
+            $crate::ffi::status_code($crate::$rotate(data, a, b, c))
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_code() {
+        assert_eq!(status_code(Ok(())), OK);
+        assert_eq!(status_code(Err(Swap3Error::EmptySlice)), ERR_OUT_OF_BOUNDS);
+        assert_eq!(
+            status_code(Err(Swap3Error::IndexOutOfBounds { index: 3, len: 3 })),
+            ERR_OUT_OF_BOUNDS
+        );
+        assert_eq!(
+            status_code(Err(Swap3Error::DuplicateIndex { index: 1 })),
+            ERR_DUPLICATE_INDEX
+        );
+    }
+
+    #[test]
+    fn test_status_codes_are_distinct() {
+        let codes = [OK, ERR_NULL_POINTER, ERR_OUT_OF_BOUNDS, ERR_DUPLICATE_INDEX];
+        for (i, a) in codes.iter().enumerate() {
+            for b in &codes[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+}
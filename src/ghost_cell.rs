@@ -0,0 +1,143 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use ghost_cell::{GhostCell, GhostToken};
+
+/// Rotates the contents of three [`GhostCell`]s of a slice to the left.
+///
+/// Access is granted by the token of the brand, so unlike with
+/// [`swap3_bca_refcells`](crate::swap3_bca_refcells), no borrow state is tracked or checked at
+/// runtime. The slice of cells is viewed as a single cell of a slice, which the token
+/// borrows mutably; only the indices are validated.
+///
+/// ## Arguments
+///
+/// * `data` - The cells whose values to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `token` - The token of the brand of the cells.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use ghost_cell::{GhostCell, GhostToken};
+///
+/// let values = GhostToken::new(|mut token| {
+///     let cells = [10, 20, 30, 40].map(GhostCell::new);
+///     // The cells may be shared freely, e.g. by the nodes of a graph.
+///     let shared = &cells;
+///     swap3::swap3_bca_ghostcells(shared, 3, 0, 1, &mut token);
+///     cells.map(GhostCell::into_inner)
+/// });
+/// assert_eq!(values, [20, 40, 30, 10]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "ghost-cell")))]
+pub fn swap3_bca_ghostcells<'brand, T>(
+    data: &[GhostCell<'brand, T>],
+    a: usize,
+    b: usize,
+    c: usize,
+    token: &mut GhostToken<'brand>,
+) {
+    swap3_bca_slice(
+        GhostCell::from_slice_of_cells(data).borrow_mut(token),
+        a,
+        b,
+        c,
+    );
+}
+
+/// Rotates the contents of three [`GhostCell`]s of a slice to the right.
+///
+/// Access is granted by the token of the brand, so unlike with
+/// [`swap3_cab_refcells`](crate::swap3_cab_refcells), no borrow state is tracked or checked at
+/// runtime. The slice of cells is viewed as a single cell of a slice, which the token
+/// borrows mutably; only the indices are validated.
+///
+/// ## Arguments
+///
+/// * `data` - The cells whose values to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `token` - The token of the brand of the cells.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use ghost_cell::{GhostCell, GhostToken};
+///
+/// let values = GhostToken::new(|mut token| {
+///     let cells = [10, 20, 30, 40].map(GhostCell::new);
+///     swap3::swap3_cab_ghostcells(&cells, 3, 0, 1, &mut token);
+///     cells.map(GhostCell::into_inner)
+/// });
+/// assert_eq!(values, [40, 10, 30, 20]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "ghost-cell")))]
+pub fn swap3_cab_ghostcells<'brand, T>(
+    data: &[GhostCell<'brand, T>],
+    a: usize,
+    b: usize,
+    c: usize,
+    token: &mut GhostToken<'brand>,
+) {
+    swap3_cab_slice(
+        GhostCell::from_slice_of_cells(data).borrow_mut(token),
+        a,
+        b,
+        c,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_ghostcells() {
+        GhostToken::new(|mut token| {
+            let cells = [1, 2, 3, 4, 5].map(GhostCell::new);
+            swap3_bca_ghostcells(&cells, 4, 0, 2, &mut token);
+            swap3_cab_ghostcells(&cells, 4, 0, 2, &mut token);
+            swap3_bca_ghostcells(&cells, 0, 2, 4, &mut token);
+            let values: Vec<_> = cells.iter().map(|cell| *cell.borrow(&token)).collect();
+            assert_eq!(values, [3, 2, 5, 4, 1]);
+        });
+    }
+
+    #[test]
+    fn test_shared_between_owners() {
+        /// A node holding a shared reference to the cells, as in a graph.
+        struct Node<'a, 'brand> {
+            cells: &'a [GhostCell<'brand, String>],
+        }
+
+        GhostToken::new(|mut token| {
+            let cells = ["a", "b", "c"].map(|s| GhostCell::new(String::from(s)));
+            let first = Node { cells: &cells };
+            let second = Node { cells: &cells };
+            swap3_bca_ghostcells(first.cells, 0, 1, 2, &mut token);
+            swap3_bca_ghostcells(second.cells, 0, 1, 2, &mut token);
+            assert_eq!(cells[0].borrow(&token), "c");
+            assert_eq!(cells[1].borrow(&token), "a");
+            assert_eq!(cells[2].borrow(&token), "b");
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_overlapping() {
+        GhostToken::new(|mut token| {
+            let cells = [1, 2, 3].map(GhostCell::new);
+            swap3_cab_ghostcells(&cells, 0, 2, 2, &mut token);
+        });
+    }
+}
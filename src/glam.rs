@@ -0,0 +1,106 @@
+use glam::Vec3Swizzles;
+
+/// Rotates the components of a three-dimensional [`glam`] vector to the left,
+/// i.e. `xyz` → `yzx`.
+///
+/// This is available for all three-dimensional vector types of `glam`, such
+/// as [`Vec3`](glam::Vec3), [`Vec3A`](glam::Vec3A), [`DVec3`](glam::DVec3),
+/// [`IVec3`](glam::IVec3) and [`UVec3`](glam::UVec3).
+///
+/// ## Arguments
+///
+/// * `v` - The vector whose components to swap. The `x` component is assigned with
+///   the value of `y`, `y` with the value of `z`, and `z` with the value of `x`.
+///
+/// ## Example
+///
+/// ```
+/// use glam::Vec3;
+///
+/// let mut v = Vec3::new(1.0, 2.0, 3.0);
+/// swap3::swap3_bca_vec3(&mut v);
+/// assert_eq!(v, Vec3::new(2.0, 3.0, 1.0));
+/// ```
+#[inline(always)]
+#[cfg_attr(docsrs, doc(cfg(feature = "glam")))]
+pub fn swap3_bca_vec3<V: Vec3Swizzles>(v: &mut V) {
+    *v = v.yzx();
+}
+
+/// Rotates the components of a three-dimensional [`glam`] vector to the right,
+/// i.e. `xyz` → `zxy`.
+///
+/// This is available for all three-dimensional vector types of `glam`, such
+/// as [`Vec3`](glam::Vec3), [`Vec3A`](glam::Vec3A), [`DVec3`](glam::DVec3),
+/// [`IVec3`](glam::IVec3) and [`UVec3`](glam::UVec3).
+///
+/// ## Arguments
+///
+/// * `v` - The vector whose components to swap. The `x` component is assigned with
+///   the value of `z`, `y` with the value of `x`, and `z` with the value of `y`.
+///
+/// ## Example
+///
+/// ```
+/// use glam::IVec3;
+///
+/// let mut v = IVec3::new(1, 2, 3);
+/// swap3::swap3_cab_vec3(&mut v);
+/// assert_eq!(v, IVec3::new(3, 1, 2));
+/// ```
+#[inline(always)]
+#[cfg_attr(docsrs, doc(cfg(feature = "glam")))]
+pub fn swap3_cab_vec3<V: Vec3Swizzles>(v: &mut V) {
+    *v = v.zxy();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{swap3_bca, swap3_cab};
+    use glam::{DVec3, I64Vec3, IVec3, U64Vec3, UVec3, Vec3, Vec3A};
+
+    /// Checks the component rotation against [`swap3_bca`] and [`swap3_cab`] on the
+    /// components as an array.
+    fn check<V, S>(v: V, to_array: impl Fn(&V) -> [S; 3])
+    where
+        V: Vec3Swizzles + PartialEq + core::fmt::Debug,
+        S: PartialEq + core::fmt::Debug,
+    {
+        let mut left = v;
+        swap3_bca_vec3(&mut left);
+        let [mut x, mut y, mut z] = to_array(&v);
+        swap3_bca(&mut x, &mut y, &mut z);
+        assert_eq!(to_array(&left), [x, y, z]);
+
+        let mut right = v;
+        swap3_cab_vec3(&mut right);
+        let [mut x, mut y, mut z] = to_array(&v);
+        swap3_cab(&mut x, &mut y, &mut z);
+        assert_eq!(to_array(&right), [x, y, z]);
+
+        swap3_bca_vec3(&mut right);
+        assert_eq!(right, v);
+    }
+
+    #[test]
+    fn test_vector_types() {
+        check(Vec3::new(1.0, 2.0, 3.0), Vec3::to_array);
+        check(Vec3A::new(1.0, 2.0, 3.0), Vec3A::to_array);
+        check(DVec3::new(1.0, 2.0, 3.0), DVec3::to_array);
+        check(IVec3::new(-1, 2, -3), IVec3::to_array);
+        check(UVec3::new(1, 2, 3), UVec3::to_array);
+        check(I64Vec3::new(-1, 2, -3), I64Vec3::to_array);
+        check(U64Vec3::new(1, 2, 3), U64Vec3::to_array);
+    }
+
+    #[test]
+    fn test_three_rotations_are_identity() {
+        let v = Vec3A::new(1.0, 2.0, 3.0);
+        let mut w = v;
+        for _ in 0..3 {
+            swap3_cab_vec3(&mut w);
+        }
+        assert_eq!(w, v);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{swap3_bca, swap3_cab, Rotation, Swap3Error};
+
+/// Three validated positions of a slice, repeatedly rotated without revalidation.
+///
+/// The guard borrows the slice mutably and holds references to the three elements, which
+/// are validated once on construction. Each rotation then only moves the values, without any
+/// bounds or distinctness checks, which benefits tight loops rotating the same positions,
+/// e.g. cycling through animation frames.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Swap3Guard;
+///
+/// let mut frames = vec!["idle", "walk", "run", "jump"];
+/// let mut rot = Swap3Guard::new(&mut frames, 0, 1, 2)?;
+/// for _ in 0..4 {
+///     rot.bca();
+/// }
+/// assert_eq!(rot.values(), [&"walk", &"run", &"idle"]);
+/// drop(rot);
+///
+/// assert_eq!(frames, ["walk", "run", "idle", "jump"]);
+/// # Ok::<(), swap3::Swap3Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Swap3Guard<'a, T> {
+    refs: [&'a mut T; 3],
+    indices: [usize; 3],
+}
+
+impl<'a, T> Swap3Guard<'a, T> {
+    /// Validates three positions of a slice for repeated rotations.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to swap.
+    /// * `a` - The first index.
+    /// * `b` - The second index.
+    /// * `c` - The third index.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the slice is empty, if any of the indices is out of bounds,
+    /// or if the indices are not pairwise distinct.
+    pub fn new(data: &'a mut [T], a: usize, b: usize, c: usize) -> Result<Self, Swap3Error> {
+        crate::error::check_indices(data.len(), a, b, c)?;
+        Ok(Self {
+            refs: crate::disjoint::get3_mut(data, a, b, c),
+            indices: [a, b, c],
+        })
+    }
+
+    /// Rotates the values to the left, assigning `a` with the value of `b`, `b` with the
+    /// value of `c`, and `c` with the value of `a`.
+    #[inline(always)]
+    pub fn bca(&mut self) {
+        let [a, b, c] = &mut self.refs;
+        swap3_bca(*a, *b, *c);
+    }
+
+    /// Rotates the values to the right, assigning `a` with the value of `c`, `b` with the
+    /// value of `a`, and `c` with the value of `b`.
+    #[inline(always)]
+    pub fn cab(&mut self) {
+        let [a, b, c] = &mut self.refs;
+        swap3_cab(*a, *b, *c);
+    }
+
+    /// Rotates the values in the given direction.
+    #[inline(always)]
+    pub fn rotate(&mut self, rotation: Rotation) {
+        match rotation {
+            Rotation::Left => self.bca(),
+            Rotation::Right => self.cab(),
+        }
+    }
+
+    /// Returns the indices `a`, `b` and `c` of the guarded positions.
+    #[inline(always)]
+    pub fn indices(&self) -> [usize; 3] {
+        self.indices
+    }
+
+    /// Returns references to the current values at `a`, `b` and `c`.
+    #[inline(always)]
+    pub fn values(&self) -> [&T; 3] {
+        let [a, b, c] = &self.refs;
+        [&**a, &**b, &**c]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_rotations() {
+        let mut data = [1, 2, 3, 4, 5];
+        let mut rot = Swap3Guard::new(&mut data, 4, 0, 2).unwrap();
+        assert_eq!(rot.indices(), [4, 0, 2]);
+        rot.bca();
+        assert_eq!(rot.values(), [&1, &3, &5]);
+        rot.cab();
+        rot.cab();
+        rot.rotate(Rotation::Right);
+        assert_eq!(rot.values(), [&1, &3, &5]);
+        rot.rotate(Rotation::Left);
+        assert_eq!(data, [5, 2, 1, 4, 3]);
+    }
+
+    #[test]
+    fn test_matches_slice_rotations() {
+        let mut guarded = [1, 2, 3, 4, 5, 6];
+        let mut plain = guarded;
+        let mut rot = Swap3Guard::new(&mut guarded, 5, 1, 3).unwrap();
+        for rotation in [
+            Rotation::Left,
+            Rotation::Left,
+            Rotation::Right,
+            Rotation::Left,
+        ] {
+            rot.rotate(rotation);
+            rotation.apply(&mut plain, 5, 1, 3);
+        }
+        assert_eq!(guarded, plain);
+    }
+
+    #[test]
+    fn test_invalid_indices() {
+        let mut data = [1, 2, 3];
+        assert_eq!(
+            Swap3Guard::new(&mut data, 0, 3, 1).err(),
+            Some(Swap3Error::IndexOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(
+            Swap3Guard::new(&mut data, 0, 1, 1).err(),
+            Some(Swap3Error::DuplicateIndex { index: 1 })
+        );
+        assert_eq!(
+            Swap3Guard::<u8>::new(&mut [], 0, 1, 2).err(),
+            Some(Swap3Error::EmptySlice)
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Rotations in array-backed binary trees.
+//!
+//! In the list representation of a binary tree (as used e.g. by binary heaps), the children
+//! of the node at index `i` are stored at indices `2 * i + 1` (left) and `2 * i + 2` (right).
+//! The functions in this module take care of this index arithmetic.
+//!
+//! ## Example
+//!
+//! Rotating a lineage of grandparent, parent and child nodes can be expressed directly
+//! using [`swap3_bca_slice`]:
+//!
+//! ```
+//! use swap3::heap::{left_child, right_child};
+//!
+//! //                   0
+//! //           1               2
+//! //       3       4       5       6
+//! let mut tree = vec![10, 20, 30, 40, 50, 60, 70];
+//!
+//! let grandparent = 0;
+//! let parent = right_child(grandparent);
+//! let child = left_child(parent);
+//! assert_eq!([parent, child], [2, 5]);
+//!
+//! swap3::swap3_bca_slice(&mut tree, grandparent, parent, child);
+//! assert_eq!(tree, [30, 20, 60, 40, 50, 10, 70]);
+//! ```
+//!
+//! Rotating a node and its two children is provided by [`swap3_bca_triplet`]
+//! and [`swap3_cab_triplet`]:
+//!
+//! ```
+//! let mut tree = vec![10, 20, 30, 40, 50, 60, 70];
+//! swap3::heap::swap3_bca_triplet(&mut tree, 1);
+//! assert_eq!(tree, [10, 40, 30, 50, 20, 60, 70]);
+//! ```
+
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Returns the index of the left child of the node at index `i`, i.e. `2 * i + 1`.
+///
+/// ## Panics
+///
+/// Panics if the index of the child exceeds `usize::MAX`, rather than wrapping around to
+/// an unrelated node.
+///
+/// ## Example
+///
+/// ```
+/// assert_eq!(swap3::heap::left_child(0), 1);
+/// assert_eq!(swap3::heap::left_child(2), 5);
+/// ```
+#[inline(always)]
+pub const fn left_child(i: usize) -> usize {
+    if i > (usize::MAX - 1) / 2 {
+        panic!("index overflow: the left child index exceeds usize::MAX");
+    }
+    2 * i + 1
+}
+
+/// Returns the index of the right child of the node at index `i`, i.e. `2 * i + 2`.
+///
+/// ## Panics
+///
+/// Panics if the index of the child exceeds `usize::MAX`, rather than wrapping around to
+/// an unrelated node.
+///
+/// ## Example
+///
+/// ```
+/// assert_eq!(swap3::heap::right_child(0), 2);
+/// assert_eq!(swap3::heap::right_child(2), 6);
+/// ```
+#[inline(always)]
+pub const fn right_child(i: usize) -> usize {
+    if i > (usize::MAX - 2) / 2 {
+        panic!("index overflow: the right child index exceeds usize::MAX");
+    }
+    2 * i + 2
+}
+
+/// Returns the index of the parent of the node at index `i`, or `None` for the root.
+///
+/// ## Example
+///
+/// ```
+/// assert_eq!(swap3::heap::parent(0), None);
+/// assert_eq!(swap3::heap::parent(5), Some(2));
+/// assert_eq!(swap3::heap::parent(6), Some(2));
+/// ```
+#[inline(always)]
+pub const fn parent(i: usize) -> Option<usize> {
+    if i == 0 {
+        None
+    } else {
+        Some((i - 1) / 2)
+    }
+}
+
+/// Rotates a node and its two children to the left.
+///
+/// ## Arguments
+///
+/// * `data` - The slice holding the tree.
+/// * `parent` - The index of the parent node, to be assigned with the value of its left child.
+///   The left child is assigned with the value of the right child, which in turn is
+///   assigned with the value of the parent.
+///
+/// ## Panics
+///
+/// Panics if the right child of `parent` is out of bounds.
+///
+/// ## Example
+///
+/// ```
+/// let mut tree = vec![10, 20, 30, 40, 50, 60, 70];
+/// swap3::heap::swap3_bca_triplet(&mut tree, 0);
+/// assert_eq!(tree, [20, 30, 10, 40, 50, 60, 70]);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_triplet<T>(data: &mut [T], parent: usize) {
+    swap3_bca_slice(data, parent, left_child(parent), right_child(parent));
+}
+
+/// Rotates a node and its two children to the right.
+///
+/// ## Arguments
+///
+/// * `data` - The slice holding the tree.
+/// * `parent` - The index of the parent node, to be assigned with the value of its right child.
+///   The left child is assigned with the value of the parent, and the right child
+///   is assigned with the value of the left child.
+///
+/// ## Panics
+///
+/// Panics if the right child of `parent` is out of bounds.
+///
+/// ## Example
+///
+/// ```
+/// let mut tree = vec![10, 20, 30, 40, 50, 60, 70];
+/// swap3::heap::swap3_cab_triplet(&mut tree, 0);
+/// assert_eq!(tree, [30, 10, 20, 40, 50, 60, 70]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_triplet<T>(data: &mut [T], parent: usize) {
+    swap3_cab_slice(data, parent, left_child(parent), right_child(parent));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_arithmetic() {
+        for i in 0..100 {
+            assert_eq!(parent(left_child(i)), Some(i));
+            assert_eq!(parent(right_child(i)), Some(i));
+        }
+    }
+
+    #[test]
+    fn test_lineage_rotation() {
+        let mut tree = vec![10, 20, 30, 40, 50, 60, 70];
+        let child = 4;
+        let node = parent(child).unwrap();
+        let grandparent = parent(node).unwrap();
+        swap3_cab_slice(&mut tree, grandparent, node, child);
+        assert_eq!(tree, [50, 10, 30, 40, 20, 60, 70]);
+    }
+
+    #[test]
+    fn test_swap3_bca_triplet() {
+        let mut tree = vec![10, 20, 30, 40, 50, 60, 70];
+        swap3_bca_triplet(&mut tree, 2);
+        assert_eq!(tree, [10, 20, 60, 40, 50, 70, 30]);
+    }
+
+    #[test]
+    fn test_swap3_cab_triplet() {
+        let mut tree = vec![10, 20, 30, 40, 50, 60, 70];
+        swap3_cab_triplet(&mut tree, 2);
+        assert_eq!(tree, [10, 20, 70, 40, 50, 30, 60]);
+    }
+
+    #[test]
+    fn test_index_arithmetic_at_the_limit() {
+        let last = (usize::MAX - 2) / 2;
+        assert_eq!(right_child(last), usize::MAX - 1);
+        assert_eq!(left_child(last + 1), usize::MAX);
+        assert_eq!(parent(usize::MAX), Some(last + 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "index overflow")]
+    fn test_triplet_overflow() {
+        let mut tree = vec![10, 20, 30, 40, 50, 60, 70];
+        swap3_cab_triplet(&mut tree, usize::MAX / 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_triplet_out_of_bounds() {
+        let mut tree = vec![10, 20, 30, 40, 50, 60, 70];
+        swap3_bca_triplet(&mut tree, 3);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use core::hash::{BuildHasher, Hash};
+use std::collections::HashMap;
+
+/// Rotates three values of a slice to the left, updating an inverted index mapping the
+/// values to their positions.
+///
+/// The index may map each value to one of its positions only. An entry is moved along with
+/// the occurrence of the value it points to: if `inverted[&v]` is one of the rotated indices
+/// and `v` is moved away from it, the entry is updated to the new position of that
+/// occurrence. All other entries are left untouched, in particular those pointing to an
+/// occurrence outside of the rotated positions. Values missing from the index are not
+/// inserted.
+///
+/// Hash collisions do not affect the result, as entries are looked up by equality.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `inverted` - The inverted index, mapping values to their positions in `data`.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// let mut data = vec!["x", "y", "z", "w"];
+/// let mut inverted: HashMap<_, _> = data.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+///
+/// swap3::swap3_bca_slice_indexed(&mut data, &mut inverted, 0, 1, 3);
+/// assert_eq!(data, ["y", "w", "z", "x"]);
+/// assert_eq!([inverted["x"], inverted["y"], inverted["z"], inverted["w"]], [3, 0, 2, 1]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn swap3_bca_slice_indexed<T, S>(
+    data: &mut [T],
+    inverted: &mut HashMap<T, usize, S>,
+    a: usize,
+    b: usize,
+    c: usize,
+) where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    swap3_bca_slice(data, a, b, c);
+    update_index(data, inverted, [(b, a), (c, b), (a, c)]);
+}
+
+/// Rotates three values of a slice to the right, updating an inverted index mapping the
+/// values to their positions.
+///
+/// The index may map each value to one of its positions only. An entry is moved along with
+/// the occurrence of the value it points to: if `inverted[&v]` is one of the rotated indices
+/// and `v` is moved away from it, the entry is updated to the new position of that
+/// occurrence. All other entries are left untouched, in particular those pointing to an
+/// occurrence outside of the rotated positions. Values missing from the index are not
+/// inserted.
+///
+/// Hash collisions do not affect the result, as entries are looked up by equality.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `inverted` - The inverted index, mapping values to their positions in `data`.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// let mut data = vec!["x", "y", "z", "w"];
+/// let mut inverted: HashMap<_, _> = data.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+///
+/// swap3::swap3_cab_slice_indexed(&mut data, &mut inverted, 0, 1, 3);
+/// assert_eq!(data, ["w", "x", "z", "y"]);
+/// assert_eq!([inverted["x"], inverted["y"], inverted["z"], inverted["w"]], [1, 3, 2, 0]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn swap3_cab_slice_indexed<T, S>(
+    data: &mut [T],
+    inverted: &mut HashMap<T, usize, S>,
+    a: usize,
+    b: usize,
+    c: usize,
+) where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    swap3_cab_slice(data, a, b, c);
+    update_index(data, inverted, [(c, a), (a, b), (b, c)]);
+}
+
+/// Moves the index entries along with the rotated values, given as `(from, to)` pairs.
+fn update_index<T, S>(data: &[T], inverted: &mut HashMap<T, usize, S>, moves: [(usize, usize); 3])
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    // All matches are determined before updating any entry. Otherwise, an entry of a value
+    // occurring twice in the triple could be moved once for each occurrence.
+    let matches = moves.map(|(from, to)| inverted.get(&data[to]) == Some(&from));
+    for ((_, to), matched) in moves.into_iter().zip(matches) {
+        if matched {
+            *inverted
+                .get_mut(&data[to])
+                .expect("the entry was checked to exist") = to;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(data: &[char]) -> HashMap<char, usize> {
+        data.iter().enumerate().map(|(i, &v)| (v, i)).collect()
+    }
+
+    #[test]
+    fn test_consistent() {
+        let mut data = ['a', 'b', 'c', 'd', 'e', 'f'];
+        let mut inverted = index(&data);
+        for (a, b, c) in [(5, 0, 2), (1, 3, 4), (0, 4, 5)] {
+            swap3_bca_slice_indexed(&mut data, &mut inverted, a, b, c);
+            assert_eq!(inverted, index(&data));
+            swap3_cab_slice_indexed(&mut data, &mut inverted, c, a, b);
+            assert_eq!(inverted, index(&data));
+        }
+    }
+
+    #[test]
+    fn test_duplicates_inside_triple() {
+        // The entry of `x` points to its occurrence at index 0, which moves to index 2.
+        let mut data = ['x', 'x', 'y'];
+        let mut inverted = HashMap::from([('x', 0), ('y', 2)]);
+        swap3_bca_slice_indexed(&mut data, &mut inverted, 0, 1, 2);
+        assert_eq!(data, ['x', 'y', 'x']);
+        assert_eq!(inverted, HashMap::from([('x', 2), ('y', 1)]));
+    }
+
+    #[test]
+    fn test_duplicates_outside_triple() {
+        // The entry of `x` points to an occurrence that is not rotated.
+        let mut data = ['x', 'y', 'z', 'x'];
+        let mut inverted = HashMap::from([('x', 3), ('y', 1), ('z', 2)]);
+        swap3_cab_slice_indexed(&mut data, &mut inverted, 0, 1, 2);
+        assert_eq!(data, ['z', 'x', 'y', 'x']);
+        assert_eq!(inverted, HashMap::from([('x', 3), ('y', 2), ('z', 0)]));
+    }
+
+    #[test]
+    fn test_missing_entries() {
+        let mut data = ['a', 'b', 'c'];
+        let mut inverted = HashMap::from([('a', 0)]);
+        swap3_bca_slice_indexed(&mut data, &mut inverted, 0, 1, 2);
+        assert_eq!(inverted, HashMap::from([('a', 2)]));
+    }
+}
@@ -0,0 +1,113 @@
+//! Adapters for the [`indexmap`](::indexmap) crate.
+
+use crate::{swap3_bca, swap3_cab};
+use ::indexmap::IndexMap;
+
+/// Rotates the values at three positions of an [`IndexMap`] to the left.
+///
+/// Only the values are rotated; the keys and their insertion order are left unchanged.
+///
+/// ## Arguments
+///
+/// * `map` - The map whose values to swap.
+/// * `a` - The first position, to be assigned with the value at position `b`.
+/// * `b` - The second position, to be assigned with the value at position `c`.
+/// * `c` - The third position, to be assigned with the value at position `a`.
+///
+/// ## Panics
+///
+/// Panics if any of the positions is out of bounds of `map.len()` or if the positions
+/// are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use indexmap::IndexMap;
+///
+/// let mut map: IndexMap<_, _> = [("a", 1), ("b", 2), ("c", 3), ("d", 4)].into_iter().collect();
+/// swap3::swap3_bca_indexmap(&mut map, 0, 1, 3);
+/// assert_eq!(map.into_iter().collect::<Vec<_>>(), [("a", 2), ("b", 4), ("c", 3), ("d", 1)]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+pub fn swap3_bca_indexmap<K, V, S>(map: &mut IndexMap<K, V, S>, a: usize, b: usize, c: usize) {
+    let len = map.len();
+    match map.get_disjoint_indices_mut([a, b, c]) {
+        Ok([(_, a), (_, b), (_, c)]) => swap3_bca(a, b, c),
+        Err(_) => crate::disjoint::invalid_indices(len, a, b, c),
+    }
+}
+
+/// Rotates the values at three positions of an [`IndexMap`] to the right.
+///
+/// Only the values are rotated; the keys and their insertion order are left unchanged.
+///
+/// ## Arguments
+///
+/// * `map` - The map whose values to swap.
+/// * `a` - The first position, to be assigned with the value at position `c`.
+/// * `b` - The second position, to be assigned with the value at position `a`.
+/// * `c` - The third position, to be assigned with the value at position `b`.
+///
+/// ## Panics
+///
+/// Panics if any of the positions is out of bounds of `map.len()` or if the positions
+/// are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use indexmap::IndexMap;
+///
+/// let mut map: IndexMap<_, _> = [("a", 1), ("b", 2), ("c", 3), ("d", 4)].into_iter().collect();
+/// swap3::swap3_cab_indexmap(&mut map, 0, 1, 3);
+/// assert_eq!(map.into_iter().collect::<Vec<_>>(), [("a", 4), ("b", 1), ("c", 3), ("d", 2)]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+pub fn swap3_cab_indexmap<K, V, S>(map: &mut IndexMap<K, V, S>, a: usize, b: usize, c: usize) {
+    let len = map.len();
+    match map.get_disjoint_indices_mut([a, b, c]) {
+        Ok([(_, a), (_, b), (_, c)]) => swap3_cab(a, b, c),
+        Err(_) => crate::disjoint::invalid_indices(len, a, b, c),
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn map() -> IndexMap<char, u32> {
+        [('x', 10), ('y', 20), ('z', 30)].into_iter().collect()
+    }
+
+    #[test]
+    fn test_swap3_bca_indexmap() {
+        let mut map = map();
+        swap3_bca_indexmap(&mut map, 0, 1, 2);
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), ['x', 'y', 'z']);
+        assert_eq!(map[&'x'], 20);
+        assert_eq!(map[&'y'], 30);
+        assert_eq!(map[&'z'], 10);
+    }
+
+    #[test]
+    fn test_swap3_cab_indexmap() {
+        let mut map = map();
+        swap3_cab_indexmap(&mut map, 0, 1, 2);
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), ['x', 'y', 'z']);
+        assert_eq!(map[&'x'], 30);
+        assert_eq!(map[&'y'], 10);
+        assert_eq!(map[&'z'], 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_out_of_bounds() {
+        swap3_bca_indexmap(&mut map(), 0, 1, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_overlapping() {
+        swap3_cab_indexmap(&mut map(), 0, 1, 1);
+    }
+}
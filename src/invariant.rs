@@ -0,0 +1,251 @@
+use crate::transactional::Rollback;
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// An invariant over the elements of a slice, witnessed by the implementing type.
+///
+/// Invariants are checked through the type rather than a value, so a marker type such as
+/// `Sorted` can name the property a data structure maintains and be reused across all
+/// rotations of it.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Invariant;
+///
+/// /// The elements are in ascending order.
+/// struct Sorted;
+///
+/// impl<T: PartialOrd> Invariant<T> for Sorted {
+///     type Error = usize;
+///
+///     /// Reports the first index at which the order is violated.
+///     fn check(data: &[T]) -> Result<(), usize> {
+///         match data.windows(2).position(|w| w[0] > w[1]) {
+///             Some(index) => Err(index + 1),
+///             None => Ok(()),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(<Sorted as Invariant<i32>>::check(&[1, 2, 3]), Ok(()));
+/// assert_eq!(<Sorted as Invariant<i32>>::check(&[1, 3, 2]), Err(2));
+/// ```
+pub trait Invariant<T> {
+    /// The error describing a violation of the invariant.
+    type Error;
+
+    /// Checks whether `data` upholds the invariant.
+    fn check(data: &[T]) -> Result<(), Self::Error>;
+}
+
+/// Rotates three values to the left, rolling back unless the invariant `I` still holds.
+///
+/// The rotation is applied first, then [`I::check`](Invariant::check) is called with the
+/// rotated slice. If it returns an error, the inverse rotation restores the original order
+/// before the error is returned. If the check panics, the rotation is likewise rolled back
+/// before the panic is propagated.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Errors
+///
+/// Returns the error of the invariant if the rotated slice violates it. The slice is left
+/// unchanged in this case.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// The invariant is not checked in this case.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Invariant;
+///
+/// /// The first element is the smallest one.
+/// struct MinFirst;
+///
+/// impl<T: Ord> Invariant<T> for MinFirst {
+///     type Error = ();
+///
+///     fn check(data: &[T]) -> Result<(), ()> {
+///         match data.split_first() {
+///             Some((first, rest)) if rest.iter().any(|value| value < first) => Err(()),
+///             _ => Ok(()),
+///         }
+///     }
+/// }
+///
+/// let mut vec = vec![10, 20, 30, 40];
+/// assert_eq!(swap3::swap3_bca_slice_invariant::<MinFirst, _>(&mut vec, 0, 1, 2), Err(()));
+/// assert_eq!(vec, &[10, 20, 30, 40]);
+///
+/// assert_eq!(swap3::swap3_bca_slice_invariant::<MinFirst, _>(&mut vec, 1, 2, 3), Ok(()));
+/// assert_eq!(vec, &[10, 30, 40, 20]);
+/// ```
+pub fn swap3_bca_slice_invariant<I, T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Result<(), I::Error>
+where
+    I: Invariant<T>,
+{
+    swap3_bca_slice(data, a, b, c);
+    Rollback::new(data, [a, b, c], swap3_cab_slice).validate(I::check)
+}
+
+/// Rotates three values to the right, rolling back unless the invariant `I` still holds.
+///
+/// The rotation is applied first, then [`I::check`](Invariant::check) is called with the
+/// rotated slice. If it returns an error, the inverse rotation restores the original order
+/// before the error is returned. If the check panics, the rotation is likewise rolled back
+/// before the panic is propagated.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Errors
+///
+/// Returns the error of the invariant if the rotated slice violates it. The slice is left
+/// unchanged in this case.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// The invariant is not checked in this case.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Invariant;
+///
+/// /// The first element is the smallest one.
+/// struct MinFirst;
+///
+/// impl<T: Ord> Invariant<T> for MinFirst {
+///     type Error = ();
+///
+///     fn check(data: &[T]) -> Result<(), ()> {
+///         match data.split_first() {
+///             Some((first, rest)) if rest.iter().any(|value| value < first) => Err(()),
+///             _ => Ok(()),
+///         }
+///     }
+/// }
+///
+/// let mut vec = vec![10, 20, 30, 40];
+/// assert_eq!(swap3::swap3_cab_slice_invariant::<MinFirst, _>(&mut vec, 1, 2, 3), Ok(()));
+/// assert_eq!(vec, &[10, 40, 20, 30]);
+/// ```
+pub fn swap3_cab_slice_invariant<I, T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Result<(), I::Error>
+where
+    I: Invariant<T>,
+{
+    swap3_cab_slice(data, a, b, c);
+    Rollback::new(data, [a, b, c], swap3_bca_slice).validate(I::check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sorted;
+
+    impl<T: PartialOrd> Invariant<T> for Sorted {
+        type Error = usize;
+
+        fn check(data: &[T]) -> Result<(), usize> {
+            match data.windows(2).position(|w| w[0] > w[1]) {
+                Some(index) => Err(index + 1),
+                None => Ok(()),
+            }
+        }
+    }
+
+    struct ByKey;
+
+    impl<K: Ord, V> Invariant<(K, V)> for ByKey {
+        type Error = ();
+
+        fn check(data: &[(K, V)]) -> Result<(), ()> {
+            match data.windows(2).all(|w| w[0].0 <= w[1].0) {
+                true => Ok(()),
+                false => Err(()),
+            }
+        }
+    }
+
+    struct Panicking;
+
+    impl<T> Invariant<T> for Panicking {
+        type Error = ();
+
+        fn check(_data: &[T]) -> Result<(), ()> {
+            panic!("invariant check failed");
+        }
+    }
+
+    #[test]
+    fn test_violated() {
+        let mut data = [1, 2, 3, 4, 5];
+        assert_eq!(
+            swap3_bca_slice_invariant::<Sorted, _>(&mut data, 0, 2, 4),
+            Err(1)
+        );
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+        assert_eq!(
+            swap3_cab_slice_invariant::<Sorted, _>(&mut data, 1, 2, 3),
+            Err(2)
+        );
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_upheld() {
+        // Rotating equal values keeps the slice sorted.
+        let mut data = [(1, 'a'), (2, 'b'), (2, 'c'), (2, 'd'), (3, 'e')];
+        assert_eq!(
+            swap3_bca_slice_invariant::<ByKey, _>(&mut data, 1, 2, 3),
+            Ok(())
+        );
+        assert_eq!(data, [(1, 'a'), (2, 'c'), (2, 'd'), (2, 'b'), (3, 'e')]);
+        assert_eq!(
+            swap3_cab_slice_invariant::<ByKey, _>(&mut data, 1, 2, 3),
+            Ok(())
+        );
+        assert_eq!(data, [(1, 'a'), (2, 'b'), (2, 'c'), (2, 'd'), (3, 'e')]);
+    }
+
+    #[test]
+    fn test_rolled_back_on_panic() {
+        let mut data = [1, 2, 3];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_bca_slice_invariant::<Panicking, _>(&mut data, 0, 1, 2)
+        }));
+        assert!(result.is_err());
+        assert_eq!(data, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_out_of_bounds() {
+        let mut data = [1, 2, 3];
+        let _ = swap3_bca_slice_invariant::<Panicking, _>(&mut data, 0, 1, 3);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values of a flattened jagged array to the left.
+///
+/// The jagged array is stored as a flat slice along with an offset table, in which
+/// `offsets[row]` is the index of the first element of `row`. Each row extends up to the
+/// start of the next row; the last row extends up to the end of `data`.
+///
+/// ## Arguments
+///
+/// * `data` - The flat slice holding the elements of all rows.
+/// * `offsets` - The start index of each row in `data`.
+/// * `a` - The first `(row, column)` coordinate, to be assigned with the value at `b`.
+/// * `b` - The second `(row, column)` coordinate, to be assigned with the value at `c`.
+/// * `c` - The third `(row, column)` coordinate, to be assigned with the value at `a`.
+///
+/// ## Panics
+///
+/// Panics if any of the rows is out of bounds of the offset table, if any of the columns
+/// is out of bounds of its row, if the offsets of an addressed row are decreasing or exceed
+/// the length of `data`, or if the coordinates are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// // [[10, 20], [30], [40, 50, 60]]
+/// let mut data = vec![10, 20, 30, 40, 50, 60];
+/// let offsets = [0, 2, 3];
+/// swap3::swap3_bca_jagged(&mut data, &offsets, (0, 1), (1, 0), (2, 2));
+/// assert_eq!(data, &[10, 30, 60, 40, 50, 20]);
+/// ```
+pub fn swap3_bca_jagged<T>(
+    data: &mut [T],
+    offsets: &[usize],
+    a: (usize, usize),
+    b: (usize, usize),
+    c: (usize, usize),
+) {
+    let len = data.len();
+    let [a, b, c] = [a, b, c].map(|coord| resolve(len, offsets, coord));
+    swap3_bca_slice(data, a, b, c);
+}
+
+/// Rotates three values of a flattened jagged array to the right.
+///
+/// The jagged array is stored as a flat slice along with an offset table, in which
+/// `offsets[row]` is the index of the first element of `row`. Each row extends up to the
+/// start of the next row; the last row extends up to the end of `data`.
+///
+/// ## Arguments
+///
+/// * `data` - The flat slice holding the elements of all rows.
+/// * `offsets` - The start index of each row in `data`.
+/// * `a` - The first `(row, column)` coordinate, to be assigned with the value at `c`.
+/// * `b` - The second `(row, column)` coordinate, to be assigned with the value at `a`.
+/// * `c` - The third `(row, column)` coordinate, to be assigned with the value at `b`.
+///
+/// ## Panics
+///
+/// Panics if any of the rows is out of bounds of the offset table, if any of the columns
+/// is out of bounds of its row, if the offsets of an addressed row are decreasing or exceed
+/// the length of `data`, or if the coordinates are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// // [[10, 20], [30], [40, 50, 60]]
+/// let mut data = vec![10, 20, 30, 40, 50, 60];
+/// let offsets = [0, 2, 3];
+/// swap3::swap3_cab_jagged(&mut data, &offsets, (0, 1), (1, 0), (2, 2));
+/// assert_eq!(data, &[10, 60, 20, 40, 50, 30]);
+/// ```
+pub fn swap3_cab_jagged<T>(
+    data: &mut [T],
+    offsets: &[usize],
+    a: (usize, usize),
+    b: (usize, usize),
+    c: (usize, usize),
+) {
+    let len = data.len();
+    let [a, b, c] = [a, b, c].map(|coord| resolve(len, offsets, coord));
+    swap3_cab_slice(data, a, b, c);
+}
+
+/// Resolves a `(row, column)` coordinate to an index into the flat data.
+fn resolve(len: usize, offsets: &[usize], (row, col): (usize, usize)) -> usize {
+    let Some(&start) = offsets.get(row) else {
+        panic!(
+            "row out of bounds: the number of rows is {} but the row is {row}",
+            offsets.len()
+        );
+    };
+    let end = offsets.get(row + 1).copied().unwrap_or(len);
+    assert!(
+        start <= end && end <= len,
+        "invalid offsets for row {row}: {start}..{end} with a data length of {len}"
+    );
+
+    let row_len = end - start;
+    assert!(
+        col < row_len,
+        "column out of bounds: the length of row {row} is {row_len} but the column is {col}"
+    );
+    start + col
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // [[1, 2, 3], [], [4], [5, 6]]
+    const OFFSETS: [usize; 4] = [0, 3, 3, 4];
+
+    #[test]
+    fn test_swap3_jagged() {
+        let mut data = [1, 2, 3, 4, 5, 6];
+        swap3_bca_jagged(&mut data, &OFFSETS, (3, 1), (0, 0), (2, 0));
+        assert_eq!(data, [4, 2, 3, 6, 5, 1]);
+        swap3_cab_jagged(&mut data, &OFFSETS, (3, 1), (0, 0), (2, 0));
+        assert_eq!(data, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "column out of bounds: the length of row 2 is 1 but the column is 1")]
+    fn test_column_beyond_row() {
+        // (2, 1) would address the first element of row 3 if not validated.
+        let mut data = [1, 2, 3, 4, 5, 6];
+        swap3_bca_jagged(&mut data, &OFFSETS, (0, 0), (2, 1), (3, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "row out of bounds: the number of rows is 4 but the row is 4")]
+    fn test_row_out_of_bounds() {
+        let mut data = [1, 2, 3, 4, 5, 6];
+        swap3_cab_jagged(&mut data, &OFFSETS, (0, 0), (0, 1), (4, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid offsets for row 1")]
+    fn test_invalid_offsets() {
+        let mut data = [1, 2, 3, 4, 5, 6];
+        swap3_bca_jagged(&mut data, &[0, 4, 2], (0, 0), (0, 1), (1, 0));
+    }
+}
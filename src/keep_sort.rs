@@ -0,0 +1,148 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values to the left, updating a sort permutation to keep describing the
+/// same order of values.
+///
+/// `perm` lists indices into `data`, e.g. `perm[k]` being the index of the `k`-th smallest
+/// value. Rotating `data` moves values between indices rather than changing their order, so
+/// instead of rotating `perm` itself, the entries referring to the rotated indices are
+/// redirected to the new positions of their values. This requires a single pass over `perm`.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `perm` - The sort permutation of `data`, listing indices into `data`.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if `data` and `perm` differ in length, if any of the indices is out of bounds, or
+/// if the indices are not pairwise distinct. All checks are done before either slice is
+/// modified.
+///
+/// ## Example
+///
+/// ```
+/// let mut data = vec![30, 10, 40, 20];
+/// let mut perm = vec![1, 3, 0, 2];
+///
+/// swap3::swap3_bca_slice_keep_sort(&mut data, &mut perm, 0, 1, 2);
+/// assert_eq!(data, &[10, 40, 30, 20]);
+/// assert_eq!(perm, &[0, 3, 2, 1]);
+/// assert_eq!(perm.iter().map(|&i| data[i]).collect::<Vec<_>>(), [10, 20, 30, 40]);
+/// ```
+pub fn swap3_bca_slice_keep_sort<T>(
+    data: &mut [T],
+    perm: &mut [usize],
+    a: usize,
+    b: usize,
+    c: usize,
+) {
+    check_lengths(data.len(), perm.len());
+    swap3_bca_slice(data, a, b, c);
+    redirect(perm, [(b, a), (c, b), (a, c)]);
+}
+
+/// Rotates three values to the right, updating a sort permutation to keep describing the
+/// same order of values.
+///
+/// `perm` lists indices into `data`, e.g. `perm[k]` being the index of the `k`-th smallest
+/// value. Rotating `data` moves values between indices rather than changing their order, so
+/// instead of rotating `perm` itself, the entries referring to the rotated indices are
+/// redirected to the new positions of their values. This requires a single pass over `perm`.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `perm` - The sort permutation of `data`, listing indices into `data`.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if `data` and `perm` differ in length, if any of the indices is out of bounds, or
+/// if the indices are not pairwise distinct. All checks are done before either slice is
+/// modified.
+///
+/// ## Example
+///
+/// ```
+/// let mut data = vec![30, 10, 40, 20];
+/// let mut perm = vec![1, 3, 0, 2];
+///
+/// swap3::swap3_cab_slice_keep_sort(&mut data, &mut perm, 0, 1, 2);
+/// assert_eq!(data, &[40, 30, 10, 20]);
+/// assert_eq!(perm, &[2, 3, 1, 0]);
+/// ```
+pub fn swap3_cab_slice_keep_sort<T>(
+    data: &mut [T],
+    perm: &mut [usize],
+    a: usize,
+    b: usize,
+    c: usize,
+) {
+    check_lengths(data.len(), perm.len());
+    swap3_cab_slice(data, a, b, c);
+    redirect(perm, [(c, a), (a, b), (b, c)]);
+}
+
+#[inline(always)]
+fn check_lengths(len: usize, perm_len: usize) {
+    assert!(
+        len == perm_len,
+        "the sort permutation must match the data: the len is {len} but the permutation has {perm_len} entries"
+    );
+}
+
+/// Redirects the entries of `perm` according to the `(from, to)` moves of the rotation.
+///
+/// Each entry is mapped at most once, so entries redirected to another rotated index are
+/// not picked up again by a later move.
+fn redirect(perm: &mut [usize], moves: [(usize, usize); 3]) {
+    for entry in perm {
+        if let Some(&(_, to)) = moves.iter().find(|(from, _)| from == entry) {
+            *entry = to;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_sorted_view(data: &[u32], perm: &[usize]) {
+        assert!(perm.windows(2).all(|w| data[w[0]] < data[w[1]]));
+    }
+
+    #[test]
+    fn test_sorted_view_is_preserved() {
+        let mut data = [50, 20, 70, 10, 60, 30, 40];
+        let mut perm = [3, 1, 5, 6, 0, 4, 2];
+        check_sorted_view(&data, &perm);
+        for (a, b, c) in [(0, 1, 2), (6, 3, 0), (2, 5, 4), (1, 6, 3)] {
+            swap3_bca_slice_keep_sort(&mut data, &mut perm, a, b, c);
+            check_sorted_view(&data, &perm);
+            swap3_cab_slice_keep_sort(&mut data, &mut perm, b, c, a);
+            check_sorted_view(&data, &perm);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut data = [50, 20, 70, 10];
+        let mut perm = [3, 1, 0, 2];
+        swap3_bca_slice_keep_sort(&mut data, &mut perm, 3, 0, 2);
+        swap3_cab_slice_keep_sort(&mut data, &mut perm, 3, 0, 2);
+        assert_eq!(data, [50, 20, 70, 10]);
+        assert_eq!(perm, [3, 1, 0, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 3 but the permutation has 2 entries")]
+    fn test_length_mismatch() {
+        swap3_bca_slice_keep_sort(&mut [1, 2, 3], &mut [0, 1], 0, 1, 2);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{permute3, Rotation};
+use alloc::vec::Vec;
+
+/// A plan of rotations that is recorded now and applied later.
+///
+/// Rotations are recorded with [`push`](LazyRotation::push) and applied to a slice with
+/// [`materialize`](LazyRotation::materialize). While recording, the plan is simplified:
+/// a rotation of the same three positions as an earlier step is merged into that step, as
+/// long as only steps on disjoint positions were recorded in between (which commute with
+/// it). Steps that collapse into the identity, such as a rotation followed by its inverse,
+/// are dropped entirely. Merged steps that result in a transposition are applied by a
+/// single swap; see [`permute3`].
+///
+/// ## Example
+///
+/// ```
+/// use swap3::{LazyRotation, Rotation};
+///
+/// let mut plan = LazyRotation::new();
+/// plan.push(Rotation::Left, 0, 1, 2)
+///     .push(Rotation::Left, 3, 4, 5)
+///     .push(Rotation::Right, 1, 2, 0);
+///
+/// // The rotation of 0, 1 and 2 was undone, leaving a single step.
+/// assert_eq!(plan.len(), 1);
+///
+/// let mut vec = vec![10, 20, 30, 40, 50, 60];
+/// plan.materialize(&mut vec);
+/// assert_eq!(vec, &[10, 20, 30, 50, 60, 40]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct LazyRotation {
+    steps: Vec<Step>,
+}
+
+/// A permutation of three positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Step {
+    /// The positions, in ascending order.
+    positions: [usize; 3],
+    /// The target position of the element at the respective position.
+    targets: [usize; 3],
+}
+
+impl Step {
+    fn new(rotation: Rotation, a: usize, b: usize, c: usize) -> Self {
+        // The pairs of source and target position of each element.
+        let mut moves = match rotation {
+            Rotation::Left => [(a, c), (b, a), (c, b)],
+            Rotation::Right => [(a, b), (b, c), (c, a)],
+        };
+        moves.sort_unstable();
+        Self {
+            positions: moves.map(|(position, _)| position),
+            targets: moves.map(|(_, target)| target),
+        }
+    }
+
+    /// Returns the step applying `self` first, then `next`, which must act on the same positions.
+    fn then(self, next: Step) -> Step {
+        let targets = self.targets.map(|target| {
+            let i = self.positions.iter().position(|&p| p == target);
+            next.targets[i.expect("the steps act on the same positions")]
+        });
+        Step {
+            positions: self.positions,
+            targets,
+        }
+    }
+
+    fn is_identity(&self) -> bool {
+        self.positions == self.targets
+    }
+
+    fn is_disjoint(&self, other: &Step) -> bool {
+        self.positions.iter().all(|p| !other.positions.contains(p))
+    }
+}
+
+impl LazyRotation {
+    /// Creates an empty plan.
+    pub const fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Returns the number of steps of the plan, after merging and dropping steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns `true` if the plan does not move any element.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Records a rotation of three positions.
+    ///
+    /// ## Arguments
+    ///
+    /// * `rotation` - The direction of the rotation.
+    /// * `a` - The first index.
+    /// * `b` - The second index.
+    /// * `c` - The third index.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the indices are not pairwise distinct. Bounds are checked when the plan
+    /// is materialized.
+    pub fn push(&mut self, rotation: Rotation, a: usize, b: usize, c: usize) -> &mut Self {
+        crate::disjoint::assert_distinct(a, b, c);
+        self.push_step(Step::new(rotation, a, b, c));
+        self
+    }
+
+    /// Appends all steps of another plan, to be applied after the steps of this one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use swap3::{LazyRotation, Rotation};
+    ///
+    /// let mut plan = LazyRotation::new();
+    /// plan.push(Rotation::Left, 0, 1, 2);
+    ///
+    /// let mut other = LazyRotation::new();
+    /// other.push(Rotation::Left, 1, 2, 0);
+    ///
+    /// // Rotating the same positions to the left twice is the same as rotating them to the right.
+    /// plan.then(&other);
+    /// assert_eq!(plan.len(), 1);
+    ///
+    /// let mut vec = vec![10, 20, 30];
+    /// plan.materialize(&mut vec);
+    /// assert_eq!(vec, &[30, 10, 20]);
+    /// ```
+    pub fn then(&mut self, other: &LazyRotation) -> &mut Self {
+        for &step in &other.steps {
+            self.push_step(step);
+        }
+        self
+    }
+
+    /// Applies the plan to a slice.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to permute.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the recorded indices is out of bounds. The indices are checked
+    /// before any element is moved.
+    pub fn materialize<T>(&self, data: &mut [T]) {
+        let len = data.len();
+        for step in &self.steps {
+            let [a, b, c] = step.positions;
+            if c >= len {
+                crate::disjoint::invalid_indices(len, a, b, c);
+            }
+        }
+        for step in &self.steps {
+            permute3(data, step.positions, step.targets);
+        }
+    }
+
+    fn push_step(&mut self, step: Step) {
+        for i in (0..self.steps.len()).rev() {
+            let earlier = self.steps[i];
+            if earlier.positions == step.positions {
+                let merged = earlier.then(step);
+                if merged.is_identity() {
+                    self.steps.remove(i);
+                } else {
+                    self.steps[i] = merged;
+                }
+                return;
+            }
+            if !earlier.is_disjoint(&step) {
+                break;
+            }
+        }
+        self.steps.push(step);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROTATIONS: [(Rotation, usize, usize, usize); 8] = [
+        (Rotation::Left, 0, 1, 2),
+        (Rotation::Right, 5, 3, 4),
+        (Rotation::Left, 2, 0, 1),
+        (Rotation::Left, 1, 4, 2),
+        (Rotation::Right, 6, 7, 5),
+        (Rotation::Right, 2, 4, 1),
+        (Rotation::Left, 0, 6, 3),
+        (Rotation::Left, 7, 5, 6),
+    ];
+
+    #[test]
+    fn test_materialize_matches_eager_rotations() {
+        for count in 0..=ROTATIONS.len() {
+            let mut expected: Vec<u32> = (0..8).collect();
+            let mut plan = LazyRotation::new();
+            for &(rotation, a, b, c) in &ROTATIONS[..count] {
+                rotation.apply(&mut expected, a, b, c);
+                plan.push(rotation, a, b, c);
+            }
+
+            let mut actual: Vec<u32> = (0..8).collect();
+            plan.materialize(&mut actual);
+            assert_eq!(actual, expected, "after {count} rotations");
+        }
+    }
+
+    #[test]
+    fn test_collapsing() {
+        let mut plan = LazyRotation::new();
+        plan.push(Rotation::Left, 0, 1, 2)
+            .push(Rotation::Right, 3, 4, 5)
+            .push(Rotation::Left, 2, 0, 1)
+            .push(Rotation::Left, 1, 2, 0);
+        // Three left rotations of the same positions cancel out.
+        assert_eq!(plan.len(), 1);
+
+        plan.push(Rotation::Left, 3, 4, 5);
+        assert!(plan.is_empty());
+
+        // Overlapping steps in between prevent merging.
+        plan.push(Rotation::Left, 0, 1, 2)
+            .push(Rotation::Left, 2, 3, 4)
+            .push(Rotation::Right, 0, 1, 2);
+        assert_eq!(plan.len(), 3);
+    }
+
+    #[test]
+    fn test_then_matches_sequential_pushes() {
+        let mut first = LazyRotation::new();
+        let mut second = LazyRotation::new();
+        let mut combined = LazyRotation::new();
+        for (i, &(rotation, a, b, c)) in ROTATIONS.iter().enumerate() {
+            if i < 4 { &mut first } else { &mut second }.push(rotation, a, b, c);
+            combined.push(rotation, a, b, c);
+        }
+        first.then(&second);
+        assert_eq!(first, combined);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_out_of_bounds_before_moving() {
+        let mut plan = LazyRotation::new();
+        plan.push(Rotation::Left, 0, 1, 2)
+            .push(Rotation::Left, 1, 2, 3);
+        plan.materialize(&mut [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_duplicate_indices() {
+        LazyRotation::new().push(Rotation::Right, 0, 1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "indices must be pairwise distinct")]
+    fn test_duplicate_indices_at_usize_max() {
+        LazyRotation::new().push(Rotation::Left, usize::MAX, usize::MAX, 0);
+    }
+}
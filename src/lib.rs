@@ -5,12 +5,67 @@
 //! when rotating elements of a binary tree in list representation.
 //!
 //! The provided functions work on arbitrary types and do *not* require the type to be [`Clone`], [`Copy`]
-//! or [`Default`].
+//! or [`Default`]. Values are moved rather than cloned; rotating e.g. `Box<T>` elements
+//! only moves the boxes' pointers, leaving their heap allocations untouched.
 //!
 //! ## Crate features
 //!
+//! * `std` - Enables support for the standard library. Enabled by default; implies `alloc`.
+//!   When disabled, the crate is `no_std`. On Rust 1.81 and later, [`Swap3Error`] implements
+//!   `core::error::Error` regardless of this feature; on older toolchains, `std` is required
+//!   for implementing `std::error::Error`.
+//! * `alloc` - Enables functionality requiring an allocator, such as `Vec`-based helpers.
 //! * `unsafe` - The `unsafe` feature enables the use of (potentially faster) unsafe code.
-//!              It is disabled by default; when disabled, `forbid(unsafe_code)` is implied.
+//!   It is disabled by default; when disabled, `forbid(unsafe_code)` is implied.
+//! * `allocator-api` - Enables tests and support for containers using custom allocators
+//!   via the unstable `allocator_api` feature. Requires a nightly compiler.
+//! * `approx` - Enables `assert_swap3_bca` and `assert_swap3_cab`, asserting that floating-point
+//!   data was rotated as expected within the tolerances of the [`approx`](https://docs.rs/approx)
+//!   crate. Intended for tests, e.g. by enabling it for the dev-dependency only.
+//! * `bytemuck` - Enables rotating records of byte buffers viewed as slices of
+//!   [`bytemuck`](https://docs.rs/bytemuck) `Pod` types.
+//! * `derive` - Enables the `Swap3Fields` derive macro, generating methods that rotate three
+//!   fields of a struct.
+//! * `ffi` - Enables the `export_swap3_c` macro, generating `extern "C"` rotation functions
+//!   for a fixed element type that report invalid arguments through status codes.
+//! * `ghost-cell` - Enables `swap3_bca_ghostcells` and `swap3_cab_ghostcells`, rotating the
+//!   contents of three `GhostCell`s of the [`ghost-cell`](https://docs.rs/ghost-cell) crate by
+//!   their brand token, without runtime borrow checks.
+//! * `glam` - Enables `swap3_bca_vec3` and `swap3_cab_vec3`, rotating the components of the
+//!   three-dimensional vector types of the [`glam`](https://docs.rs/glam) crate. In `no_std`
+//!   builds, a math backend of `glam` such as its `libm` feature must be enabled.
+//! * `indexmap` - Enables rotating the values at three positions of an `IndexMap` of the
+//!   [`indexmap`](https://docs.rs/indexmap) crate.
+//! * `portable-simd` - Enables `swap3_bca_slice_many_simd` and `swap3_cab_slice_many_simd`,
+//!   rotating batches of triples using SIMD gather/scatter via the unstable `portable_simd`
+//!   feature. Requires a nightly compiler.
+//! * `postcard` - Implies `serde` and adds `Rotation3::to_postcard_bytes` and
+//!   `Rotation3::from_postcard_bytes`, encoding rotations compactly using the
+//!   [`postcard`](https://docs.rs/postcard) format, e.g. to send them to embedded devices.
+//! * `prefetch` - Enables `swap3_bca_slice_prefetch` and `swap3_cab_slice_prefetch`, issuing
+//!   software prefetch hints for the elements before rotating them. The hints are only
+//!   issued on x86 and x86-64 targets with SSE. Implies `unsafe`.
+//! * `proptest` - Implements `Arbitrary` of the [`proptest`](https://docs.rs/proptest) crate
+//!   for [`Rotation`] and [`Rotation3`], and provides the `rotation3` and `rotations3`
+//!   strategies, which shrink failing sequences of rotations to minimal ones. Implies `std`.
+//! * `rand` - Enables deterministic shuffling and chaos testing using random three-cycles,
+//!   see `shuffle_via_rotations` and `chaos_rotate`, as well as rotations of positions chosen by
+//!   weight, see `swap3_bca_weighted`. Implies `alloc`.
+//! * `rayon` - Enables rotating the disjoint triples of a slice in parallel using
+//!   [`rayon`](https://docs.rs/rayon), see `rotate_triples_left_par`. Implies `std`.
+//! * `serde` - Implements `Serialize` and `Deserialize` of the [`serde`](https://docs.rs/serde)
+//!   crate for [`Rotation`] and [`Rotation3`].
+//! * `slab` - Enables rotating the values at three occupied keys of a `Slab` of the
+//!   [`slab`](https://docs.rs/slab) crate. Implies `alloc`.
+//! * `timing` - Enables the `swap3_bca_slice_timed` and `swap3_cab_slice_timed` functions for
+//!   ad hoc profiling of individual calls. Intended for development only; implies `std`.
+//! * `tinyvec` - Enables adapters for the `ArrayVec` and `TinyVec` types of
+//!   the [`tinyvec`](https://docs.rs/tinyvec) crate.
+//! * `tracing` - Enables `swap3_bca_slice_traced` and `swap3_cab_slice_traced`, rotating
+//!   within a span of the [`tracing`](https://docs.rs/tracing) crate that records the
+//!   direction and indices.
+//! * `typed-index-collections` - Implements [`Swap3`] for `TiSlice` and provides adapters for
+//!   `TiVec` of the [`typed-index-collections`](https://docs.rs/typed-index-collections) crate.
 //!
 //! ## Examples
 //!
@@ -47,17 +102,276 @@
 // SPDX-FileCopyrightText: 2023 Markus Mayer
 // SPDX-License-Identifier: MIT
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(feature = "unsafe", allow(unsafe_code))]
 #![cfg_attr(not(feature = "unsafe"), forbid(unsafe_code))]
 // only enables the `doc_cfg` feature when
 // the `docsrs` configuration attribute is defined
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(all(test, feature = "allocator-api"), feature(allocator_api))]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 /// Provides simple access to commonly used traits.
 pub mod prelude {
     pub use crate::Swap3;
 }
 
+#[cfg(feature = "ffi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+pub mod ffi;
+pub mod heap;
+pub mod morton;
+
+mod affected;
+mod aggregate;
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod arc;
+mod arrangement;
+mod auto;
+mod btree;
+#[cfg(feature = "alloc")]
+mod btreemap;
+mod by_key;
+mod capped;
+mod cascade;
+mod chunks;
+mod columns;
+#[cfg(feature = "alloc")]
+mod cow;
+mod crc;
+#[cfg(feature = "alloc")]
+mod describe;
+mod diagonal;
+#[cfg(all(feature = "bytemuck", feature = "alloc"))]
+mod diff;
+mod disjoint;
+mod distinct;
+mod error;
+mod excluding;
+mod fenced;
+#[cfg(feature = "ghost-cell")]
+mod ghost_cell;
+#[cfg(feature = "glam")]
+mod glam;
+mod guard;
+#[cfg(feature = "std")]
+mod indexed;
+#[cfg(feature = "indexmap")]
+mod indexmap;
+mod invariant;
+mod jagged;
+mod keep_sort;
+#[cfg(feature = "alloc")]
+mod lazy;
+mod lossy;
+mod many;
+#[cfg(feature = "portable-simd")]
+mod many_simd;
+mod masked;
+#[cfg(feature = "std")]
+mod mutex;
+#[cfg(feature = "alloc")]
+mod nested;
+#[cfg(feature = "std")]
+mod notify;
+mod opt_refs;
+mod packed;
+#[cfg(feature = "alloc")]
+mod padded;
+#[cfg(feature = "rayon")]
+mod par;
+mod parents;
+mod parity;
+mod path;
+#[cfg(feature = "alloc")]
+mod permutation;
+mod permute;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "postcard")]
+mod postcard;
+#[cfg(feature = "prefetch")]
+mod prefetch;
+mod project;
+#[cfg(feature = "proptest")]
+mod proptest;
+mod provenance;
+mod records;
+mod refcell;
+mod reset;
+mod ring;
+mod rotation;
+#[cfg(feature = "unsafe")]
+mod scratch;
+mod script;
+mod seqlock;
+#[cfg(feature = "rand")]
+mod shuffle;
+#[cfg(feature = "slab")]
+mod slab;
+mod slots;
+mod soa;
+mod strategy;
+mod strided;
+mod subrange;
+mod symmetric;
+mod sync;
+mod tagged;
+#[cfg(feature = "timing")]
+mod timed;
+#[cfg(feature = "tinyvec")]
+mod tinyvec;
+#[cfg(feature = "tracing")]
+mod traced;
+mod transactional;
+#[cfg(feature = "typed-index-collections")]
+mod typed_index;
+mod undo;
+#[cfg(feature = "unsafe")]
+mod unsafe_cell;
+#[cfg(feature = "alloc")]
+mod vecdeque;
+#[cfg(kani)]
+mod verification;
+#[cfg(feature = "rand")]
+mod weighted;
+mod with_swap;
+
+pub use affected::{swap3_bca_slice_affected, swap3_cab_slice_affected};
+pub use aggregate::{swap3_bca_slice_agg, swap3_cab_slice_agg};
+#[cfg(feature = "approx")]
+pub use approx::{
+    assert_swap3_bca, assert_swap3_bca_with, assert_swap3_cab, assert_swap3_cab_with,
+};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use arc::{swap3_bca_arc, swap3_cab_arc};
+pub use arrangement::{Arranged, Arrangement, RotatedLeft, RotatedRight, Unrotated};
+pub use auto::swap3_auto;
+pub use btree::rotate_btree_keys;
+#[cfg(feature = "alloc")]
+pub use btreemap::{swap3_bca_btreemap, swap3_cab_btreemap, MapKeyError};
+pub use by_key::{swap3_bca_by_key, swap3_cab_by_key};
+pub use capped::{swap3_bca_slice_cap, swap3_cab_slice_cap};
+pub use cascade::{cascade_rotate_left, cascade_rotate_right};
+pub use chunks::{swap3_bca_chunks, swap3_cab_chunks};
+pub use columns::{swap3_bca_columns, swap3_cab_columns};
+#[cfg(feature = "alloc")]
+pub use cow::{swap3_bca_cow, swap3_cab_cow};
+pub use crc::{swap3_bca_slice_crc, swap3_cab_slice_crc};
+#[cfg(feature = "alloc")]
+pub use describe::{describe_swap3_bca, describe_swap3_cab};
+pub use diagonal::{swap3_bca_diagonal, swap3_cab_diagonal};
+#[cfg(all(feature = "bytemuck", feature = "alloc"))]
+pub use diff::{swap3_bca_slice_diff, swap3_cab_slice_diff, ByteDiff};
+pub use distinct::Distinct3;
+pub use error::Swap3Error;
+pub use excluding::{swap3_bca_excluding, swap3_cab_excluding};
+pub use fenced::{swap3_bca_slice_fenced, swap3_cab_slice_fenced};
+#[cfg(feature = "ghost-cell")]
+pub use ghost_cell::{swap3_bca_ghostcells, swap3_cab_ghostcells};
+#[cfg(feature = "glam")]
+pub use glam::{swap3_bca_vec3, swap3_cab_vec3};
+pub use guard::Swap3Guard;
+#[cfg(feature = "std")]
+pub use indexed::{swap3_bca_slice_indexed, swap3_cab_slice_indexed};
+#[cfg(feature = "indexmap")]
+pub use indexmap::{swap3_bca_indexmap, swap3_cab_indexmap};
+pub use invariant::{swap3_bca_slice_invariant, swap3_cab_slice_invariant, Invariant};
+pub use jagged::{swap3_bca_jagged, swap3_cab_jagged};
+pub use keep_sort::{swap3_bca_slice_keep_sort, swap3_cab_slice_keep_sort};
+#[cfg(feature = "alloc")]
+pub use lazy::LazyRotation;
+pub use lossy::{swap3_bca_slice_lossy, swap3_cab_slice_lossy};
+pub use many::{swap3_bca_slice_many, swap3_cab_slice_many};
+#[cfg(feature = "portable-simd")]
+pub use many_simd::{swap3_bca_slice_many_simd, swap3_cab_slice_many_simd};
+pub use masked::{swap3_bca_slice_masked, swap3_cab_slice_masked, MaskError};
+#[cfg(feature = "std")]
+pub use mutex::{
+    swap3_bca_mutexes, swap3_cab_mutexes, try_swap3_bca_mutexes, try_swap3_cab_mutexes,
+};
+#[cfg(feature = "alloc")]
+pub use nested::{swap3_bca_nested, swap3_cab_nested};
+#[cfg(feature = "std")]
+pub use notify::{swap3_bca_slice_notify, swap3_cab_slice_notify, RotationEvent};
+pub use opt_refs::{swap3_bca_opt_refs, swap3_cab_opt_refs};
+pub use packed::{swap3_bca_packed, swap3_cab_packed, PackedInts};
+#[cfg(feature = "alloc")]
+pub use padded::{swap3_bca_vec_padded, swap3_cab_vec_padded};
+#[cfg(feature = "rayon")]
+pub use par::{rotate_triples_left_par, rotate_triples_right_par};
+pub use parents::{swap3_bca_parents, swap3_cab_parents};
+pub use parity::{rotation_parity, swap3_bca_slice_parity, swap3_cab_slice_parity};
+pub use path::{swap3_bca_slice_reporting, swap3_cab_slice_reporting, PathUsed};
+#[cfg(feature = "alloc")]
+pub use permutation::{apply_permutation, Permutation, PermutationError};
+pub use permute::permute3;
+#[cfg(feature = "bytemuck")]
+pub use pod::{swap3_bca_pod, swap3_cab_pod};
+#[cfg(feature = "prefetch")]
+pub use prefetch::{swap3_bca_slice_prefetch, swap3_cab_slice_prefetch};
+pub use project::{swap3_bca_by, swap3_cab_by};
+#[cfg(feature = "proptest")]
+pub use proptest::{
+    rotation3, rotations3, Rotation3Params, Rotation3Strategy, Rotation3ValueTree,
+    Rotations3Strategy, Rotations3ValueTree,
+};
+pub use provenance::{swap3_bca_slice_provenance, swap3_cab_slice_provenance};
+pub use records::{swap3_bca_records, swap3_cab_records};
+pub use refcell::{
+    swap3_bca_refcells, swap3_cab_refcells, try_swap3_bca_refcells, try_swap3_cab_refcells,
+};
+pub use reset::{swap3_bca_slice_reset, swap3_cab_slice_reset};
+pub use ring::{swap3_bca_circular, swap3_cab_circular, RingBuffer};
+pub use rotation::{Rotation, Rotation3};
+#[cfg(feature = "unsafe")]
+pub use scratch::{swap3_bca_slice_scratch, swap3_cab_slice_scratch};
+pub use script::{apply_script, ScriptError};
+pub use seqlock::{swap3_bca_slice_seqlock, swap3_cab_slice_seqlock};
+#[cfg(feature = "rand")]
+pub use shuffle::{chaos_rotate, shuffle_via_rotations, unshuffle_via_rotations};
+#[cfg(feature = "slab")]
+pub use slab::{swap3_bca_slab, swap3_cab_slab};
+pub use slots::{swap3_bca_slots, swap3_cab_slots};
+pub use soa::{swap3_bca_soa, swap3_cab_soa};
+#[cfg(feature = "unsafe")]
+pub use strategy::SingleTemp;
+pub use strategy::{swap3_bca_slice_via, swap3_cab_slice_via, DoubleSwap, SwapStrategy};
+pub use strided::{swap3_bca_strided, swap3_cab_strided};
+pub use subrange::{swap3_bca_subrange, swap3_cab_subrange};
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use swap3_derive::Swap3Fields;
+pub use symmetric::{swap3_bca_symmetric, swap3_cab_symmetric};
+pub use tagged::{swap3_bca_tagged, swap3_cab_tagged, TagError, TagTable};
+#[cfg(feature = "timing")]
+pub use timed::{swap3_bca_slice_timed, swap3_cab_slice_timed};
+#[cfg(feature = "tinyvec")]
+pub use tinyvec::{swap3_bca_arrayvec, swap3_cab_arrayvec};
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+pub use tinyvec::{swap3_bca_tinyvec, swap3_cab_tinyvec};
+#[cfg(feature = "tracing")]
+pub use traced::{swap3_bca_slice_traced, swap3_cab_slice_traced};
+pub use transactional::{swap3_bca_slice_transactional, swap3_cab_slice_transactional};
+#[cfg(all(feature = "typed-index-collections", feature = "alloc"))]
+pub use typed_index::{swap3_bca_tivec, swap3_cab_tivec};
+pub use undo::{swap3_bca_slice_undoable, swap3_cab_slice_undoable, Undo};
+#[cfg(feature = "unsafe")]
+pub use unsafe_cell::{swap3_bca_unsafecells, swap3_cab_unsafecells};
+#[cfg(feature = "alloc")]
+pub use vecdeque::{
+    swap3_bca_vecdeque, swap3_bca_vecdeque_ends, swap3_cab_vecdeque, swap3_cab_vecdeque_ends,
+    DequePosition,
+};
+#[cfg(feature = "rand")]
+pub use weighted::{swap3_bca_weighted, swap3_cab_weighted};
+pub use with_swap::{swap3_bca_with_swap, swap3_cab_with_swap};
+
 /// Rotates three values to the left.
 ///
 /// ## Arguments
@@ -76,8 +390,8 @@ pub mod prelude {
 /// assert_eq!([a, b, c], [20, 30, 10]);
 /// ```
 pub fn swap3_bca<T>(a: &mut T, b: &mut T, c: &mut T) {
-    std::mem::swap(a, b);
-    std::mem::swap(b, c);
+    core::mem::swap(a, b);
+    core::mem::swap(b, c);
 }
 
 /// Rotates three values to the right.
@@ -98,8 +412,8 @@ pub fn swap3_bca<T>(a: &mut T, b: &mut T, c: &mut T) {
 /// assert_eq!([a, b, c], [30, 10, 20]);
 /// ```
 pub fn swap3_cab<T>(a: &mut T, b: &mut T, c: &mut T) {
-    std::mem::swap(a, c);
-    std::mem::swap(b, c);
+    core::mem::swap(a, c);
+    core::mem::swap(b, c);
 }
 
 /// Rotates three values to the left.
@@ -111,6 +425,10 @@ pub fn swap3_cab<T>(a: &mut T, b: &mut T, c: &mut T) {
 /// * `b` - The second index, to be assigned with the value of `data[c]`.
 /// * `c` - The third index, to be assigned with the value of `data[a]`.
 ///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
 /// ## Example
 ///
 /// ```
@@ -135,12 +453,16 @@ pub fn swap3_bca_slice<T>(data: &mut [T], a: usize, b: usize, c: usize) {
 /// * `b` - The second index, to be assigned with the value of `data[a]`.
 /// * `c` - The third index, to be assigned with the value of `data[b]`.
 ///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
 /// ## Example
 ///
 /// ```
 /// let mut vec = vec![50, 10, 90, 25, 30, 75];
-/// swap3::swap3_bca_slice(&mut vec, 0, 1, 4);
-/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+/// swap3::swap3_cab_slice(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
 /// ```
 #[inline(always)]
 pub fn swap3_cab_slice<T>(data: &mut [T], a: usize, b: usize, c: usize) {
@@ -150,6 +472,114 @@ pub fn swap3_cab_slice<T>(data: &mut [T], a: usize, b: usize, c: usize) {
     slice::cab_safe(data, a, b, c);
 }
 
+/// Applies the reverse three-cycle `c → b → a → c` to indices given in forward order.
+///
+/// Read right to left along the cycle, `c` is assigned with the value of `b`, `b` with the
+/// value of `a`, and `a` wraps around to be assigned with the value of `c`. This is the same
+/// permutation as [`swap3_cab_slice`], named for access patterns that traverse positions
+/// in reverse, such as reverse-complement addressing of sequence data, where the indices are
+/// typically far apart. The inverse operation is [`swap3_bca_slice`].
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut seq = b"ACGTACGT".to_vec();
+/// swap3::swap3_reverse_cycle_slice(&mut seq, 0, 3, 7);
+/// assert_eq!(&seq, b"TCGAACGT");
+/// ```
+#[inline(always)]
+pub fn swap3_reverse_cycle_slice<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+    swap3_cab_slice(data, a, b, c);
+}
+
+/// Rotates three values to the left, returning an error if the indices are invalid.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Errors
+///
+/// Returns [`Swap3Error::EmptySlice`] if `data` is empty, [`Swap3Error::IndexOutOfBounds`]
+/// if any of the indices is out of bounds, or [`Swap3Error::DuplicateIndex`] if the indices
+/// are not pairwise distinct. The slice is left unchanged in either case.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Swap3Error;
+///
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// assert_eq!(swap3::try_swap3_bca_slice(&mut vec, 0, 1, 4), Ok(()));
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+///
+/// let result = swap3::try_swap3_bca_slice(&mut vec, 0, 1, 6);
+/// assert_eq!(result, Err(Swap3Error::IndexOutOfBounds { index: 6, len: 6 }));
+/// ```
+pub fn try_swap3_bca_slice<T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Result<(), Swap3Error> {
+    error::check_indices(data.len(), a, b, c)?;
+    swap3_bca_slice(data, a, b, c);
+    Ok(())
+}
+
+/// Rotates three values to the right, returning an error if the indices are invalid.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Errors
+///
+/// Returns [`Swap3Error::EmptySlice`] if `data` is empty, [`Swap3Error::IndexOutOfBounds`]
+/// if any of the indices is out of bounds, or [`Swap3Error::DuplicateIndex`] if the indices
+/// are not pairwise distinct. The slice is left unchanged in either case.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Swap3Error;
+///
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// assert_eq!(swap3::try_swap3_cab_slice(&mut vec, 0, 1, 4), Ok(()));
+/// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+///
+/// let mut empty: Vec<i32> = Vec::new();
+/// let result = swap3::try_swap3_cab_slice(&mut empty, 0, 1, 2);
+/// assert_eq!(result, Err(Swap3Error::EmptySlice));
+/// ```
+pub fn try_swap3_cab_slice<T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Result<(), Swap3Error> {
+    error::check_indices(data.len(), a, b, c)?;
+    swap3_cab_slice(data, a, b, c);
+    Ok(())
+}
+
 /// Trait providing the [`Swap3::swap3_bca`] and [`Swap3::swap3_cab`] functions directly.
 pub trait Swap3<I = usize> {
     /// Rotates three values to the left.
@@ -209,6 +639,11 @@ pub mod slice {
     /// * `b` - The second index, to be assigned with the value of `data[c]`.
     /// * `c` - The third index, to be assigned with the value of `data[a]`.
     ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+    /// Both conditions are validated in a single pass before any element is moved.
+    ///
     /// ## Example
     ///
     /// ```
@@ -218,8 +653,8 @@ pub mod slice {
     /// ```
     #[inline(always)]
     pub fn bca_safe<T>(data: &mut [T], a: usize, b: usize, c: usize) {
-        data.swap(a, b);
-        data.swap(b, c);
+        let [a, b, c] = crate::disjoint::get3_mut(data, a, b, c);
+        crate::swap3_bca(a, b, c);
     }
 
     /// Rotates three values to the left.
@@ -231,6 +666,10 @@ pub mod slice {
     /// * `b` - The second index, to be assigned with the value of `data[c]`.
     /// * `c` - The third index, to be assigned with the value of `data[a]`.
     ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+    ///
     /// ## Example
     ///
     /// ```
@@ -242,7 +681,7 @@ pub mod slice {
     #[cfg(feature = "unsafe")]
     #[inline(always)]
     pub fn bca_unsafe<T>(data: &mut [T], a: usize, b: usize, c: usize) {
-        use std::ptr;
+        use core::ptr;
 
         // SPDX-SnippetBegin
         // SDPX—SnippetName: Adjusted slice::swap() from the Rust core library.
@@ -253,15 +692,15 @@ pub mod slice {
         let pa = ptr::addr_of_mut!(data[a]);
         let pb = ptr::addr_of_mut!(data[b]);
         let pc = ptr::addr_of_mut!(data[c]);
+        crate::disjoint::assert_distinct(a, b, c);
         // SAFETY: `pa`, `pb` and `pc` have been created from safe mutable references and refer
         // to elements in the slice and therefore are guaranteed to be valid and aligned.
         // Note that accessing the elements behind `a`, `b` and `c` is checked and will
-        // panic when out of bounds.
+        // panic when out of bounds. The indices are pairwise distinct, hence the
+        // pointers do not overlap.
         unsafe {
-            ptr::swap(pa, pb);
-            ptr::swap(pb, pc);
-            // ptr::swap_nonoverlapping(pa, pb, 1);
-            // ptr::swap_nonoverlapping(pb, pc, 1);
+            ptr::swap_nonoverlapping(pa, pb, 1);
+            ptr::swap_nonoverlapping(pb, pc, 1);
         }
 
         // SPDX-SnippetEnd
@@ -276,6 +715,11 @@ pub mod slice {
     /// * `b` - The second index, to be assigned with the value of `data[a]`.
     /// * `c` - The third index, to be assigned with the value of `data[b]`.
     ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+    /// Both conditions are validated in a single pass before any element is moved.
+    ///
     /// ## Example
     ///
     /// ```
@@ -285,8 +729,8 @@ pub mod slice {
     /// ```
     #[inline(always)]
     pub fn cab_safe<T>(data: &mut [T], a: usize, b: usize, c: usize) {
-        data.swap(a, c);
-        data.swap(b, c);
+        let [a, b, c] = crate::disjoint::get3_mut(data, a, b, c);
+        crate::swap3_cab(a, b, c);
     }
 
     /// Rotates three values to the left.
@@ -298,6 +742,10 @@ pub mod slice {
     /// * `b` - The second index, to be assigned with the value of `data[c]`.
     /// * `c` - The third index, to be assigned with the value of `data[a]`.
     ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+    ///
     /// ## Example
     ///
     /// ```
@@ -309,7 +757,7 @@ pub mod slice {
     #[cfg(feature = "unsafe")]
     #[inline(always)]
     pub fn cab_unsafe<T>(data: &mut [T], a: usize, b: usize, c: usize) {
-        use std::ptr;
+        use core::ptr;
 
         // SPDX-SnippetBegin
         // SDPX—SnippetName: Adjusted slice::swap() from the Rust core library.
@@ -320,24 +768,181 @@ pub mod slice {
         let pa = ptr::addr_of_mut!(data[a]);
         let pb = ptr::addr_of_mut!(data[b]);
         let pc = ptr::addr_of_mut!(data[c]);
+        crate::disjoint::assert_distinct(a, b, c);
         // SAFETY: `pa`, `pb` and `pc` have been created from safe mutable references and refer
         // to elements in the slice and therefore are guaranteed to be valid and aligned.
         // Note that accessing the elements behind `a`, `b` and `c` is checked and will
-        // panic when out of bounds.
+        // panic when out of bounds. The indices are pairwise distinct, hence the
+        // pointers do not overlap.
         unsafe {
-            ptr::swap(pa, pc);
-            ptr::swap(pb, pc);
-            // ptr::swap_nonoverlapping(pa, pb, 1);
-            // ptr::swap_nonoverlapping(pb, pc, 1);
+            ptr::swap_nonoverlapping(pa, pc, 1);
+            ptr::swap_nonoverlapping(pb, pc, 1);
         }
 
         // SPDX-SnippetEnd
     }
+
+    /// Rotates three values to the left without any bounds or distinctness checks.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to swap.
+    /// * `a` - The first index, to be assigned with the value of `data[b]`.
+    /// * `b` - The second index, to be assigned with the value of `data[c]`.
+    /// * `c` - The third index, to be assigned with the value of `data[a]`.
+    ///
+    /// ## Safety
+    ///
+    /// The indices must be in bounds and pairwise distinct. This is verified
+    /// by debug assertions only.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut vec = vec![50, 10, 90, 25, 30, 75];
+    /// // SAFETY: The indices are in bounds and pairwise distinct.
+    /// unsafe { swap3::slice::bca_unchecked(&mut vec, 0, 1, 4) };
+    /// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "unsafe")))]
+    #[cfg(feature = "unsafe")]
+    #[inline(always)]
+    pub unsafe fn bca_unchecked<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+        debug_assert!(a < data.len() && b < data.len() && c < data.len());
+        debug_assert!(a != b && b != c && a != c);
+
+        let ptr = data.as_mut_ptr();
+        // SAFETY: The caller guarantees that the indices are in bounds and pairwise distinct,
+        // hence the pointers are valid, aligned and do not overlap.
+        unsafe {
+            let (pa, pb, pc) = (ptr.add(a), ptr.add(b), ptr.add(c));
+            core::ptr::swap_nonoverlapping(pa, pb, 1);
+            core::ptr::swap_nonoverlapping(pb, pc, 1);
+        }
+    }
+
+    /// Rotates three values to the right without any bounds or distinctness checks.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to swap.
+    /// * `a` - The first index, to be assigned with the value of `data[c]`.
+    /// * `b` - The second index, to be assigned with the value of `data[a]`.
+    /// * `c` - The third index, to be assigned with the value of `data[b]`.
+    ///
+    /// ## Safety
+    ///
+    /// The indices must be in bounds and pairwise distinct. This is verified
+    /// by debug assertions only.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut vec = vec![50, 10, 90, 25, 30, 75];
+    /// // SAFETY: The indices are in bounds and pairwise distinct.
+    /// unsafe { swap3::slice::cab_unchecked(&mut vec, 0, 1, 4) };
+    /// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "unsafe")))]
+    #[cfg(feature = "unsafe")]
+    #[inline(always)]
+    pub unsafe fn cab_unchecked<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+        debug_assert!(a < data.len() && b < data.len() && c < data.len());
+        debug_assert!(a != b && b != c && a != c);
+
+        let ptr = data.as_mut_ptr();
+        // SAFETY: The caller guarantees that the indices are in bounds and pairwise distinct,
+        // hence the pointers are valid, aligned and do not overlap.
+        unsafe {
+            let (pa, pb, pc) = (ptr.add(a), ptr.add(b), ptr.add(c));
+            core::ptr::swap_nonoverlapping(pa, pc, 1);
+            core::ptr::swap_nonoverlapping(pb, pc, 1);
+        }
+    }
+
+    /// Rotates three values to the left using a single temporary.
+    ///
+    /// Instead of two pairwise swaps, this moves `data[a]` into a temporary, moves
+    /// `data[b]` to `a` and `data[c]` to `b`, and finally moves the temporary to `c`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to swap.
+    /// * `a` - The first index, to be assigned with the value of `data[b]`.
+    /// * `b` - The second index, to be assigned with the value of `data[c]`.
+    /// * `c` - The third index, to be assigned with the value of `data[a]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut vec = vec![50, 10, 90, 25, 30, 75];
+    /// swap3::slice::bca_single_temp(&mut vec, 0, 1, 4);
+    /// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "unsafe")))]
+    #[cfg(feature = "unsafe")]
+    #[inline(always)]
+    pub fn bca_single_temp<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+        use core::ptr;
+
+        let pa = ptr::addr_of_mut!(data[a]);
+        let pb = ptr::addr_of_mut!(data[b]);
+        let pc = ptr::addr_of_mut!(data[c]);
+        // SAFETY: `pa`, `pb` and `pc` have been created from safe mutable references and refer
+        // to elements in the slice and therefore are guaranteed to be valid and aligned.
+        // The value read into `tmp` is written back before returning, and nothing in between
+        // can panic, so no value is dropped twice or leaked. `ptr::copy` permits overlapping
+        // pointers, so coinciding indices are sound as well.
+        unsafe {
+            let tmp = ptr::read(pa);
+            ptr::copy(pb, pa, 1);
+            ptr::copy(pc, pb, 1);
+            ptr::write(pc, tmp);
+        }
+    }
+
+    /// Rotates three values to the right using a single temporary.
+    ///
+    /// Instead of two pairwise swaps, this moves `data[c]` into a temporary, moves
+    /// `data[b]` to `c` and `data[a]` to `b`, and finally moves the temporary to `a`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to swap.
+    /// * `a` - The first index, to be assigned with the value of `data[c]`.
+    /// * `b` - The second index, to be assigned with the value of `data[a]`.
+    /// * `c` - The third index, to be assigned with the value of `data[b]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut vec = vec![50, 10, 90, 25, 30, 75];
+    /// swap3::slice::cab_single_temp(&mut vec, 0, 1, 4);
+    /// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "unsafe")))]
+    #[cfg(feature = "unsafe")]
+    #[inline(always)]
+    pub fn cab_single_temp<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+        use core::ptr;
+
+        let pa = ptr::addr_of_mut!(data[a]);
+        let pb = ptr::addr_of_mut!(data[b]);
+        let pc = ptr::addr_of_mut!(data[c]);
+        // SAFETY: See `bca_single_temp`.
+        unsafe {
+            let tmp = ptr::read(pc);
+            ptr::copy(pb, pc, 1);
+            ptr::copy(pa, pb, 1);
+            ptr::write(pa, tmp);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::mem::ManuallyDrop;
 
     #[test]
     fn test_swap3_bca() {
@@ -357,6 +962,35 @@ mod tests {
         assert_eq!([a, b, c], [67, 65, 66]);
     }
 
+    #[test]
+    fn test_reverse_cycle_distant_indices() {
+        const LEN: usize = 1 << 20;
+        let mut seq: Vec<u32> = (0..LEN as u32).collect();
+        let (a, b, c) = (0, LEN / 2 + 1, LEN - 1);
+        swap3_reverse_cycle_slice(&mut seq, a, b, c);
+        assert_eq!([seq[a], seq[b], seq[c]], [c as u32, a as u32, b as u32]);
+        swap3_bca_slice(&mut seq, a, b, c);
+        assert!(seq.iter().enumerate().all(|(i, &x)| x == i as u32));
+    }
+
+    #[test]
+    fn test_reverse_cycle_matches_cab() {
+        let mut expected: Vec<u64> = (0..10_000).map(|x| x * x).collect();
+        let mut actual = expected.clone();
+        for (a, b, c) in [(9_999, 17, 5_000), (1, 9_998, 2), (4_242, 0, 9_999)] {
+            swap3_cab_slice(&mut expected, a, b, c);
+            swap3_reverse_cycle_slice(&mut actual, a, b, c);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 1048576 but the index is 1048576")]
+    fn test_reverse_cycle_out_of_bounds() {
+        let mut seq = vec![0u8; 1 << 20];
+        swap3_reverse_cycle_slice(&mut seq, 0, 1, 1 << 20);
+    }
+
     #[test]
     fn test_swap3_bca_vec() {
         let mut vec = vec![50, 10, 90, 25, 30, 75];
@@ -387,6 +1021,497 @@ mod tests {
         assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
     }
 
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_swap3_vec_unchecked() {
+        let mut vec = vec![50, 10, 90, 25, 30, 75];
+        unsafe { slice::bca_unchecked(&mut vec, 0, 1, 4) };
+        assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+        unsafe { slice::cab_unchecked(&mut vec, 0, 1, 4) };
+        assert_eq!(vec, &[50, 10, 90, 25, 30, 75]);
+    }
+
+    /// An over-aligned type, as used e.g. for SIMD data.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(align(64))]
+    struct Aligned([f32; 4]);
+
+    fn check_aligned_rotation(
+        bca: impl Fn(&mut [Aligned], usize, usize, usize),
+        cab: impl Fn(&mut [Aligned], usize, usize, usize),
+    ) {
+        let original: Vec<_> = (0..6).map(|i| Aligned([i as f32; 4])).collect();
+        let mut vec = original.clone();
+
+        bca(&mut vec, 0, 1, 4);
+        assert_eq!(vec[0], original[1]);
+        assert_eq!(vec[1], original[4]);
+        assert_eq!(vec[4], original[0]);
+        cab(&mut vec, 0, 1, 4);
+        assert_eq!(vec, original);
+
+        for value in &vec {
+            assert_eq!(value as *const Aligned as usize % 64, 0);
+        }
+    }
+
+    #[test]
+    fn test_aligned_values() {
+        let mut a = Aligned([1.0; 4]);
+        let mut b = Aligned([2.0; 4]);
+        let mut c = Aligned([3.0; 4]);
+        swap3_bca(&mut a, &mut b, &mut c);
+        assert_eq!(
+            [a, b, c],
+            [Aligned([2.0; 4]), Aligned([3.0; 4]), Aligned([1.0; 4])]
+        );
+        swap3_cab(&mut a, &mut b, &mut c);
+        assert_eq!(
+            [a, b, c],
+            [Aligned([1.0; 4]), Aligned([2.0; 4]), Aligned([3.0; 4])]
+        );
+    }
+
+    #[test]
+    fn test_aligned_safe() {
+        check_aligned_rotation(slice::bca_safe, slice::cab_safe);
+        check_aligned_rotation(swap3_bca_slice, swap3_cab_slice);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_aligned_unsafe() {
+        check_aligned_rotation(slice::bca_unsafe, slice::cab_unsafe);
+        check_aligned_rotation(slice::bca_single_temp, slice::cab_single_temp);
+        check_aligned_rotation(
+            |data, a, b, c| unsafe { slice::bca_unchecked(data, a, b, c) },
+            |data, a, b, c| unsafe { slice::cab_unchecked(data, a, b, c) },
+        );
+    }
+
+    fn check_float_bits<F: Copy, B: PartialEq + core::fmt::Debug>(
+        values: [F; 4],
+        bits: impl Fn(F) -> B,
+        bca: impl Fn(&mut [F], usize, usize, usize),
+        cab: impl Fn(&mut [F], usize, usize, usize),
+    ) {
+        let mut data = values;
+        bca(&mut data, 0, 1, 3);
+        let expected = [values[1], values[3], values[2], values[0]];
+        for (actual, expected) in data.iter().zip(expected) {
+            assert_eq!(bits(*actual), bits(expected));
+        }
+        cab(&mut data, 0, 1, 3);
+        for (actual, expected) in data.iter().zip(values) {
+            assert_eq!(bits(*actual), bits(expected));
+        }
+    }
+
+    fn check_float_rotation(
+        bca32: impl Fn(&mut [f32], usize, usize, usize),
+        cab32: impl Fn(&mut [f32], usize, usize, usize),
+        bca64: impl Fn(&mut [f64], usize, usize, usize),
+        cab64: impl Fn(&mut [f64], usize, usize, usize),
+    ) {
+        // A signalling NaN with a payload, a negative quiet NaN, a negative zero and a subnormal.
+        let f32s = [
+            f32::from_bits(0x7f80_0001),
+            f32::from_bits(0xffc0_beef),
+            -0.0,
+            f32::from_bits(0x0000_0001),
+        ];
+        check_float_bits(f32s, f32::to_bits, bca32, cab32);
+
+        let f64s = [
+            f64::from_bits(0x7ff0_0000_0000_0001),
+            f64::from_bits(0xfff8_dead_beef_0000),
+            -0.0,
+            f64::from_bits(0x0000_0000_0000_0001),
+        ];
+        check_float_bits(f64s, f64::to_bits, bca64, cab64);
+    }
+
+    #[test]
+    fn test_float_bits_values() {
+        let mut a = f64::from_bits(0x7ff0_0000_0000_0001);
+        let mut b = -0.0f64;
+        let mut c = f64::from_bits(0xfff8_dead_beef_0000);
+        swap3_bca(&mut a, &mut b, &mut c);
+        assert_eq!(
+            [a.to_bits(), b.to_bits(), c.to_bits()],
+            [
+                (-0.0f64).to_bits(),
+                0xfff8_dead_beef_0000,
+                0x7ff0_0000_0000_0001
+            ]
+        );
+    }
+
+    #[test]
+    fn test_float_bits_safe() {
+        check_float_rotation(
+            slice::bca_safe,
+            slice::cab_safe,
+            slice::bca_safe,
+            slice::cab_safe,
+        );
+        check_float_rotation(
+            swap3_bca_slice,
+            swap3_cab_slice,
+            swap3_bca_slice,
+            swap3_cab_slice,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_float_bits_unsafe() {
+        check_float_rotation(
+            slice::bca_unsafe,
+            slice::cab_unsafe,
+            slice::bca_unsafe,
+            slice::cab_unsafe,
+        );
+        check_float_rotation(
+            slice::bca_single_temp,
+            slice::cab_single_temp,
+            slice::bca_single_temp,
+            slice::cab_single_temp,
+        );
+        check_float_rotation(
+            |data, a, b, c| unsafe { slice::bca_unchecked(data, a, b, c) },
+            |data, a, b, c| unsafe { slice::cab_unchecked(data, a, b, c) },
+            |data, a, b, c| unsafe { slice::bca_unchecked(data, a, b, c) },
+            |data, a, b, c| unsafe { slice::cab_unchecked(data, a, b, c) },
+        );
+    }
+
+    fn check_box_rotation(
+        bca: impl Fn(&mut [Box<[u8; 64]>], usize, usize, usize),
+        cab: impl Fn(&mut [Box<[u8; 64]>], usize, usize, usize),
+    ) {
+        let mut boxes: Vec<_> = (0..4u8).map(|x| Box::new([x; 64])).collect();
+        let addresses = |boxes: &[Box<[u8; 64]>]| -> Vec<*const [u8; 64]> {
+            boxes.iter().map(|b| &**b as *const _).collect()
+        };
+        let before = addresses(&boxes);
+
+        bca(&mut boxes, 0, 1, 3);
+        assert_eq!(
+            addresses(&boxes),
+            [before[1], before[3], before[2], before[0]]
+        );
+        assert_eq!(
+            boxes.iter().map(|b| b[63]).collect::<Vec<_>>(),
+            [1, 3, 2, 0]
+        );
+
+        cab(&mut boxes, 0, 1, 3);
+        assert_eq!(addresses(&boxes), before);
+        assert!(boxes.iter().enumerate().all(|(i, b)| **b == [i as u8; 64]));
+    }
+
+    #[test]
+    fn test_boxes_values() {
+        let mut a = Box::new(String::from("a"));
+        let mut b = Box::new(String::from("b"));
+        let mut c = Box::new(String::from("c"));
+        let before: [*const String; 3] = [&*a, &*b, &*c];
+        swap3_bca(&mut a, &mut b, &mut c);
+        let after: [*const String; 3] = [&*a, &*b, &*c];
+        assert_eq!(after, [before[1], before[2], before[0]]);
+        assert_eq!([a.as_str(), b.as_str(), c.as_str()], ["b", "c", "a"]);
+    }
+
+    #[test]
+    fn test_boxes_safe() {
+        check_box_rotation(slice::bca_safe, slice::cab_safe);
+        check_box_rotation(swap3_bca_slice, swap3_cab_slice);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_boxes_unsafe() {
+        check_box_rotation(slice::bca_unsafe, slice::cab_unsafe);
+        check_box_rotation(slice::bca_single_temp, slice::cab_single_temp);
+        check_box_rotation(
+            |data, a, b, c| unsafe { slice::bca_unchecked(data, a, b, c) },
+            |data, a, b, c| unsafe { slice::cab_unchecked(data, a, b, c) },
+        );
+    }
+
+    /// A guard recording its identifier in a shared log when dropped.
+    struct DropGuard {
+        id: usize,
+        log: DropLog,
+    }
+
+    type DropLog = std::rc::Rc<core::cell::RefCell<Vec<usize>>>;
+
+    impl Drop for DropGuard {
+        fn drop(&mut self) {
+            self.log.borrow_mut().push(self.id);
+        }
+    }
+
+    fn check_guard_rotation(
+        bca: impl Fn(&mut [DropGuard], usize, usize, usize),
+        cab: impl Fn(&mut [DropGuard], usize, usize, usize),
+    ) {
+        let log = DropLog::default();
+        let mut guards: Vec<_> = (0..4)
+            .map(|id| DropGuard {
+                id,
+                log: log.clone(),
+            })
+            .collect();
+
+        bca(&mut guards, 0, 1, 3);
+        assert!(
+            log.borrow().is_empty(),
+            "no guard may be dropped by the rotation"
+        );
+        assert_eq!(
+            guards.iter().map(|g| g.id).collect::<Vec<_>>(),
+            [1, 3, 2, 0]
+        );
+
+        cab(&mut guards, 0, 2, 3);
+        assert!(
+            log.borrow().is_empty(),
+            "no guard may be dropped by the rotation"
+        );
+        assert_eq!(
+            guards.iter().map(|g| g.id).collect::<Vec<_>>(),
+            [0, 3, 1, 2]
+        );
+
+        drop(guards);
+        assert_eq!(
+            *log.borrow(),
+            [0, 3, 1, 2],
+            "every guard is dropped exactly once"
+        );
+    }
+
+    #[test]
+    fn test_drop_guards_values() {
+        let log = DropLog::default();
+        let mut a = DropGuard {
+            id: 0,
+            log: log.clone(),
+        };
+        let mut b = DropGuard {
+            id: 1,
+            log: log.clone(),
+        };
+        let mut c = DropGuard {
+            id: 2,
+            log: log.clone(),
+        };
+        swap3_bca(&mut a, &mut b, &mut c);
+        swap3_bca(&mut a, &mut b, &mut c);
+        swap3_cab(&mut a, &mut b, &mut c);
+        assert!(log.borrow().is_empty());
+        assert_eq!([a.id, b.id, c.id], [1, 2, 0]);
+
+        drop(a);
+        drop(b);
+        drop(c);
+        assert_eq!(*log.borrow(), [1, 2, 0]);
+    }
+
+    #[test]
+    fn test_drop_guards_safe() {
+        check_guard_rotation(slice::bca_safe, slice::cab_safe);
+        check_guard_rotation(swap3_bca_slice, swap3_cab_slice);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_drop_guards_unsafe() {
+        check_guard_rotation(slice::bca_unsafe, slice::cab_unsafe);
+        check_guard_rotation(slice::bca_single_temp, slice::cab_single_temp);
+        check_guard_rotation(
+            |data, a, b, c| unsafe { slice::bca_unchecked(data, a, b, c) },
+            |data, a, b, c| unsafe { slice::cab_unchecked(data, a, b, c) },
+        );
+    }
+
+    #[test]
+    fn test_drop_guards_on_panic() {
+        let log = DropLog::default();
+        let mut guards: Vec<_> = (0..3)
+            .map(|id| DropGuard {
+                id,
+                log: log.clone(),
+            })
+            .collect();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_bca_slice(&mut guards, 0, 1, 3);
+        }));
+        assert!(result.is_err());
+        assert!(log.borrow().is_empty());
+        drop(guards);
+        assert_eq!(*log.borrow(), [0, 1, 2]);
+    }
+
+    fn check_manually_drop_rotation(
+        bca: impl Fn(&mut [ManuallyDrop<DropGuard>], usize, usize, usize),
+        cab: impl Fn(&mut [ManuallyDrop<DropGuard>], usize, usize, usize),
+    ) {
+        let log = DropLog::default();
+        let mut guards: Vec<_> = (0..4)
+            .map(|id| {
+                ManuallyDrop::new(DropGuard {
+                    id,
+                    log: log.clone(),
+                })
+            })
+            .collect();
+
+        bca(&mut guards, 0, 1, 3);
+        cab(&mut guards, 1, 2, 3);
+        assert!(
+            log.borrow().is_empty(),
+            "no guard may be dropped by the rotation"
+        );
+        assert_eq!(
+            guards.iter().map(|g| g.id).collect::<Vec<_>>(),
+            [1, 0, 3, 2]
+        );
+
+        let guards: Vec<_> = guards.into_iter().map(ManuallyDrop::into_inner).collect();
+        assert!(log.borrow().is_empty());
+        drop(guards);
+        assert_eq!(
+            *log.borrow(),
+            [1, 0, 3, 2],
+            "every guard is dropped exactly once"
+        );
+    }
+
+    #[test]
+    fn test_manually_drop_values() {
+        let log = DropLog::default();
+        let [mut a, mut b, mut c] = [0, 1, 2].map(|id| {
+            ManuallyDrop::new(DropGuard {
+                id,
+                log: log.clone(),
+            })
+        });
+        swap3_cab(&mut a, &mut b, &mut c);
+        assert_eq!([a.id, b.id, c.id], [2, 0, 1]);
+
+        for guard in [a, b, c] {
+            drop(ManuallyDrop::into_inner(guard));
+        }
+        assert_eq!(*log.borrow(), [2, 0, 1]);
+    }
+
+    #[test]
+    fn test_manually_drop_safe() {
+        check_manually_drop_rotation(slice::bca_safe, slice::cab_safe);
+        check_manually_drop_rotation(swap3_bca_slice, swap3_cab_slice);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_manually_drop_unsafe() {
+        check_manually_drop_rotation(slice::bca_unsafe, slice::cab_unsafe);
+        check_manually_drop_rotation(slice::bca_single_temp, slice::cab_single_temp);
+        check_manually_drop_rotation(
+            |data, a, b, c| unsafe { slice::bca_unchecked(data, a, b, c) },
+            |data, a, b, c| unsafe { slice::cab_unchecked(data, a, b, c) },
+        );
+    }
+
+    /// A transparent newtype around a `Copy` type.
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Meters(u64);
+
+    /// A transparent newtype around a non-`Copy` type.
+    #[repr(transparent)]
+    #[derive(Debug, Clone, PartialEq)]
+    struct Name(String);
+
+    type Rotate<T> = fn(&mut [T], usize, usize, usize);
+
+    /// Checks that rotating the newtypes behaves exactly like rotating the raw values.
+    fn check_transparent_rotation<T, W>(
+        values: [T; 5],
+        wrap: impl Fn(T) -> W,
+        peel: impl Fn(W) -> T,
+        raw: [Rotate<T>; 2],
+        wrapped: [Rotate<W>; 2],
+    ) where
+        T: Clone + PartialEq + core::fmt::Debug,
+    {
+        assert_eq!(core::mem::size_of::<W>(), core::mem::size_of::<T>());
+        assert_eq!(core::mem::align_of::<W>(), core::mem::align_of::<T>());
+
+        let mut expected = values.to_vec();
+        let mut actual: Vec<W> = values.into_iter().map(&wrap).collect();
+        for (i, (a, b, c)) in [(0, 1, 4), (3, 2, 0), (4, 3, 1), (1, 0, 2)]
+            .into_iter()
+            .enumerate()
+        {
+            raw[i % 2](&mut expected, a, b, c);
+            wrapped[i % 2](&mut actual, a, b, c);
+        }
+        let actual: Vec<T> = actual.into_iter().map(peel).collect();
+        assert_eq!(actual, expected);
+    }
+
+    /// Checks both newtypes, using the same rotation functions for the raw and wrapped values.
+    macro_rules! check_transparent_newtypes {
+        ($bca:expr, $cab:expr) => {
+            check_transparent_rotation(
+                [10, 20, 30, 40, 50],
+                Meters,
+                |m| m.0,
+                [$bca, $cab],
+                [$bca, $cab],
+            );
+            check_transparent_rotation(
+                ["a", "b", "c", "d", "e"].map(String::from),
+                Name,
+                |n| n.0,
+                [$bca, $cab],
+                [$bca, $cab],
+            );
+        };
+    }
+
+    #[test]
+    fn test_transparent_newtypes_values() {
+        let [mut a, mut b, mut c] = [1, 2, 3].map(Meters);
+        swap3_bca(&mut a, &mut b, &mut c);
+        assert_eq!([a, b, c], [2, 3, 1].map(Meters));
+
+        let [mut a, mut b, mut c] = ["a", "b", "c"].map(|s| Name(s.into()));
+        swap3_cab(&mut a, &mut b, &mut c);
+        assert_eq!([a.0, b.0, c.0], ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_transparent_newtypes_safe() {
+        check_transparent_newtypes!(slice::bca_safe, slice::cab_safe);
+        check_transparent_newtypes!(swap3_bca_slice, swap3_cab_slice);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_transparent_newtypes_unsafe() {
+        check_transparent_newtypes!(slice::bca_unsafe, slice::cab_unsafe);
+        check_transparent_newtypes!(slice::bca_single_temp, slice::cab_single_temp);
+        check_transparent_newtypes!(
+            |data, a, b, c| unsafe { slice::bca_unchecked(data, a, b, c) },
+            |data, a, b, c| unsafe { slice::cab_unchecked(data, a, b, c) }
+        );
+    }
+
     #[test]
     fn test_vec_trait_bca() {
         let mut vec = vec![50, 10, 90, 25, 30, 75];
@@ -400,4 +1525,99 @@ mod tests {
         vec.swap3_cab(0, 1, 4);
         assert_eq!(vec, [30, 50, 90, 25, 10, 75]);
     }
+
+    #[test]
+    fn test_try_swap3_empty_slice() {
+        let mut vec: Vec<i32> = vec![];
+        assert_eq!(
+            try_swap3_bca_slice(&mut vec, 0, 0, 0),
+            Err(Swap3Error::EmptySlice)
+        );
+        assert_eq!(
+            try_swap3_cab_slice(&mut vec, 0, 1, 2),
+            Err(Swap3Error::EmptySlice)
+        );
+    }
+
+    #[test]
+    fn test_try_swap3_single_element() {
+        let mut vec = vec![1];
+        assert_eq!(
+            try_swap3_bca_slice(&mut vec, 0, 0, 0),
+            Err(Swap3Error::DuplicateIndex { index: 0 })
+        );
+        assert_eq!(
+            try_swap3_cab_slice(&mut vec, 0, 1, 0),
+            Err(Swap3Error::IndexOutOfBounds { index: 1, len: 1 })
+        );
+        assert_eq!(vec, &[1]);
+    }
+
+    #[test]
+    fn test_try_swap3_duplicate_index() {
+        let mut vec = vec![1, 2, 3];
+        assert_eq!(
+            try_swap3_bca_slice(&mut vec, 0, 2, 2),
+            Err(Swap3Error::DuplicateIndex { index: 2 })
+        );
+        assert_eq!(vec, &[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_swap3_bca_slice_overlapping() {
+        let mut vec = vec![1, 2, 3];
+        swap3_bca_slice(&mut vec, 0, 1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_swap3_cab_slice_overlapping() {
+        let mut vec = vec![1, 2, 3];
+        swap3_cab_slice(&mut vec, 2, 2, 1);
+    }
+
+    #[test]
+    fn test_try_swap3_two_elements() {
+        let mut vec = vec![1, 2];
+        assert_eq!(
+            try_swap3_bca_slice(&mut vec, 0, 1, 2),
+            Err(Swap3Error::IndexOutOfBounds { index: 2, len: 2 })
+        );
+        assert_eq!(
+            try_swap3_cab_slice(&mut vec, 2, 1, 0),
+            Err(Swap3Error::IndexOutOfBounds { index: 2, len: 2 })
+        );
+        assert_eq!(vec, &[1, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "allocator-api")]
+    fn test_vec_custom_allocator() {
+        use std::alloc::System;
+
+        let mut vec = Vec::new_in(System);
+        vec.extend([50, 10, 90, 25, 30, 75]);
+        swap3_bca_slice(&mut vec, 0, 1, 4);
+        assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+        swap3_cab_slice(&mut vec, 0, 1, 4);
+        assert_eq!(vec, &[50, 10, 90, 25, 30, 75]);
+        vec.swap3_bca(0, 1, 4);
+        assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+        vec.swap3_cab(0, 1, 4);
+        assert_eq!(vec, &[50, 10, 90, 25, 30, 75]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "allocator-api", feature = "unsafe"))]
+    fn test_vec_custom_allocator_unsafe() {
+        use std::alloc::System;
+
+        let mut vec = Vec::new_in(System);
+        vec.extend([50, 10, 90, 25, 30, 75]);
+        slice::bca_unsafe(&mut vec, 0, 1, 4);
+        assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+        slice::cab_unsafe(&mut vec, 0, 1, 4);
+        assert_eq!(vec, &[50, 10, 90, 25, 30, 75]);
+    }
 }
@@ -0,0 +1,168 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values to the left, permitting the indices to coincide.
+///
+/// If the indices are pairwise distinct, this behaves exactly like [`swap3_bca_slice`] and
+/// does not clone any values. Otherwise, the rotation follows value semantics: the values at
+/// `a`, `b` and `c` are read first, and then written to `a`, `b` and `c` in this order, so
+/// that the last write to a position wins. Writing `x`, `y` and `z` for the original values,
+/// this results in:
+///
+/// | Indices       | `data[a]` | `data[b]` | `data[c]` |
+/// |---------------|-----------|-----------|-----------|
+/// | all distinct  | `y`       | `z`       | `x`       |
+/// | `a == b`      | `z`       | `z`       | `x`       |
+/// | `b == c`      | `y`       | `x`       | `x`       |
+/// | `a == c`      | `x`       | `x`       | `x`       |
+/// | all equal     | `x`       | `x`       | `x`       |
+///
+/// That is, for `a == b` and `b == c` the values at the two positions involved are swapped.
+/// For `a == c`, the value at `b` is overwritten, and the same value ends up at both positions.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20, 30, 40];
+/// swap3::swap3_bca_slice_lossy(&mut vec, 0, 1, 2);
+/// assert_eq!(vec, &[20, 30, 10, 40]);
+///
+/// swap3::swap3_bca_slice_lossy(&mut vec, 0, 0, 3);
+/// assert_eq!(vec, &[40, 30, 10, 20]);
+///
+/// swap3::swap3_bca_slice_lossy(&mut vec, 1, 2, 1);
+/// assert_eq!(vec, &[40, 30, 30, 20]);
+/// ```
+pub fn swap3_bca_slice_lossy<T: Clone>(data: &mut [T], a: usize, b: usize, c: usize) {
+    if a != b && b != c && a != c {
+        swap3_bca_slice(data, a, b, c);
+    } else {
+        assign3(data, [a, b, c], [b, c, a]);
+    }
+}
+
+/// Rotates three values to the right, permitting the indices to coincide.
+///
+/// If the indices are pairwise distinct, this behaves exactly like [`swap3_cab_slice`] and
+/// does not clone any values. Otherwise, the rotation follows value semantics: the values at
+/// `a`, `b` and `c` are read first, and then written to `a`, `b` and `c` in this order, so
+/// that the last write to a position wins. Writing `x`, `y` and `z` for the original values,
+/// this results in:
+///
+/// | Indices       | `data[a]` | `data[b]` | `data[c]` |
+/// |---------------|-----------|-----------|-----------|
+/// | all distinct  | `z`       | `x`       | `y`       |
+/// | `a == b`      | `x`       | `x`       | `x`       |
+/// | `b == c`      | `y`       | `y`       | `y`       |
+/// | `a == c`      | `y`       | `x`       | `y`       |
+/// | all equal     | `x`       | `x`       | `x`       |
+///
+/// That is, only for `a == c` the values at the two positions involved are swapped. For
+/// `a == b` and `b == c`, one of the values is overwritten, and the same value ends up at
+/// both positions.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20, 30, 40];
+/// swap3::swap3_cab_slice_lossy(&mut vec, 0, 1, 2);
+/// assert_eq!(vec, &[30, 10, 20, 40]);
+///
+/// swap3::swap3_cab_slice_lossy(&mut vec, 0, 3, 0);
+/// assert_eq!(vec, &[40, 10, 20, 30]);
+///
+/// swap3::swap3_cab_slice_lossy(&mut vec, 1, 1, 2);
+/// assert_eq!(vec, &[40, 10, 10, 30]);
+/// ```
+pub fn swap3_cab_slice_lossy<T: Clone>(data: &mut [T], a: usize, b: usize, c: usize) {
+    if a != b && b != c && a != c {
+        swap3_cab_slice(data, a, b, c);
+    } else {
+        assign3(data, [a, b, c], [c, a, b]);
+    }
+}
+
+/// Assigns `data[targets[i]] = old data[sources[i]]` for `i` in `0..3`, in this order.
+#[cold]
+fn assign3<T: Clone>(data: &mut [T], targets: [usize; 3], sources: [usize; 3]) {
+    let values = sources.map(|source| data[source].clone());
+    for (target, value) in targets.into_iter().zip(values) {
+        data[target] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rotates using the documented value semantics, without the distinct fast path.
+    fn reference(data: &[char], targets: [usize; 3], sources: [usize; 3]) -> Vec<char> {
+        let mut expected = data.to_vec();
+        let values = sources.map(|source| data[source]);
+        for (target, value) in targets.into_iter().zip(values) {
+            expected[target] = value;
+        }
+        expected
+    }
+
+    #[test]
+    fn test_lossy_matches_value_semantics() {
+        let data = ['x', 'y', 'z', 'w'];
+        for a in 0..4 {
+            for b in 0..4 {
+                for c in 0..4 {
+                    let mut bca = data;
+                    swap3_bca_slice_lossy(&mut bca, a, b, c);
+                    assert_eq!(bca[..], reference(&data, [a, b, c], [b, c, a]));
+
+                    let mut cab = data;
+                    swap3_cab_slice_lossy(&mut cab, a, b, c);
+                    assert_eq!(cab[..], reference(&data, [a, b, c], [c, a, b]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_distinct_indices_do_not_clone() {
+        struct NoClone(u8);
+        impl Clone for NoClone {
+            fn clone(&self) -> Self {
+                panic!("cloned {}", self.0);
+            }
+        }
+
+        let mut data = [NoClone(1), NoClone(2), NoClone(3)];
+        swap3_bca_slice_lossy(&mut data, 2, 0, 1);
+        swap3_cab_slice_lossy(&mut data, 1, 0, 2);
+        assert_eq!(data.map(|value| value.0), [3, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_lossy_out_of_bounds() {
+        let mut data = [1, 2, 3];
+        swap3_bca_slice_lossy(&mut data, 3, 3, 0);
+    }
+}
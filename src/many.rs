@@ -0,0 +1,82 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates multiple triples of values to the left, in order.
+///
+/// This is equivalent to calling [`swap3_bca_slice`] for each triple of indices.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `indexes` - The triples `(a, b, c)` of indices to rotate, applied in order.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices of a triple are not
+/// pairwise distinct. Triples preceding the offending one will already have been applied.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_bca_slice_many(&mut vec, &[(0, 1, 4), (2, 3, 5)]);
+/// assert_eq!(vec, &[10, 30, 25, 75, 50, 90]);
+/// ```
+pub fn swap3_bca_slice_many<T>(data: &mut [T], indexes: &[(usize, usize, usize)]) {
+    for &(a, b, c) in indexes {
+        swap3_bca_slice(data, a, b, c);
+    }
+}
+
+/// Rotates multiple triples of values to the right, in order.
+///
+/// This is equivalent to calling [`swap3_cab_slice`] for each triple of indices.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `indexes` - The triples `(a, b, c)` of indices to rotate, applied in order.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices of a triple are not
+/// pairwise distinct. Triples preceding the offending one will already have been applied.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_cab_slice_many(&mut vec, &[(0, 1, 4), (2, 3, 5)]);
+/// assert_eq!(vec, &[30, 50, 75, 90, 10, 25]);
+/// ```
+pub fn swap3_cab_slice_many<T>(data: &mut [T], indexes: &[(usize, usize, usize)]) {
+    for &(a, b, c) in indexes {
+        swap3_cab_slice(data, a, b, c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_bca_slice_many() {
+        let mut vec = vec![1, 2, 3, 4, 5];
+        swap3_bca_slice_many(&mut vec, &[(0, 1, 2), (2, 3, 4)]);
+        assert_eq!(vec, &[2, 3, 4, 5, 1]);
+    }
+
+    #[test]
+    fn test_swap3_cab_slice_many() {
+        let mut vec = vec![1, 2, 3, 4, 5];
+        swap3_cab_slice_many(&mut vec, &[(0, 1, 2), (2, 3, 4)]);
+        assert_eq!(vec, &[3, 1, 5, 2, 4]);
+    }
+
+    #[test]
+    fn test_many_inverse() {
+        let mut vec = vec![1, 2, 3, 4, 5];
+        swap3_bca_slice_many(&mut vec, &[(0, 1, 2), (2, 3, 4)]);
+        swap3_cab_slice_many(&mut vec, &[(2, 3, 4), (0, 1, 2)]);
+        assert_eq!(vec, &[1, 2, 3, 4, 5]);
+    }
+}
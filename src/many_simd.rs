@@ -0,0 +1,180 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use core::simd::{Simd, SimdElement};
+
+/// The number of triples rotated by a single gather/scatter.
+const LANES: usize = 4;
+
+/// Rotates multiple triples of values to the left, in order, using SIMD gather/scatter.
+///
+/// This is equivalent to [`swap3_bca_slice_many`](crate::swap3_bca_slice_many), but loads
+/// and stores the elements of up to four triples at once. Batches whose indices are not all
+/// pairwise distinct and in bounds, as well as the trailing triples, are rotated one by one,
+/// which preserves the sequential semantics of overlapping triples.
+///
+/// Whether this is faster than the scalar path depends on the target: checking the batches
+/// for shared indices has a cost, and gathers and scatters are emulated on targets without
+/// native support. Use the `many_simd` benchmark to compare both on the target in question.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `indexes` - The triples `(a, b, c)` of indices to rotate, applied in order.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices of a triple are not
+/// pairwise distinct. Triples preceding the offending one will already have been applied.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_bca_slice_many_simd(&mut vec, &[(0, 1, 4), (2, 3, 5)]);
+/// assert_eq!(vec, &[10, 30, 25, 75, 50, 90]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "portable-simd")))]
+pub fn swap3_bca_slice_many_simd<T>(data: &mut [T], indexes: &[(usize, usize, usize)])
+where
+    T: SimdElement + Default,
+{
+    let mut batches = indexes.chunks_exact(LANES);
+    for batch in batches.by_ref() {
+        match gather_indices(data.len(), batch) {
+            Some([a, b, c]) => {
+                let [va, vb, vc] = [a, b, c].map(|idx| Simd::gather_or_default(data, idx));
+                vb.scatter(data, a);
+                vc.scatter(data, b);
+                va.scatter(data, c);
+            }
+            None => crate::swap3_bca_slice_many(data, batch),
+        }
+    }
+    for &(a, b, c) in batches.remainder() {
+        swap3_bca_slice(data, a, b, c);
+    }
+}
+
+/// Rotates multiple triples of values to the right, in order, using SIMD gather/scatter.
+///
+/// This is equivalent to [`swap3_cab_slice_many`](crate::swap3_cab_slice_many), but loads
+/// and stores the elements of up to four triples at once. Batches whose indices are not all
+/// pairwise distinct and in bounds, as well as the trailing triples, are rotated one by one,
+/// which preserves the sequential semantics of overlapping triples.
+///
+/// Whether this is faster than the scalar path depends on the target: checking the batches
+/// for shared indices has a cost, and gathers and scatters are emulated on targets without
+/// native support. Use the `many_simd` benchmark to compare both on the target in question.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `indexes` - The triples `(a, b, c)` of indices to rotate, applied in order.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices of a triple are not
+/// pairwise distinct. Triples preceding the offending one will already have been applied.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_cab_slice_many_simd(&mut vec, &[(0, 1, 4), (2, 3, 5)]);
+/// assert_eq!(vec, &[30, 50, 75, 90, 10, 25]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "portable-simd")))]
+pub fn swap3_cab_slice_many_simd<T>(data: &mut [T], indexes: &[(usize, usize, usize)])
+where
+    T: SimdElement + Default,
+{
+    let mut batches = indexes.chunks_exact(LANES);
+    for batch in batches.by_ref() {
+        match gather_indices(data.len(), batch) {
+            Some([a, b, c]) => {
+                let [va, vb, vc] = [a, b, c].map(|idx| Simd::gather_or_default(data, idx));
+                vc.scatter(data, a);
+                va.scatter(data, b);
+                vb.scatter(data, c);
+            }
+            None => crate::swap3_cab_slice_many(data, batch),
+        }
+    }
+    for &(a, b, c) in batches.remainder() {
+        swap3_cab_slice(data, a, b, c);
+    }
+}
+
+/// Transposes a batch of triples into index vectors, if all indices of the batch
+/// are in bounds and pairwise distinct. Only then do the triples not interact,
+/// so that they can be rotated simultaneously.
+#[inline(always)]
+fn gather_indices(len: usize, batch: &[(usize, usize, usize)]) -> Option<[Simd<usize, LANES>; 3]> {
+    let mut all = [0; 3 * LANES];
+    for (i, &(a, b, c)) in batch.iter().enumerate() {
+        all[3 * i..3 * i + 3].copy_from_slice(&[a, b, c]);
+    }
+    for (i, &index) in all.iter().enumerate() {
+        if index >= len || all[i + 1..].contains(&index) {
+            return None;
+        }
+    }
+
+    let lane = |select: fn(&(usize, usize, usize)) -> usize| {
+        Simd::from_array(core::array::from_fn(|i| select(&batch[i])))
+    };
+    Some([lane(|t| t.0), lane(|t| t.1), lane(|t| t.2)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{swap3_bca_slice_many, swap3_cab_slice_many};
+
+    fn triples(len: usize, count: usize, seed: usize) -> Vec<(usize, usize, usize)> {
+        (0..count)
+            .map(|i| {
+                let a = (i * 7 + seed) % len;
+                let b = (a + 1 + (i * 13 + seed) % (len - 2)) % len;
+                let mut c = (b + 1 + (i * 5) % (len - 2)) % len;
+                while c == a || c == b {
+                    c = (c + 1) % len;
+                }
+                (a, b, c)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_scalar() {
+        for (len, count) in [(3, 9), (8, 17), (64, 100), (1000, 1001)] {
+            let indexes = triples(len, count, len);
+            let mut expected: Vec<u32> = (0..len as u32).collect();
+            let mut actual = expected.clone();
+
+            swap3_bca_slice_many(&mut expected, &indexes);
+            swap3_bca_slice_many_simd(&mut actual, &indexes);
+            assert_eq!(actual, expected, "bca, len {len}");
+
+            swap3_cab_slice_many(&mut expected, &indexes);
+            swap3_cab_slice_many_simd(&mut actual, &indexes);
+            assert_eq!(actual, expected, "cab, len {len}");
+        }
+    }
+
+    #[test]
+    fn test_disjoint_batch() {
+        let indexes = [(0, 1, 2), (3, 4, 5), (6, 7, 8), (9, 10, 11)];
+        let mut data: [f64; 12] = core::array::from_fn(|i| i as f64);
+        swap3_bca_slice_many_simd(&mut data, &indexes);
+        assert_eq!(data, [1., 2., 0., 4., 5., 3., 7., 8., 6., 10., 11., 9.]);
+        swap3_cab_slice_many_simd(&mut data, &indexes);
+        assert_eq!(data, core::array::from_fn(|i| i as f64));
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 12 but the index is 12")]
+    fn test_out_of_bounds_in_batch() {
+        let indexes = [(0, 1, 2), (3, 4, 5), (6, 7, 12), (9, 10, 11)];
+        swap3_bca_slice_many_simd(&mut [0u8; 12], &indexes);
+    }
+}
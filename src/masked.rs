@@ -0,0 +1,213 @@
+use crate::{swap3_bca_slice, swap3_cab_slice, Swap3Error};
+use core::fmt::{Display, Formatter};
+
+/// The error returned by [`swap3_bca_slice_masked`] and [`swap3_cab_slice_masked`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MaskError {
+    /// The position is marked as frozen in the mask.
+    Frozen {
+        /// The frozen index.
+        index: usize,
+    },
+    /// The indices are invalid for the slice.
+    Rotation {
+        /// The reason the rotation was rejected.
+        error: Swap3Error,
+    },
+}
+
+impl Display for MaskError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MaskError::Frozen { index } => write!(f, "the element at index {index} is frozen"),
+            MaskError::Rotation { error } => write!(f, "invalid indices: {error}"),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for MaskError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            MaskError::Rotation { error } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(not(has_core_error), feature = "std"))]
+impl std::error::Error for MaskError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MaskError::Rotation { error } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Rotates three values to the left, unless any of their positions is frozen.
+///
+/// The indices are validated and checked against the mask before anything is moved, so a
+/// rejected rotation leaves the slice unchanged. This is intended for constrained editing,
+/// such as spreadsheets with locked cells.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `frozen` - The mask marking the read-only positions of `data` with `true`.
+///
+/// ## Errors
+///
+/// Returns [`MaskError::Rotation`] if any of the indices is out of bounds or the indices are
+/// not pairwise distinct, or [`MaskError::Frozen`] for the first of `a`, `b` and `c` that is
+/// frozen. The slice is left unchanged in either case.
+///
+/// ## Panics
+///
+/// Panics if the mask and the slice differ in length.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::MaskError;
+///
+/// let mut cells = vec![10, 20, 30, 40];
+/// let frozen = [false, true, false, false];
+/// assert_eq!(
+///     swap3::swap3_bca_slice_masked(&mut cells, 0, 1, 2, &frozen),
+///     Err(MaskError::Frozen { index: 1 })
+/// );
+/// assert_eq!(cells, &[10, 20, 30, 40]);
+///
+/// swap3::swap3_bca_slice_masked(&mut cells, 0, 2, 3, &frozen).unwrap();
+/// assert_eq!(cells, &[30, 20, 40, 10]);
+/// ```
+pub fn swap3_bca_slice_masked<T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+    frozen: &[bool],
+) -> Result<(), MaskError> {
+    check_mask(data.len(), a, b, c, frozen)?;
+    swap3_bca_slice(data, a, b, c);
+    Ok(())
+}
+
+/// Rotates three values to the right, unless any of their positions is frozen.
+///
+/// The indices are validated and checked against the mask before anything is moved, so a
+/// rejected rotation leaves the slice unchanged. This is intended for constrained editing,
+/// such as spreadsheets with locked cells.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `frozen` - The mask marking the read-only positions of `data` with `true`.
+///
+/// ## Errors
+///
+/// Returns [`MaskError::Rotation`] if any of the indices is out of bounds or the indices are
+/// not pairwise distinct, or [`MaskError::Frozen`] for the first of `a`, `b` and `c` that is
+/// frozen. The slice is left unchanged in either case.
+///
+/// ## Panics
+///
+/// Panics if the mask and the slice differ in length.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::{MaskError, Swap3Error};
+///
+/// let mut cells = vec![10, 20, 30, 40];
+/// let frozen = [false, true, false, false];
+/// swap3::swap3_cab_slice_masked(&mut cells, 0, 2, 3, &frozen).unwrap();
+/// assert_eq!(cells, &[40, 20, 10, 30]);
+///
+/// assert_eq!(
+///     swap3::swap3_cab_slice_masked(&mut cells, 0, 2, 4, &frozen),
+///     Err(MaskError::Rotation {
+///         error: Swap3Error::IndexOutOfBounds { index: 4, len: 4 }
+///     })
+/// );
+/// ```
+pub fn swap3_cab_slice_masked<T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+    frozen: &[bool],
+) -> Result<(), MaskError> {
+    check_mask(data.len(), a, b, c, frozen)?;
+    swap3_cab_slice(data, a, b, c);
+    Ok(())
+}
+
+/// Validates the indices and checks that none of them is frozen.
+fn check_mask(len: usize, a: usize, b: usize, c: usize, frozen: &[bool]) -> Result<(), MaskError> {
+    assert_eq!(
+        frozen.len(),
+        len,
+        "the mask must have the same len as the slice"
+    );
+    crate::error::check_indices(len, a, b, c).map_err(|error| MaskError::Rotation { error })?;
+    match [a, b, c].into_iter().find(|&index| frozen[index]) {
+        Some(index) => Err(MaskError::Frozen { index }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unfrozen() {
+        let mut data = [1, 2, 3, 4, 5];
+        let frozen = [false, true, false, true, false];
+        assert_eq!(swap3_bca_slice_masked(&mut data, 4, 0, 2, &frozen), Ok(()));
+        assert_eq!(data, [3, 2, 5, 4, 1]);
+        assert_eq!(swap3_cab_slice_masked(&mut data, 4, 0, 2, &frozen), Ok(()));
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_first_frozen_reported() {
+        let mut data = [1, 2, 3, 4];
+        let frozen = [true, false, true, false];
+        assert_eq!(
+            swap3_bca_slice_masked(&mut data, 3, 2, 0, &frozen),
+            Err(MaskError::Frozen { index: 2 })
+        );
+        assert_eq!(
+            swap3_cab_slice_masked(&mut data, 0, 1, 2, &frozen),
+            Err(MaskError::Frozen { index: 0 })
+        );
+        assert_eq!(data, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_invalid_indices_take_precedence() {
+        let mut data = [1, 2, 3];
+        assert_eq!(
+            swap3_bca_slice_masked(&mut data, 0, 0, 1, &[true; 3]),
+            Err(MaskError::Rotation {
+                error: Swap3Error::DuplicateIndex { index: 0 }
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the mask must have the same len as the slice")]
+    fn test_mask_len_mismatch() {
+        let _ = swap3_bca_slice_masked(&mut [1, 2, 3], 0, 1, 2, &[false; 2]);
+    }
+}
@@ -0,0 +1,277 @@
+//! Rotations of cells stored in Morton (Z-order) layout.
+//!
+//! Quadtrees, octrees and spatial grids are often stored in Z-order, in which the index of
+//! a cell is its Morton code: the bits of its coordinates interleaved, with the lowest bit
+//! taken from `x`. The functions in this module encode and decode such codes and rotate
+//! cells addressed by them.
+//!
+//! ## Example
+//!
+//! ```
+//! use swap3::morton::{decode2, encode2};
+//!
+//! // A 4×4 grid in Z-order, holding the coordinates of each cell.
+//! let mut grid: Vec<(u32, u32)> = (0..16).map(decode2).collect();
+//! assert_eq!(grid[encode2(3, 1) as usize], (3, 1));
+//!
+//! swap3::morton::swap3_bca_morton(&mut grid, encode2(0, 0), encode2(3, 1), encode2(1, 3));
+//! assert_eq!(grid[0], (3, 1));
+//! assert_eq!(grid[encode2(3, 1) as usize], (1, 3));
+//! assert_eq!(grid[encode2(1, 3) as usize], (0, 0));
+//! ```
+
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// The largest coordinate representable in a three-dimensional Morton code.
+pub const MAX_COORDINATE_3D: u32 = (1 << 21) - 1;
+
+/// Returns the two-dimensional Morton code of the coordinates `x` and `y`.
+///
+/// The bits of `x` occupy the even and those of `y` the odd bits of the code.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::morton::encode2;
+///
+/// assert_eq!(encode2(1, 0), 0b01);
+/// assert_eq!(encode2(0, 1), 0b10);
+/// assert_eq!(encode2(3, 5), 0b10_01_11);
+/// ```
+#[inline(always)]
+pub const fn encode2(x: u32, y: u32) -> u64 {
+    spread2(x) | (spread2(y) << 1)
+}
+
+/// Returns the coordinates `(x, y)` of a two-dimensional Morton code.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::morton::{decode2, encode2};
+///
+/// assert_eq!(decode2(0b10_01_11), (3, 5));
+/// assert_eq!(decode2(encode2(u32::MAX, 7)), (u32::MAX, 7));
+/// ```
+#[inline(always)]
+pub const fn decode2(code: u64) -> (u32, u32) {
+    (compact2(code), compact2(code >> 1))
+}
+
+/// Returns the three-dimensional Morton code of the coordinates `x`, `y` and `z`.
+///
+/// The bits of `x`, `y` and `z` occupy every third bit of the code, starting at bits 0, 1
+/// and 2, respectively. Each coordinate can use at most 21 bits, i.e. up to
+/// [`MAX_COORDINATE_3D`].
+///
+/// ## Panics
+///
+/// Panics if any of the coordinates exceeds [`MAX_COORDINATE_3D`], rather than silently
+/// truncating it to an unrelated cell.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::morton::encode3;
+///
+/// assert_eq!(encode3(1, 0, 0), 0b001);
+/// assert_eq!(encode3(0, 0, 1), 0b100);
+/// assert_eq!(encode3(3, 1, 2), 0b101_011);
+/// ```
+#[inline(always)]
+pub const fn encode3(x: u32, y: u32, z: u32) -> u64 {
+    if x > MAX_COORDINATE_3D || y > MAX_COORDINATE_3D || z > MAX_COORDINATE_3D {
+        panic!("coordinate out of range: three-dimensional Morton codes hold up to 21 bits per coordinate");
+    }
+    spread3(x) | (spread3(y) << 1) | (spread3(z) << 2)
+}
+
+/// Returns the coordinates `(x, y, z)` of a three-dimensional Morton code.
+///
+/// The highest bit of the code is not part of any coordinate and is ignored.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::morton::{decode3, encode3, MAX_COORDINATE_3D};
+///
+/// assert_eq!(decode3(0b101_011), (3, 1, 2));
+/// assert_eq!(decode3(encode3(MAX_COORDINATE_3D, 0, 9)), (MAX_COORDINATE_3D, 0, 9));
+/// ```
+#[inline(always)]
+pub const fn decode3(code: u64) -> (u32, u32, u32) {
+    (compact3(code), compact3(code >> 1), compact3(code >> 2))
+}
+
+/// Rotates three cells addressed by their Morton codes to the left.
+///
+/// The codes are used as indices into `data`, which holds the cells in Z-order.
+///
+/// ## Arguments
+///
+/// * `data` - The cells in Z-order.
+/// * `a` - The first code, to be assigned with the value at `b`.
+/// * `b` - The second code, to be assigned with the value at `c`.
+/// * `c` - The third code, to be assigned with the value at `a`.
+///
+/// ## Panics
+///
+/// Panics if any of the codes is out of bounds, including codes not representable as
+/// `usize`, or if the codes are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::morton::encode2;
+///
+/// let mut grid: Vec<u32> = (0..16).collect();
+/// swap3::morton::swap3_bca_morton(&mut grid, encode2(1, 0), encode2(0, 1), encode2(1, 1));
+/// assert_eq!(grid[..4], [0, 2, 3, 1]);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_morton<T>(data: &mut [T], a: u64, b: u64, c: u64) {
+    let [a, b, c] = [a, b, c].map(|code| to_index(data.len(), code));
+    swap3_bca_slice(data, a, b, c);
+}
+
+/// Rotates three cells addressed by their Morton codes to the right.
+///
+/// The codes are used as indices into `data`, which holds the cells in Z-order.
+///
+/// ## Arguments
+///
+/// * `data` - The cells in Z-order.
+/// * `a` - The first code, to be assigned with the value at `c`.
+/// * `b` - The second code, to be assigned with the value at `a`.
+/// * `c` - The third code, to be assigned with the value at `b`.
+///
+/// ## Panics
+///
+/// Panics if any of the codes is out of bounds, including codes not representable as
+/// `usize`, or if the codes are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::morton::encode3;
+///
+/// let mut grid: Vec<u32> = (0..8).collect();
+/// swap3::morton::swap3_cab_morton(&mut grid, encode3(1, 0, 0), encode3(0, 1, 0), encode3(0, 0, 1));
+/// assert_eq!(grid[..5], [0, 4, 1, 3, 2]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_morton<T>(data: &mut [T], a: u64, b: u64, c: u64) {
+    let [a, b, c] = [a, b, c].map(|code| to_index(data.len(), code));
+    swap3_cab_slice(data, a, b, c);
+}
+
+/// Converts a Morton code to an index. Codes exceeding `usize` are out of bounds of any slice.
+#[inline(always)]
+fn to_index(len: usize, code: u64) -> usize {
+    match usize::try_from(code) {
+        Ok(index) => index,
+        Err(_) => panic!("index out of bounds: the len is {len} but the index is {code}"),
+    }
+}
+
+/// Spreads the bits of `v` to the even bits of the result.
+#[inline(always)]
+const fn spread2(v: u32) -> u64 {
+    let mut v = v as u64;
+    v = (v | (v << 16)) & 0x0000_ffff_0000_ffff;
+    v = (v | (v << 8)) & 0x00ff_00ff_00ff_00ff;
+    v = (v | (v << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    (v | (v << 1)) & 0x5555_5555_5555_5555
+}
+
+/// Gathers the even bits of `v`, reversing [`spread2`].
+#[inline(always)]
+const fn compact2(v: u64) -> u32 {
+    let mut v = v & 0x5555_5555_5555_5555;
+    v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+    v = (v | (v >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+    v = (v | (v >> 4)) & 0x00ff_00ff_00ff_00ff;
+    v = (v | (v >> 8)) & 0x0000_ffff_0000_ffff;
+    ((v | (v >> 16)) & 0xffff_ffff) as u32
+}
+
+/// Spreads the lowest 21 bits of `v` to every third bit of the result.
+#[inline(always)]
+const fn spread3(v: u32) -> u64 {
+    let mut v = v as u64 & 0x1f_ffff;
+    v = (v | (v << 32)) & 0x001f_0000_0000_ffff;
+    v = (v | (v << 16)) & 0x001f_0000_ff00_00ff;
+    v = (v | (v << 8)) & 0x100f_00f0_0f00_f00f;
+    v = (v | (v << 4)) & 0x10c3_0c30_c30c_30c3;
+    (v | (v << 2)) & 0x1249_2492_4924_9249
+}
+
+/// Gathers every third bit of `v`, reversing [`spread3`].
+#[inline(always)]
+const fn compact3(v: u64) -> u32 {
+    let mut v = v & 0x1249_2492_4924_9249;
+    v = (v | (v >> 2)) & 0x10c3_0c30_c30c_30c3;
+    v = (v | (v >> 4)) & 0x100f_00f0_0f00_f00f;
+    v = (v | (v >> 8)) & 0x001f_0000_ff00_00ff;
+    v = (v | (v >> 16)) & 0x001f_0000_0000_ffff;
+    ((v | (v >> 32)) & 0x1f_ffff) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Interleaves the bits of the coordinates one at a time.
+    fn naive_encode(coordinates: &[u32], bits: u32) -> u64 {
+        let mut code = 0;
+        for bit in 0..bits {
+            for (k, coordinate) in coordinates.iter().enumerate() {
+                let value = u64::from(coordinate >> bit & 1);
+                code |= value << (bit as usize * coordinates.len() + k);
+            }
+        }
+        code
+    }
+
+    #[test]
+    fn test_matches_naive_interleaving() {
+        let samples = [0, 1, 2, 5, 0x1234, 0xa_bcde, MAX_COORDINATE_3D];
+        for &x in &samples {
+            for &y in &samples {
+                assert_eq!(encode2(x, y), naive_encode(&[x, y], 32));
+                assert_eq!(decode2(encode2(x, y)), (x, y));
+                for &z in &samples {
+                    assert_eq!(encode3(x, y, z), naive_encode(&[x, y, z], 21));
+                    assert_eq!(decode3(encode3(x, y, z)), (x, y, z));
+                }
+            }
+        }
+        assert_eq!(encode2(u32::MAX, u32::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn test_rotate_quadrants() {
+        // The first cells of the four quadrants of an 8×8 grid.
+        let mut grid: [u64; 64] = core::array::from_fn(|i| i as u64);
+        let [tl, tr, bl] = [(0, 0), (4, 0), (0, 4)].map(|(x, y)| encode2(x, y));
+        assert_eq!([tl, tr, bl], [0, 16, 32]);
+        swap3_bca_morton(&mut grid, tl, tr, bl);
+        assert_eq!([grid[0], grid[16], grid[32]], [16, 32, 0]);
+        swap3_cab_morton(&mut grid, tl, tr, bl);
+        assert_eq!([grid[0], grid[16], grid[32]], [0, 16, 32]);
+    }
+
+    #[test]
+    #[should_panic(expected = "coordinate out of range")]
+    fn test_encode3_out_of_range() {
+        encode3(0, MAX_COORDINATE_3D + 1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 16 but the index is 16")]
+    fn test_code_out_of_bounds() {
+        let mut grid = [0; 16];
+        swap3_bca_morton(&mut grid, 0, 1, encode2(4, 0));
+    }
+}
@@ -0,0 +1,317 @@
+use crate::sync::{Mutex, MutexGuard};
+use crate::{swap3_bca, swap3_cab};
+use std::sync::{PoisonError, TryLockError};
+
+/// Rotates the values guarded by three mutexes of a slice to the left.
+///
+/// The mutexes are always locked in ascending index order, regardless of the order of the
+/// arguments. As long as all threads follow this order, e.g. by using this function,
+/// concurrent rotations of overlapping triples cannot deadlock.
+///
+/// ## Arguments
+///
+/// * `data` - The mutexes whose values to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds, if the indices are not pairwise distinct,
+/// or if any of the mutexes is poisoned.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::Mutex;
+///
+/// let data = [10, 20, 30, 40].map(Mutex::new);
+/// swap3::swap3_bca_mutexes(&data, 3, 0, 1);
+/// assert_eq!(data.map(|m| m.into_inner().unwrap()), [20, 40, 30, 10]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn swap3_bca_mutexes<T>(data: &[Mutex<T>], a: usize, b: usize, c: usize) {
+    let [mut a, mut b, mut c] = lock3(data, a, b, c, |m| {
+        m.lock().map_err(|_| PoisonError::new(()))
+    })
+    .unwrap_or_else(|_: PoisonError<()>| panic!("a mutex was poisoned"));
+    swap3_bca(&mut *a, &mut *b, &mut *c);
+}
+
+/// Rotates the values guarded by three mutexes of a slice to the right.
+///
+/// The mutexes are always locked in ascending index order, regardless of the order of the
+/// arguments. As long as all threads follow this order, e.g. by using this function,
+/// concurrent rotations of overlapping triples cannot deadlock.
+///
+/// ## Arguments
+///
+/// * `data` - The mutexes whose values to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds, if the indices are not pairwise distinct,
+/// or if any of the mutexes is poisoned.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::Mutex;
+///
+/// let data = [10, 20, 30, 40].map(Mutex::new);
+/// swap3::swap3_cab_mutexes(&data, 3, 0, 1);
+/// assert_eq!(data.map(|m| m.into_inner().unwrap()), [40, 10, 30, 20]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn swap3_cab_mutexes<T>(data: &[Mutex<T>], a: usize, b: usize, c: usize) {
+    let [mut a, mut b, mut c] = lock3(data, a, b, c, |m| {
+        m.lock().map_err(|_| PoisonError::new(()))
+    })
+    .unwrap_or_else(|_: PoisonError<()>| panic!("a mutex was poisoned"));
+    swap3_cab(&mut *a, &mut *b, &mut *c);
+}
+
+/// Rotates the values guarded by three mutexes of a slice to the left, unless any of
+/// them is locked.
+///
+/// The mutexes are tried in ascending index order; locks acquired before a failing
+/// attempt are released again. This never blocks.
+///
+/// ## Arguments
+///
+/// * `data` - The mutexes whose values to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Errors
+///
+/// Returns [`TryLockError::WouldBlock`] if any of the mutexes is currently locked, or
+/// [`TryLockError::Poisoned`] if any of them is poisoned. The values are left unchanged
+/// in either case.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::{Mutex, TryLockError};
+///
+/// let data = [10, 20, 30].map(Mutex::new);
+/// let guard = data[1].lock().unwrap();
+/// assert!(matches!(
+///     swap3::try_swap3_bca_mutexes(&data, 0, 1, 2),
+///     Err(TryLockError::WouldBlock)
+/// ));
+/// drop(guard);
+///
+/// assert!(swap3::try_swap3_bca_mutexes(&data, 0, 1, 2).is_ok());
+/// assert_eq!(data.map(|m| m.into_inner().unwrap()), [20, 30, 10]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn try_swap3_bca_mutexes<T>(
+    data: &[Mutex<T>],
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Result<(), TryLockError<()>> {
+    let [mut a, mut b, mut c] = lock3(data, a, b, c, try_lock)?;
+    swap3_bca(&mut *a, &mut *b, &mut *c);
+    Ok(())
+}
+
+/// Rotates the values guarded by three mutexes of a slice to the right, unless any of
+/// them is locked.
+///
+/// The mutexes are tried in ascending index order; locks acquired before a failing
+/// attempt are released again. This never blocks.
+///
+/// ## Arguments
+///
+/// * `data` - The mutexes whose values to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Errors
+///
+/// Returns [`TryLockError::WouldBlock`] if any of the mutexes is currently locked, or
+/// [`TryLockError::Poisoned`] if any of them is poisoned. The values are left unchanged
+/// in either case.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::Mutex;
+///
+/// let data = [10, 20, 30].map(Mutex::new);
+/// assert!(swap3::try_swap3_cab_mutexes(&data, 0, 1, 2).is_ok());
+/// assert_eq!(data.map(|m| m.into_inner().unwrap()), [30, 10, 20]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn try_swap3_cab_mutexes<T>(
+    data: &[Mutex<T>],
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Result<(), TryLockError<()>> {
+    let [mut a, mut b, mut c] = lock3(data, a, b, c, try_lock)?;
+    swap3_cab(&mut *a, &mut *b, &mut *c);
+    Ok(())
+}
+
+fn try_lock<T>(mutex: &Mutex<T>) -> Result<MutexGuard<'_, T>, TryLockError<()>> {
+    mutex.try_lock().map_err(|error| match error {
+        TryLockError::Poisoned(_) => TryLockError::Poisoned(PoisonError::new(())),
+        TryLockError::WouldBlock => TryLockError::WouldBlock,
+    })
+}
+
+/// Locks the mutexes at three distinct indices in ascending index order and returns the
+/// guards in argument order. On error, the guards acquired so far are released.
+fn lock3<'a, T, E, L>(
+    data: &'a [Mutex<T>],
+    a: usize,
+    b: usize,
+    c: usize,
+    mut lock: L,
+) -> Result<[MutexGuard<'a, T>; 3], E>
+where
+    L: FnMut(&'a Mutex<T>) -> Result<MutexGuard<'a, T>, E>,
+{
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+
+    let mut order = [(a, 0), (b, 1), (c, 2)];
+    order.sort_unstable();
+
+    let mut guards = [None, None, None];
+    for (index, slot) in order {
+        guards[slot] = Some(lock(&data[index])?);
+    }
+    let [a, b, c] = guards;
+    Ok([a, b, c].map(|guard| guard.expect("all mutexes are locked")))
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_swap3_mutexes() {
+        let data = [1, 2, 3, 4, 5].map(Mutex::new);
+        swap3_bca_mutexes(&data, 4, 0, 2);
+        swap3_cab_mutexes(&data, 4, 0, 2);
+        swap3_bca_mutexes(&data, 0, 2, 4);
+        assert_eq!(data.map(|m| m.into_inner().unwrap()), [3, 2, 5, 4, 1]);
+    }
+
+    #[test]
+    fn test_concurrent_overlapping_rotations() {
+        // Opposite argument orders would deadlock without a consistent locking order.
+        let data: Arc<[Mutex<u32>]> = (0..4).map(Mutex::new).collect();
+        let threads: Vec<_> = [(0, 1, 2), (2, 1, 0), (1, 2, 3), (3, 2, 1)]
+            .into_iter()
+            .map(|(a, b, c)| {
+                let data = Arc::clone(&data);
+                thread::spawn(move || {
+                    for _ in 0..3_000 {
+                        swap3_bca_mutexes(&data, a, b, c);
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let mut values: Vec<u32> = data.iter().map(|m| *m.lock().unwrap()).collect();
+        values.sort_unstable();
+        assert_eq!(values, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_try_swap3_mutexes() {
+        let data = [1, 2, 3].map(Mutex::new);
+        {
+            let _guard = data[2].lock().unwrap();
+            assert!(matches!(
+                try_swap3_cab_mutexes(&data, 0, 1, 2),
+                Err(TryLockError::WouldBlock)
+            ));
+            // The locks acquired before the failing attempt must have been released.
+            assert!(data[0].try_lock().is_ok());
+            assert!(data[1].try_lock().is_ok());
+        }
+        assert!(try_swap3_cab_mutexes(&data, 0, 1, 2).is_ok());
+        assert_eq!(data.map(|m| m.into_inner().unwrap()), [3, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a mutex was poisoned")]
+    fn test_poisoned() {
+        let data = [1, 2, 3].map(Mutex::new);
+        let _ = std::panic::catch_unwind(|| {
+            let _guard = data[1].lock().unwrap();
+            panic!("poison");
+        });
+        swap3_bca_mutexes(&data, 0, 1, 2);
+    }
+}
+
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use super::*;
+    use loom::sync::Arc;
+    use loom::thread;
+
+    #[test]
+    fn test_opposite_argument_orders_do_not_deadlock() {
+        loom::model(|| {
+            let data = Arc::new([1, 2, 3, 4].map(Mutex::new));
+            let other = {
+                let data = Arc::clone(&data);
+                thread::spawn(move || swap3_bca_mutexes(&*data, 3, 2, 1))
+            };
+            swap3_bca_mutexes(&*data, 1, 2, 3);
+            other.join().unwrap();
+
+            // The rotations are inverse to each other, regardless of their order.
+            let values: Vec<_> = data.iter().map(|m| *m.lock().unwrap()).collect();
+            assert_eq!(values, [1, 2, 3, 4]);
+        });
+    }
+
+    #[test]
+    fn test_try_lock_is_all_or_nothing() {
+        loom::model(|| {
+            let data = Arc::new([1, 2, 3].map(Mutex::new));
+            let other = {
+                let data = Arc::clone(&data);
+                thread::spawn(move || {
+                    *data[1].lock().unwrap() += 10;
+                })
+            };
+            let rotated = try_swap3_cab_mutexes(&*data, 0, 1, 2).is_ok();
+            other.join().unwrap();
+
+            let values: Vec<_> = data.iter().map(|m| *m.lock().unwrap()).collect();
+            if rotated {
+                // The increment happened either after or before the rotation.
+                assert!(values == [3, 11, 2] || values == [3, 1, 12], "{values:?}");
+            } else {
+                assert_eq!(values, [1, 12, 3]);
+            }
+        });
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{swap3_bca, swap3_cab};
+use alloc::vec::Vec;
+
+/// Rotates three values of a nested grid to the left.
+///
+/// The coordinates may refer to any rows of the grid, including the same row more than
+/// once; only the coordinates themselves must be pairwise distinct.
+///
+/// ## Arguments
+///
+/// * `grid` - The rows whose elements to swap.
+/// * `a` - The first `(row, column)` coordinate, to be assigned with the value at `b`.
+/// * `b` - The second `(row, column)` coordinate, to be assigned with the value at `c`.
+/// * `c` - The third `(row, column)` coordinate, to be assigned with the value at `a`.
+///
+/// ## Panics
+///
+/// Panics if any of the rows is out of bounds of the grid, if any of the columns is
+/// out of bounds of its row, or if the coordinates are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut grid = vec![vec![1, 2, 3], vec![4], vec![5, 6]];
+/// swap3::swap3_bca_nested(&mut grid, (0, 0), (0, 2), (2, 1));
+/// assert_eq!(grid, [vec![3, 2, 6], vec![4], vec![5, 1]]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_bca_nested<T>(
+    grid: &mut [Vec<T>],
+    a: (usize, usize),
+    b: (usize, usize),
+    c: (usize, usize),
+) {
+    let [a, b, c] = get3_mut(grid, a, b, c);
+    swap3_bca(a, b, c);
+}
+
+/// Rotates three values of a nested grid to the right.
+///
+/// The coordinates may refer to any rows of the grid, including the same row more than
+/// once; only the coordinates themselves must be pairwise distinct.
+///
+/// ## Arguments
+///
+/// * `grid` - The rows whose elements to swap.
+/// * `a` - The first `(row, column)` coordinate, to be assigned with the value at `c`.
+/// * `b` - The second `(row, column)` coordinate, to be assigned with the value at `a`.
+/// * `c` - The third `(row, column)` coordinate, to be assigned with the value at `b`.
+///
+/// ## Panics
+///
+/// Panics if any of the rows is out of bounds of the grid, if any of the columns is
+/// out of bounds of its row, or if the coordinates are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut grid = vec![vec![1, 2, 3], vec![4], vec![5, 6]];
+/// swap3::swap3_cab_nested(&mut grid, (0, 0), (0, 2), (2, 1));
+/// assert_eq!(grid, [vec![6, 2, 1], vec![4], vec![5, 3]]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_cab_nested<T>(
+    grid: &mut [Vec<T>],
+    a: (usize, usize),
+    b: (usize, usize),
+    c: (usize, usize),
+) {
+    let [a, b, c] = get3_mut(grid, a, b, c);
+    swap3_cab(a, b, c);
+}
+
+type Coord = (usize, usize);
+
+/// Returns mutable references to the elements at three distinct coordinates.
+///
+/// Coordinates sharing a row are resolved within that row, as the row can only be
+/// borrowed mutably once.
+fn get3_mut<T>(grid: &mut [Vec<T>], a: Coord, b: Coord, c: Coord) -> [&mut T; 3] {
+    for (row, col) in [a, b, c] {
+        let rows = grid.len();
+        assert!(
+            row < rows,
+            "row out of bounds: the number of rows is {rows} but the row is {row}"
+        );
+        let len = grid[row].len();
+        assert!(
+            col < len,
+            "column out of bounds: the length of row {row} is {len} but the column is {col}"
+        );
+    }
+    assert!(
+        a != b && b != c && a != c,
+        "coordinates must be pairwise distinct, but got {a:?}, {b:?} and {c:?}"
+    );
+
+    let [(ra, ca), (rb, cb), (rc, cc)] = [a, b, c];
+    match (ra == rb, rb == rc, ra == rc) {
+        (true, true, _) => crate::disjoint::get3_mut(&mut grid[ra], ca, cb, cc),
+        (true, false, _) => {
+            let [ab, c] = get2_mut(grid, ra, rc);
+            let [a, b] = get2_mut(ab, ca, cb);
+            [a, b, &mut c[cc]]
+        }
+        (false, true, _) => {
+            let [a, bc] = get2_mut(grid, ra, rb);
+            let [b, c] = get2_mut(bc, cb, cc);
+            [&mut a[ca], b, c]
+        }
+        (false, false, true) => {
+            let [ac, b] = get2_mut(grid, ra, rb);
+            let [a, c] = get2_mut(ac, ca, cc);
+            [a, &mut b[cb], c]
+        }
+        (false, false, false) => {
+            let [a, b, c] = crate::disjoint::get3_mut(grid, ra, rb, rc);
+            [&mut a[ca], &mut b[cb], &mut c[cc]]
+        }
+    }
+}
+
+/// Returns mutable references to the elements at two distinct, in-bounds indices.
+fn get2_mut<T>(data: &mut [T], a: usize, b: usize) -> [&mut T; 2] {
+    debug_assert_ne!(a, b);
+    if a < b {
+        let (head, tail) = data.split_at_mut(b);
+        [&mut head[a], &mut tail[0]]
+    } else {
+        let (head, tail) = data.split_at_mut(a);
+        [&mut tail[0], &mut head[b]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn grid() -> Vec<Vec<u8>> {
+        vec![vec![0, 1, 2], vec![10, 11], vec![20, 21, 22, 23]]
+    }
+
+    #[test]
+    fn test_row_sharing_patterns() {
+        let coords = [
+            ((0, 0), (0, 1), (0, 2)),
+            ((0, 0), (0, 2), (2, 3)),
+            ((1, 1), (2, 0), (2, 3)),
+            ((2, 2), (1, 0), (2, 1)),
+            ((2, 3), (0, 1), (1, 0)),
+        ];
+        for (a, b, c) in coords {
+            let mut actual = grid();
+            swap3_bca_nested(&mut actual, a, b, c);
+            let get = |grid: &[Vec<u8>], (r, c): Coord| grid[r][c];
+            let original = grid();
+            assert_eq!(get(&actual, a), get(&original, b));
+            assert_eq!(get(&actual, b), get(&original, c));
+            assert_eq!(get(&actual, c), get(&original, a));
+
+            swap3_cab_nested(&mut actual, a, b, c);
+            assert_eq!(actual, original);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "column out of bounds: the length of row 1 is 2 but the column is 2")]
+    fn test_column_out_of_bounds() {
+        swap3_bca_nested(&mut grid(), (0, 0), (1, 2), (2, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "row out of bounds: the number of rows is 3 but the row is 3")]
+    fn test_row_out_of_bounds() {
+        swap3_cab_nested(&mut grid(), (0, 0), (3, 0), (2, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "coordinates must be pairwise distinct")]
+    fn test_duplicate_coordinates() {
+        swap3_bca_nested(&mut grid(), (2, 1), (0, 0), (2, 1));
+    }
+}
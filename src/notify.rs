@@ -0,0 +1,179 @@
+use crate::Rotation;
+use std::sync::mpsc::Sender;
+
+/// Describes a rotation applied by [`swap3_bca_slice_notify`] or [`swap3_cab_slice_notify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct RotationEvent {
+    /// The direction of the rotation.
+    pub rotation: Rotation,
+    /// The first index.
+    pub a: usize,
+    /// The second index.
+    pub b: usize,
+    /// The third index.
+    pub c: usize,
+}
+
+/// Rotates three values to the left, then notifies an observer through a channel.
+///
+/// The event is sent after the rotation has been applied. Sending never blocks, as
+/// [`Sender`] is unbounded; if the receiving end has been dropped, the event is discarded
+/// and the rotation is still applied.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `tx` - The channel to send the [`RotationEvent`] to.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// No event is sent in this case.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::mpsc::channel;
+/// use swap3::{Rotation, RotationEvent};
+///
+/// let (tx, rx) = channel();
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_bca_slice_notify(&mut vec, 0, 1, 4, &tx);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+/// assert_eq!(
+///     rx.recv(),
+///     Ok(RotationEvent { rotation: Rotation::Left, a: 0, b: 1, c: 4 })
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn swap3_bca_slice_notify<T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+    tx: &Sender<RotationEvent>,
+) {
+    notify(data, Rotation::Left, a, b, c, tx);
+}
+
+/// Rotates three values to the right, then notifies an observer through a channel.
+///
+/// The event is sent after the rotation has been applied. Sending never blocks, as
+/// [`Sender`] is unbounded; if the receiving end has been dropped, the event is discarded
+/// and the rotation is still applied.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `tx` - The channel to send the [`RotationEvent`] to.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// No event is sent in this case.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::mpsc::channel;
+/// use swap3::{Rotation, RotationEvent};
+///
+/// let (tx, rx) = channel();
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_cab_slice_notify(&mut vec, 0, 1, 4, &tx);
+/// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+/// assert_eq!(rx.recv().unwrap().rotation, Rotation::Right);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn swap3_cab_slice_notify<T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+    tx: &Sender<RotationEvent>,
+) {
+    notify(data, Rotation::Right, a, b, c, tx);
+}
+
+#[inline(always)]
+fn notify<T>(
+    data: &mut [T],
+    rotation: Rotation,
+    a: usize,
+    b: usize,
+    c: usize,
+    tx: &Sender<RotationEvent>,
+) {
+    rotation.apply(data, a, b, c);
+    // A disconnected observer must not affect the rotation itself.
+    let _ = tx.send(RotationEvent { rotation, a, b, c });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+
+    #[test]
+    fn test_event_sequence() {
+        let (tx, rx) = channel();
+        let mut data = [1, 2, 3, 4, 5];
+        swap3_bca_slice_notify(&mut data, 0, 2, 4, &tx);
+        swap3_cab_slice_notify(&mut data, 4, 3, 1, &tx);
+        drop(tx);
+
+        let events: Vec<_> = rx.iter().collect();
+        assert_eq!(
+            events,
+            [
+                RotationEvent {
+                    rotation: Rotation::Left,
+                    a: 0,
+                    b: 2,
+                    c: 4
+                },
+                RotationEvent {
+                    rotation: Rotation::Right,
+                    a: 4,
+                    b: 3,
+                    c: 1
+                },
+            ]
+        );
+
+        // Replaying the events in reverse order with inverted directions restores the data.
+        for event in events.iter().rev() {
+            event
+                .rotation
+                .inverse()
+                .apply(&mut data, event.a, event.b, event.c);
+        }
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_disconnected_observer() {
+        let (tx, rx) = channel();
+        drop(rx);
+        let mut data = [1, 2, 3];
+        swap3_bca_slice_notify(&mut data, 0, 1, 2, &tx);
+        assert_eq!(data, [2, 3, 1]);
+    }
+
+    #[test]
+    fn test_no_event_on_panic() {
+        let (tx, rx) = channel();
+        let result = std::panic::catch_unwind(|| {
+            swap3_cab_slice_notify(&mut [1, 2, 3], 0, 1, 3, &tx);
+        });
+        assert!(result.is_err());
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{swap3_bca, swap3_cab};
+
+/// Rotates the values behind the present references to the left, skipping absent ones.
+///
+/// If all three references are present, this behaves like [`swap3_bca`](crate::swap3_bca).
+/// If exactly two are present, their values are swapped, which is the only rotation of two
+/// values. With fewer than two present references, nothing changes.
+///
+/// ## Arguments
+///
+/// * `a` - The first reference, to be assigned with the value of the next present one.
+/// * `b` - The second reference, to be assigned with the value of the next present one.
+/// * `c` - The third reference, to be assigned with the value of the first present one.
+///
+/// ## Example
+///
+/// ```
+/// let (mut a, mut b, mut c) = (10, 20, 30);
+/// swap3::swap3_bca_opt_refs(Some(&mut a), Some(&mut b), Some(&mut c));
+/// assert_eq!((a, b, c), (20, 30, 10));
+///
+/// swap3::swap3_bca_opt_refs(Some(&mut a), None, Some(&mut c));
+/// assert_eq!((a, b, c), (10, 30, 20));
+/// ```
+pub fn swap3_bca_opt_refs<T>(a: Option<&mut T>, b: Option<&mut T>, c: Option<&mut T>) {
+    match (a, b, c) {
+        (Some(a), Some(b), Some(c)) => swap3_bca(a, b, c),
+        (Some(x), Some(y), None) | (Some(x), None, Some(y)) | (None, Some(x), Some(y)) => {
+            core::mem::swap(x, y)
+        }
+        _ => {}
+    }
+}
+
+/// Rotates the values behind the present references to the right, skipping absent ones.
+///
+/// If all three references are present, this behaves like [`swap3_cab`](crate::swap3_cab).
+/// If exactly two are present, their values are swapped, which is the only rotation of two
+/// values. With fewer than two present references, nothing changes.
+///
+/// ## Arguments
+///
+/// * `a` - The first reference, to be assigned with the value of the last present one.
+/// * `b` - The second reference, to be assigned with the value of the previous present one.
+/// * `c` - The third reference, to be assigned with the value of the previous present one.
+///
+/// ## Example
+///
+/// ```
+/// let (mut a, mut b, mut c) = (10, 20, 30);
+/// swap3::swap3_cab_opt_refs(Some(&mut a), Some(&mut b), Some(&mut c));
+/// assert_eq!((a, b, c), (30, 10, 20));
+///
+/// swap3::swap3_cab_opt_refs(None, Some(&mut b), None);
+/// assert_eq!((a, b, c), (30, 10, 20));
+/// ```
+pub fn swap3_cab_opt_refs<T>(a: Option<&mut T>, b: Option<&mut T>, c: Option<&mut T>) {
+    match (a, b, c) {
+        (Some(a), Some(b), Some(c)) => swap3_cab(a, b, c),
+        (Some(x), Some(y), None) | (Some(x), None, Some(y)) | (None, Some(x), Some(y)) => {
+            core::mem::swap(x, y)
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_present() {
+        let mut data = [1, 2, 3];
+        let [a, b, c] = data.each_mut();
+        swap3_bca_opt_refs(Some(a), Some(b), Some(c));
+        assert_eq!(data, [2, 3, 1]);
+        let [a, b, c] = data.each_mut();
+        swap3_cab_opt_refs(Some(a), Some(b), Some(c));
+        assert_eq!(data, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_two_present() {
+        let mut data = [1, 2, 3];
+        let [a, b, _] = data.each_mut();
+        swap3_bca_opt_refs(Some(a), Some(b), None);
+        assert_eq!(data, [2, 1, 3]);
+        let [_, b, c] = data.each_mut();
+        swap3_cab_opt_refs(None, Some(b), Some(c));
+        assert_eq!(data, [2, 3, 1]);
+    }
+
+    #[test]
+    fn test_fewer_than_two_present() {
+        let mut value = 1;
+        swap3_bca_opt_refs(None, Some(&mut value), None);
+        swap3_cab_opt_refs::<i32>(None, None, None);
+        assert_eq!(value, 1);
+    }
+}
@@ -0,0 +1,273 @@
+/// A mutable view of unsigned integers packed into a slice of words with a fixed number of
+/// bits per element.
+///
+/// Element `i` occupies the bits `i * bits .. (i + 1) * bits` of the words, counting from the
+/// least significant bit of the first word. Elements are not aligned to word boundaries, so
+/// an element may span two adjacent words.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::PackedInts;
+///
+/// let mut words = [0u64; 2];
+/// let mut packed = PackedInts::new(&mut words, 5, 25);
+/// packed.set(12, 0b10110);
+/// assert_eq!(packed.get(12), 0b10110);
+///
+/// // The element starts at bit 60, spanning the two words.
+/// assert_eq!(words, [0b0110 << 60, 0b1]);
+/// ```
+#[derive(Debug)]
+pub struct PackedInts<'a> {
+    words: &'a mut [u64],
+    bits: u32,
+    len: usize,
+}
+
+impl<'a> PackedInts<'a> {
+    /// Creates a view of `len` elements of `bits` bits each, packed into `words`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `bits` is zero or greater than 64, or if `words` holds fewer than
+    /// `len * bits` bits.
+    pub fn new(words: &'a mut [u64], bits: u32, len: usize) -> Self {
+        assert!(
+            (1..=u64::BITS).contains(&bits),
+            "the number of bits per element must be between 1 and 64, but got {bits}"
+        );
+        // The bit positions of all elements must also be representable as `usize`.
+        let fits = match len.checked_mul(bits as usize) {
+            Some(required) => required as u128 <= words.len() as u128 * u64::BITS as u128,
+            None => false,
+        };
+        assert!(
+            fits,
+            "{len} elements of {bits} bits do not fit into {} words",
+            words.len()
+        );
+        Self { words, bits, len }
+    }
+
+    /// Returns the number of elements.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view holds no elements.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bits per element.
+    #[inline(always)]
+    pub const fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Returns the largest value an element can hold.
+    #[inline(always)]
+    pub const fn max_value(&self) -> u64 {
+        u64::MAX >> (u64::BITS - self.bits)
+    }
+
+    /// Returns the element at `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> u64 {
+        self.check_index(index);
+        let (word, offset) = self.position(index);
+        let mut value = self.words[word] >> offset;
+        if offset + self.bits > u64::BITS {
+            // The remaining high bits are the low bits of the next word.
+            value |= self.words[word + 1] << (u64::BITS - offset);
+        }
+        value & self.max_value()
+    }
+
+    /// Sets the element at `index` to `value`, leaving all other elements unchanged.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds or if `value` exceeds [`PackedInts::max_value`].
+    pub fn set(&mut self, index: usize, value: u64) {
+        self.check_index(index);
+        assert!(
+            value <= self.max_value(),
+            "the value {value} does not fit into {} bits",
+            self.bits
+        );
+        let (word, offset) = self.position(index);
+        let mask = self.max_value() << offset;
+        self.words[word] = (self.words[word] & !mask) | (value << offset);
+        if offset + self.bits > u64::BITS {
+            let high_bits = offset + self.bits - u64::BITS;
+            let high_mask = (1 << high_bits) - 1;
+            self.words[word + 1] =
+                (self.words[word + 1] & !high_mask) | (value >> (u64::BITS - offset));
+        }
+    }
+
+    /// Returns the index of the word holding the lowest bit of an element and the offset of
+    /// that bit within the word.
+    #[inline(always)]
+    fn position(&self, index: usize) -> (usize, u32) {
+        // Does not overflow, as the bits of all elements fit into the words.
+        let bit = index * self.bits as usize;
+        (bit / u64::BITS as usize, (bit % u64::BITS as usize) as u32)
+    }
+
+    #[inline(always)]
+    fn check_index(&self, index: usize) {
+        assert!(
+            index < self.len,
+            "index out of bounds: the len is {} but the index is {index}",
+            self.len
+        );
+    }
+}
+
+/// Rotates three elements of a bit-packed integer array to the left.
+///
+/// The three values are extracted, rotated, and inserted again, masking the bits of each
+/// element so that neighbouring elements sharing the same words are left unchanged.
+///
+/// ## Arguments
+///
+/// * `data` - The packed integers whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is not less than [`PackedInts::len`] or if the indices are
+/// not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::PackedInts;
+///
+/// let mut words = [0u64; 1];
+/// let mut packed = PackedInts::new(&mut words, 3, 4);
+/// for (index, value) in [1, 2, 3, 4].into_iter().enumerate() {
+///     packed.set(index, value);
+/// }
+/// swap3::swap3_bca_packed(&mut packed, 0, 1, 3);
+/// assert_eq!([0, 1, 2, 3].map(|i| packed.get(i)), [2, 4, 3, 1]);
+/// ```
+pub fn swap3_bca_packed(data: &mut PackedInts<'_>, a: usize, b: usize, c: usize) {
+    let [va, vb, vc] = get3(data, a, b, c);
+    data.set(a, vb);
+    data.set(b, vc);
+    data.set(c, va);
+}
+
+/// Rotates three elements of a bit-packed integer array to the right.
+///
+/// The three values are extracted, rotated, and inserted again, masking the bits of each
+/// element so that neighbouring elements sharing the same words are left unchanged.
+///
+/// ## Arguments
+///
+/// * `data` - The packed integers whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is not less than [`PackedInts::len`] or if the indices are
+/// not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::PackedInts;
+///
+/// let mut words = [0u64; 1];
+/// let mut packed = PackedInts::new(&mut words, 3, 4);
+/// for (index, value) in [1, 2, 3, 4].into_iter().enumerate() {
+///     packed.set(index, value);
+/// }
+/// swap3::swap3_cab_packed(&mut packed, 0, 1, 3);
+/// assert_eq!([0, 1, 2, 3].map(|i| packed.get(i)), [4, 1, 3, 2]);
+/// ```
+pub fn swap3_cab_packed(data: &mut PackedInts<'_>, a: usize, b: usize, c: usize) {
+    let [va, vb, vc] = get3(data, a, b, c);
+    data.set(a, vc);
+    data.set(b, va);
+    data.set(c, vb);
+}
+
+/// Validates three indices against the logical length and reads their values.
+fn get3(data: &PackedInts<'_>, a: usize, b: usize, c: usize) -> [u64; 3] {
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+    [a, b, c].map(|index| data.get(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(packed: &mut PackedInts<'_>) {
+        for index in 0..packed.len() {
+            let value = (index as u64 * 7 + 3) & packed.max_value();
+            packed.set(index, value);
+        }
+    }
+
+    fn values(packed: &PackedInts<'_>) -> [u64; 25] {
+        core::array::from_fn(|index| packed.get(index))
+    }
+
+    #[test]
+    fn test_across_word_boundaries() {
+        // With 5 bits per element, element 12 spans bits 60 to 64.
+        let mut words = [0; 2];
+        let mut packed = PackedInts::new(&mut words, 5, 25);
+        fill(&mut packed);
+        let mut expected = values(&packed);
+
+        swap3_bca_packed(&mut packed, 12, 0, 24);
+        crate::swap3_bca_slice(&mut expected, 12, 0, 24);
+        assert_eq!(values(&packed), expected);
+
+        swap3_cab_packed(&mut packed, 11, 12, 13);
+        crate::swap3_cab_slice(&mut expected, 11, 12, 13);
+        assert_eq!(values(&packed), expected);
+    }
+
+    #[test]
+    fn test_full_and_single_bit_widths() {
+        let mut words = [u64::MAX, 1, 1 << 63];
+        let mut packed = PackedInts::new(&mut words, 64, 3);
+        swap3_bca_packed(&mut packed, 0, 1, 2);
+        assert_eq!(words, [1, 1 << 63, u64::MAX]);
+
+        let mut words = [0b1011];
+        let mut packed = PackedInts::new(&mut words, 1, 5);
+        swap3_cab_packed(&mut packed, 1, 2, 3);
+        assert_eq!(words, [0b0111]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 25 but the index is 25")]
+    fn test_beyond_logical_len() {
+        // The words have room for 128 bits, but only 25 elements are valid.
+        let mut words = [0; 2];
+        swap3_bca_packed(&mut PackedInts::new(&mut words, 5, 25), 0, 1, 25);
+    }
+
+    #[test]
+    #[should_panic(expected = "do not fit")]
+    fn test_too_few_words() {
+        let _ = PackedInts::new(&mut [0; 2], 5, 26);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use alloc::vec::Vec;
+
+/// Rotates three values of a [`Vec`] to the left, growing it to fit the indices.
+///
+/// If any of the indices is beyond the end of the vector, the vector is first resized to
+/// `max(a, b, c) + 1` elements, filling the new positions with [`Default::default`].
+/// Growing the vector may allocate.
+///
+/// ## Arguments
+///
+/// * `vec` - The vector whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `vec[b]`.
+/// * `b` - The second index, to be assigned with the value of `vec[c]`.
+/// * `c` - The third index, to be assigned with the value of `vec[a]`.
+///
+/// ## Panics
+///
+/// Panics if the indices are not pairwise distinct, or if any of the indices is `usize::MAX`,
+/// in which case the required length cannot be represented. The vector is not grown in
+/// either case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20];
+/// swap3::swap3_bca_vec_padded(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[20, 0, 0, 0, 10]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_bca_vec_padded<T: Default>(vec: &mut Vec<T>, a: usize, b: usize, c: usize) {
+    pad_to_fit(vec, a, b, c);
+    swap3_bca_slice(vec, a, b, c);
+}
+
+/// Rotates three values of a [`Vec`] to the right, growing it to fit the indices.
+///
+/// If any of the indices is beyond the end of the vector, the vector is first resized to
+/// `max(a, b, c) + 1` elements, filling the new positions with [`Default::default`].
+/// Growing the vector may allocate.
+///
+/// ## Arguments
+///
+/// * `vec` - The vector whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `vec[c]`.
+/// * `b` - The second index, to be assigned with the value of `vec[a]`.
+/// * `c` - The third index, to be assigned with the value of `vec[b]`.
+///
+/// ## Panics
+///
+/// Panics if the indices are not pairwise distinct, or if any of the indices is `usize::MAX`,
+/// in which case the required length cannot be represented. The vector is not grown in
+/// either case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20];
+/// swap3::swap3_cab_vec_padded(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[0, 10, 0, 0, 20]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_cab_vec_padded<T: Default>(vec: &mut Vec<T>, a: usize, b: usize, c: usize) {
+    pad_to_fit(vec, a, b, c);
+    swap3_cab_slice(vec, a, b, c);
+}
+
+fn pad_to_fit<T: Default>(vec: &mut Vec<T>, a: usize, b: usize, c: usize) {
+    assert!(
+        a != b && b != c && a != c,
+        "indices must be pairwise distinct, but got {a}, {b} and {c}"
+    );
+
+    let max = a.max(b).max(c);
+    let Some(len) = max.checked_add(1) else {
+        panic!("index overflow: cannot grow the vector to fit the index {max}");
+    };
+    if len > vec.len() {
+        vec.resize_with(len, T::default);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_swap3_bca_vec_padded() {
+        let mut vec = vec![1, 2, 3, 4, 5];
+        swap3_bca_vec_padded(&mut vec, 0, 2, 4);
+        assert_eq!(vec, [3, 2, 5, 4, 1]);
+        swap3_bca_vec_padded(&mut vec, 1, 6, 3);
+        assert_eq!(vec, [3, 0, 5, 2, 1, 0, 4]);
+    }
+
+    #[test]
+    fn test_swap3_cab_vec_padded() {
+        let mut vec: Vec<String> = Vec::new();
+        swap3_cab_vec_padded(&mut vec, 2, 0, 1);
+        assert_eq!(vec, ["", "", ""]);
+        vec[0].push('a');
+        swap3_cab_vec_padded(&mut vec, 0, 1, 3);
+        assert_eq!(vec, ["", "a", "", ""]);
+    }
+
+    #[test]
+    fn test_duplicate_does_not_grow() {
+        let mut vec = vec![1, 2, 3];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_bca_vec_padded(&mut vec, 0, 5, 5);
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index overflow: cannot grow the vector to fit the index")]
+    fn test_index_overflow() {
+        let mut vec = vec![1, 2, 3];
+        swap3_cab_vec_padded(&mut vec, 0, usize::MAX, 1);
+    }
+}
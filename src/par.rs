@@ -0,0 +1,83 @@
+//! Parallel rotation of disjoint triples using [`rayon`](::rayon).
+
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use ::rayon::prelude::*;
+
+/// Rotates each consecutive, non-overlapping triple of a slice to the left, in parallel.
+///
+/// The slice is split into the windows `[0, 1, 2]`, `[3, 4, 5]` and so on, each of which
+/// is rotated like [`swap3_bca_slice`] with the indices `0, 1, 2`. As the windows are
+/// disjoint, they are processed in parallel using `par_chunks_exact_mut`. If the length of the
+/// slice is not a multiple of three, the trailing one or two elements are left unchanged.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose triples to rotate.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![1, 2, 3, 4, 5, 6, 7, 8];
+/// swap3::rotate_triples_left_par(&mut vec);
+/// assert_eq!(vec, &[2, 3, 1, 5, 6, 4, 7, 8]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn rotate_triples_left_par<T: Send>(data: &mut [T]) {
+    data.par_chunks_exact_mut(3)
+        .for_each(|triple| swap3_bca_slice(triple, 0, 1, 2));
+}
+
+/// Rotates each consecutive, non-overlapping triple of a slice to the right, in parallel.
+///
+/// The slice is split into the windows `[0, 1, 2]`, `[3, 4, 5]` and so on, each of which
+/// is rotated like [`swap3_cab_slice`] with the indices `0, 1, 2`. As the windows are
+/// disjoint, they are processed in parallel using `par_chunks_exact_mut`. If the length of the
+/// slice is not a multiple of three, the trailing one or two elements are left unchanged.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose triples to rotate.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![1, 2, 3, 4, 5, 6, 7, 8];
+/// swap3::rotate_triples_right_par(&mut vec);
+/// assert_eq!(vec, &[3, 1, 2, 6, 4, 5, 7, 8]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn rotate_triples_right_par<T: Send>(data: &mut [T]) {
+    data.par_chunks_exact_mut(3)
+        .for_each(|triple| swap3_cab_slice(triple, 0, 1, 2));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_sequential() {
+        for len in [0, 1, 2, 3, 4, 5, 6, 10_000, 10_001, 10_002] {
+            let mut expected: Vec<usize> = (0..len).collect();
+            let mut actual = expected.clone();
+            for chunk in expected.chunks_exact_mut(3) {
+                chunk.rotate_left(1);
+            }
+            rotate_triples_left_par(&mut actual);
+            assert_eq!(actual, expected, "len {len}");
+
+            for chunk in expected.chunks_exact_mut(3) {
+                chunk.rotate_right(1);
+            }
+            rotate_triples_right_par(&mut actual);
+            assert_eq!(actual, expected, "len {len}");
+        }
+    }
+
+    #[test]
+    fn test_remainder_untouched() {
+        let mut data = [1, 2, 3, 4, 5];
+        rotate_triples_right_par(&mut data);
+        assert_eq!(data, [3, 1, 2, 4, 5]);
+    }
+}
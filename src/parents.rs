@@ -0,0 +1,160 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three nodes of a disjoint-set forest to the left, keeping the forest consistent.
+///
+/// `parent` is the parent array of a union-find structure, in which `parent[i]` is the parent
+/// of node `i` and roots are their own parents. The entries are rotated like
+/// [`swap3_bca_slice`] does, moving the node at `b` to `a`, the one at `c` to `b` and the one
+/// at `a` to `c`. Since the nodes change their indices, all references to them are updated as
+/// well: every entry equal to `b` becomes `a`, every entry equal to `c` becomes `b`, and every
+/// entry equal to `a` becomes `c`.
+///
+/// The result describes the same forest with the three nodes relabeled. In particular, a root
+/// moved to another index remains a root, each node keeps its parent, and the membership of
+/// the sets is preserved. Data associated with the nodes, such as ranks or sizes, can be kept
+/// in sync by rotating it using [`swap3_bca_slice`] with the same indices.
+///
+/// All entries are scanned, hence this takes `O(n)` time for `n` nodes. Entries that do not
+/// refer to any of the three nodes are left unchanged, even if they are out of bounds.
+///
+/// ## Arguments
+///
+/// * `parent` - The parent array of the forest.
+/// * `a` - The first index, to be assigned with the node at `b`.
+/// * `b` - The second index, to be assigned with the node at `c`.
+/// * `c` - The third index, to be assigned with the node at `a`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// // The sets {0, 1} and {2, 3}, rooted at 0 and 2, respectively.
+/// let mut parent = vec![0, 0, 2, 2];
+/// swap3::swap3_bca_parents(&mut parent, 0, 1, 2);
+///
+/// // The former root 0 is now at index 2 and the former root 2 at index 1.
+/// assert_eq!(parent, &[2, 1, 2, 1]);
+/// ```
+pub fn swap3_bca_parents(parent: &mut [usize], a: usize, b: usize, c: usize) {
+    crate::disjoint::assert_indices(parent.len(), a, b, c);
+    relabel(parent, [(a, c), (b, a), (c, b)]);
+    swap3_bca_slice(parent, a, b, c);
+}
+
+/// Rotates three nodes of a disjoint-set forest to the right, keeping the forest consistent.
+///
+/// `parent` is the parent array of a union-find structure, in which `parent[i]` is the parent
+/// of node `i` and roots are their own parents. The entries are rotated like
+/// [`swap3_cab_slice`] does, moving the node at `c` to `a`, the one at `a` to `b` and the one
+/// at `b` to `c`. Since the nodes change their indices, all references to them are updated as
+/// well: every entry equal to `c` becomes `a`, every entry equal to `a` becomes `b`, and every
+/// entry equal to `b` becomes `c`.
+///
+/// The result describes the same forest with the three nodes relabeled. In particular, a root
+/// moved to another index remains a root, each node keeps its parent, and the membership of
+/// the sets is preserved. Data associated with the nodes, such as ranks or sizes, can be kept
+/// in sync by rotating it using [`swap3_cab_slice`] with the same indices.
+///
+/// All entries are scanned, hence this takes `O(n)` time for `n` nodes. Entries that do not
+/// refer to any of the three nodes are left unchanged, even if they are out of bounds.
+///
+/// ## Arguments
+///
+/// * `parent` - The parent array of the forest.
+/// * `a` - The first index, to be assigned with the node at `c`.
+/// * `b` - The second index, to be assigned with the node at `a`.
+/// * `c` - The third index, to be assigned with the node at `b`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// // The sets {0, 1} and {2, 3}, rooted at 0 and 2, respectively.
+/// let mut parent = vec![0, 0, 2, 2];
+/// swap3::swap3_cab_parents(&mut parent, 0, 1, 2);
+///
+/// // The former root 0 is now at index 1 and the former root 2 at index 0.
+/// assert_eq!(parent, &[0, 1, 1, 0]);
+/// ```
+pub fn swap3_cab_parents(parent: &mut [usize], a: usize, b: usize, c: usize) {
+    crate::disjoint::assert_indices(parent.len(), a, b, c);
+    relabel(parent, [(a, b), (b, c), (c, a)]);
+    swap3_cab_slice(parent, a, b, c);
+}
+
+/// Replaces every entry equal to the first index of a pair with the second index.
+#[inline(always)]
+fn relabel(parent: &mut [usize], moves: [(usize, usize); 3]) {
+    let [(a, to_a), (b, to_b), (c, to_c)] = moves;
+    for entry in parent {
+        *entry = match *entry {
+            p if p == a => to_a,
+            p if p == b => to_b,
+            p if p == c => to_c,
+            p => p,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(parent: &[usize], mut node: usize) -> usize {
+        while parent[node] != node {
+            node = parent[node];
+        }
+        node
+    }
+
+    /// Checks that `after` is `before` with the node at each index `i` moved to `target(i)`.
+    fn check_relabeled(before: &[usize], after: &[usize], target: impl Fn(usize) -> usize) {
+        for i in 0..before.len() {
+            assert_eq!(after[target(i)], target(before[i]), "node {i}");
+            for j in 0..before.len() {
+                assert_eq!(
+                    find(before, i) == find(before, j),
+                    find(after, target(i)) == find(after, target(j))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_forest_is_preserved() {
+        // Two trees rooted at 0 and 5, and the single-node tree 7.
+        let before = [0, 0, 1, 1, 0, 5, 5, 7, 6];
+        for (a, b, c) in [(0, 5, 7), (2, 0, 6), (1, 3, 8), (4, 7, 5)] {
+            let mut after = before;
+            swap3_bca_parents(&mut after, a, b, c);
+            check_relabeled(&before, &after, |i| match i {
+                i if i == a => c,
+                i if i == b => a,
+                i if i == c => b,
+                i => i,
+            });
+
+            swap3_cab_parents(&mut after, a, b, c);
+            assert_eq!(after, before);
+        }
+    }
+
+    #[test]
+    fn test_roots_stay_roots() {
+        let mut parent = [0, 1, 2, 3];
+        swap3_cab_parents(&mut parent, 3, 0, 2);
+        assert_eq!(parent, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_overlapping() {
+        swap3_bca_parents(&mut [0, 1, 2], 0, 1, 1);
+    }
+}
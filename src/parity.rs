@@ -0,0 +1,147 @@
+use crate::{swap3_bca_slice, swap3_cab_slice, Rotation};
+
+/// Returns the sign of the permutation applied by a rotation in the given direction.
+///
+/// A rotation of three distinct values is a three-cycle, which decomposes into two
+/// transpositions. It is therefore an even permutation with sign `+1`, regardless of its
+/// direction. This is useful when composing rotations with other permutations whose signs
+/// are tracked, e.g. the row exchanges of a determinant computation.
+///
+/// ## Arguments
+///
+/// * `dir` - The direction of the rotation.
+///
+/// ## Returns
+///
+/// `+1` for an even and `-1` for an odd permutation.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::{rotation_parity, Rotation};
+///
+/// assert_eq!(rotation_parity(Rotation::Left), 1);
+/// assert_eq!(rotation_parity(Rotation::Right), 1);
+/// ```
+#[inline(always)]
+pub const fn rotation_parity(dir: Rotation) -> i8 {
+    match dir {
+        Rotation::Left | Rotation::Right => 1,
+    }
+}
+
+/// Rotates three values of a slice to the left, multiplying `sign` by the sign of the
+/// applied permutation.
+///
+/// See [`rotation_parity`] for the sign of a rotation.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `sign` - The accumulated sign of all permutations applied to `data` so far.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `sign` is left unchanged in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut rows = vec![[0, 1], [1, 0], [2, 2]];
+/// let mut sign = 1;
+///
+/// // A row exchange flips the sign of the determinant ...
+/// rows.swap(0, 1);
+/// sign = -sign;
+///
+/// // ... while a rotation of three rows keeps it.
+/// swap3::swap3_bca_slice_parity(&mut rows, 0, 1, 2, &mut sign);
+/// assert_eq!(rows, [[0, 1], [2, 2], [1, 0]]);
+/// assert_eq!(sign, -1);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_slice_parity<T>(data: &mut [T], a: usize, b: usize, c: usize, sign: &mut i8) {
+    swap3_bca_slice(data, a, b, c);
+    *sign *= rotation_parity(Rotation::Left);
+}
+
+/// Rotates three values of a slice to the right, multiplying `sign` by the sign of the
+/// applied permutation.
+///
+/// See [`rotation_parity`] for the sign of a rotation.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `sign` - The accumulated sign of all permutations applied to `data` so far.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `sign` is left unchanged in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20, 30];
+/// let mut sign = 1;
+/// swap3::swap3_cab_slice_parity(&mut vec, 0, 1, 2, &mut sign);
+/// assert_eq!(vec, &[30, 10, 20]);
+/// assert_eq!(sign, 1);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_slice_parity<T>(data: &mut [T], a: usize, b: usize, c: usize, sign: &mut i8) {
+    swap3_cab_slice(data, a, b, c);
+    *sign *= rotation_parity(Rotation::Right);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Computes the sign of a permutation of `0..N` by counting its inversions.
+    fn sign_by_inversions<const N: usize>(perm: &[usize; N]) -> i8 {
+        let inversions = (0..N)
+            .flat_map(|i| (i + 1..N).map(move |j| (i, j)))
+            .filter(|&(i, j)| perm[i] > perm[j])
+            .count();
+        if inversions % 2 == 0 {
+            1
+        } else {
+            -1
+        }
+    }
+
+    #[test]
+    fn test_parity_matches_inversions() {
+        for dir in [Rotation::Left, Rotation::Right] {
+            let mut perm = [0, 1, 2, 3, 4];
+            dir.apply(&mut perm, 3, 0, 4);
+            assert_eq!(rotation_parity(dir), sign_by_inversions(&perm));
+        }
+    }
+
+    #[test]
+    fn test_accumulates_sign() {
+        let mut data = [1, 2, 3, 4, 5];
+        let mut sign = -1;
+        swap3_bca_slice_parity(&mut data, 4, 0, 2, &mut sign);
+        swap3_cab_slice_parity(&mut data, 1, 2, 3, &mut sign);
+        assert_eq!(data, [3, 4, 2, 5, 1]);
+        assert_eq!(sign, -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_out_of_bounds() {
+        let mut sign = 1;
+        swap3_bca_slice_parity(&mut [1, 2, 3], 0, 1, 3, &mut sign);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::slice;
+
+/// The implementation used by [`swap3_bca_slice`](crate::swap3_bca_slice) and
+/// [`swap3_cab_slice`](crate::swap3_cab_slice), as selected by the crate features.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::PathUsed;
+///
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// let path = swap3::swap3_bca_slice_reporting(&mut vec, 0, 1, 4);
+/// assert_eq!(path, PathUsed::CURRENT);
+/// assert_eq!(path == PathUsed::Unsafe, cfg!(feature = "unsafe"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathUsed {
+    /// The safe implementation, [`slice::bca_safe`] and [`slice::cab_safe`].
+    Safe,
+    /// The unsafe implementation, `slice::bca_unsafe` and `slice::cab_unsafe`.
+    /// Used if the `unsafe` feature is enabled.
+    Unsafe,
+}
+
+impl PathUsed {
+    /// The implementation selected by the crate features of the current build.
+    #[cfg(feature = "unsafe")]
+    pub const CURRENT: Self = Self::Unsafe;
+
+    /// The implementation selected by the crate features of the current build.
+    #[cfg(not(feature = "unsafe"))]
+    pub const CURRENT: Self = Self::Safe;
+}
+
+/// Rotates three values to the left, reporting the implementation used.
+///
+/// This behaves exactly like [`swap3_bca_slice`](crate::swap3_bca_slice).
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// let path = swap3::swap3_bca_slice_reporting(&mut vec, 0, 1, 4);
+/// assert_eq!(path, swap3::PathUsed::CURRENT);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_slice_reporting<T>(data: &mut [T], a: usize, b: usize, c: usize) -> PathUsed {
+    #[cfg(feature = "unsafe")]
+    {
+        slice::bca_unsafe(data, a, b, c);
+        PathUsed::Unsafe
+    }
+    #[cfg(not(feature = "unsafe"))]
+    {
+        slice::bca_safe(data, a, b, c);
+        PathUsed::Safe
+    }
+}
+
+/// Rotates three values to the right, reporting the implementation used.
+///
+/// This behaves exactly like [`swap3_cab_slice`](crate::swap3_cab_slice).
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// let path = swap3::swap3_cab_slice_reporting(&mut vec, 0, 1, 4);
+/// assert_eq!(path, swap3::PathUsed::CURRENT);
+/// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_slice_reporting<T>(data: &mut [T], a: usize, b: usize, c: usize) -> PathUsed {
+    #[cfg(feature = "unsafe")]
+    {
+        slice::cab_unsafe(data, a, b, c);
+        PathUsed::Unsafe
+    }
+    #[cfg(not(feature = "unsafe"))]
+    {
+        slice::cab_safe(data, a, b, c);
+        PathUsed::Safe
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reported_path_matches_features() {
+        let mut data = [1, 2, 3, 4, 5];
+        let expected = if cfg!(feature = "unsafe") {
+            PathUsed::Unsafe
+        } else {
+            PathUsed::Safe
+        };
+        assert_eq!(PathUsed::CURRENT, expected);
+        assert_eq!(swap3_bca_slice_reporting(&mut data, 0, 2, 4), expected);
+        assert_eq!(data, [3, 2, 5, 4, 1]);
+        assert_eq!(swap3_cab_slice_reporting(&mut data, 0, 2, 4), expected);
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_reporting_duplicate() {
+        swap3_bca_slice_reporting(&mut [1, 2, 3], 0, 2, 2);
+    }
+}
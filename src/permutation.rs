@@ -0,0 +1,370 @@
+use crate::swap3_cab_slice;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+/// The error returned by [`apply_permutation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub enum PermutationError {
+    /// The permutation does not have the same length as the slice.
+    LengthMismatch {
+        /// The length of the slice.
+        len: usize,
+        /// The length of the permutation.
+        perm_len: usize,
+    },
+    /// A target position is out of bounds for the slice.
+    OutOfBounds {
+        /// The index into the permutation holding the offending target.
+        index: usize,
+        /// The offending target position.
+        target: usize,
+    },
+    /// A target position was given more than once.
+    DuplicateTarget {
+        /// The index into the permutation holding the second occurrence of the target.
+        index: usize,
+        /// The repeated target position.
+        target: usize,
+    },
+}
+
+impl Display for PermutationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PermutationError::LengthMismatch { len, perm_len } => write!(
+                f,
+                "the permutation must match the slice: the len is {len} but the permutation has {perm_len} entries"
+            ),
+            PermutationError::OutOfBounds { index, target } => write!(
+                f,
+                "the target {target} at index {index} of the permutation is out of bounds"
+            ),
+            PermutationError::DuplicateTarget { index, target } => write!(
+                f,
+                "the target {target} at index {index} of the permutation was given more than once"
+            ),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for PermutationError {}
+
+#[cfg(all(not(has_core_error), feature = "std"))]
+impl std::error::Error for PermutationError {}
+
+/// Permutes a slice in place, moving the element at index `i` to index `perm[i]`.
+///
+/// The permutation is decomposed into its disjoint cycles, each of which is applied using
+/// rotations of three elements: a cycle of length `k >= 3` is shortened by two with every
+/// right rotation (see [`swap3_cab_slice`]), leaving either nothing or a single swap for
+/// the remaining two elements. As with [`permute3`](crate::permute3), the element at `i`
+/// ends up at `perm[i]`; the identity permutation does not move anything.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to permute.
+/// * `perm` - The target positions, which must be a permutation of `0..data.len()`.
+///
+/// ## Returns
+///
+/// The number of moves performed, counting each rotation and each swap as one move.
+///
+/// ## Errors
+///
+/// Returns a [`PermutationError`] if `perm` does not have the length of `data`, or if it
+/// is not a permutation of `0..data.len()`. The slice is left unchanged in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec!['a', 'b', 'c', 'd', 'e'];
+///
+/// // A five-cycle 0 → 1 → 2 → 3 → 4 → 0, applied using two rotations.
+/// assert_eq!(swap3::apply_permutation(&mut vec, &[1, 2, 3, 4, 0]), Ok(2));
+/// assert_eq!(vec, &['e', 'a', 'b', 'c', 'd']);
+///
+/// // The target 0 is given twice.
+/// assert!(swap3::apply_permutation(&mut vec, &[0, 1, 0, 3, 4]).is_err());
+/// assert_eq!(vec, &['e', 'a', 'b', 'c', 'd']);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn apply_permutation<T>(data: &mut [T], perm: &[usize]) -> Result<usize, PermutationError> {
+    let len = data.len();
+    let mut done = validate(len, perm)?;
+    // Reuse the marks for the positions already placed.
+    done.fill(false);
+
+    let mut moves = 0;
+    for head in 0..len {
+        if done[head] {
+            continue;
+        }
+        done[head] = true;
+
+        // The element currently at `head` must move to `next`. Rotating it along with the
+        // element at `next` places both, and leaves the element that belongs to `perm[y]`
+        // at `head` again.
+        let mut next = perm[head];
+        while next != head {
+            let y = perm[next];
+            done[next] = true;
+            if y == head {
+                data.swap(head, next);
+                moves += 1;
+                break;
+            }
+            done[y] = true;
+            swap3_cab_slice(data, head, next, y);
+            moves += 1;
+            next = perm[y];
+        }
+    }
+    Ok(moves)
+}
+
+/// Ensures that `perm` is a permutation of `0..len`, returning the marks of all targets.
+fn validate(len: usize, perm: &[usize]) -> Result<Vec<bool>, PermutationError> {
+    if perm.len() != len {
+        return Err(PermutationError::LengthMismatch {
+            len,
+            perm_len: perm.len(),
+        });
+    }
+
+    let mut seen = vec![false; len];
+    for (index, &target) in perm.iter().enumerate() {
+        match seen.get_mut(target) {
+            None => return Err(PermutationError::OutOfBounds { index, target }),
+            Some(true) => return Err(PermutationError::DuplicateTarget { index, target }),
+            Some(seen) => *seen = true,
+        }
+    }
+    Ok(seen)
+}
+
+/// A validated permutation of the positions `0..len`.
+///
+/// The element at position `i` is moved to `targets()[i]`, as with [`apply_permutation`].
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Permutation;
+///
+/// let permutation = Permutation::new(vec![2, 0, 1]).unwrap();
+/// let mut vec = vec!['a', 'b', 'c'];
+/// permutation.apply(&mut vec);
+/// assert_eq!(vec, &['b', 'c', 'a']);
+///
+/// assert_eq!(permutation.inverse().targets(), [1, 2, 0]);
+/// permutation.revert(&mut vec);
+/// assert_eq!(vec, &['a', 'b', 'c']);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct Permutation {
+    targets: Vec<usize>,
+}
+
+impl Permutation {
+    /// Creates a permutation from the target position of each element.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`PermutationError`] if `targets` is not a permutation of `0..targets.len()`.
+    pub fn new(targets: Vec<usize>) -> Result<Self, PermutationError> {
+        validate(targets.len(), &targets)?;
+        Ok(Self { targets })
+    }
+
+    /// Creates the identity permutation of `len` positions.
+    pub fn identity(len: usize) -> Self {
+        Self {
+            targets: (0..len).collect(),
+        }
+    }
+
+    /// Creates the permutation that moved the element originally at `origins[i]` to `i`.
+    pub(crate) fn from_origins(origins: &[usize]) -> Self {
+        let mut targets = vec![0; origins.len()];
+        for (position, &origin) in origins.iter().enumerate() {
+            targets[origin] = position;
+        }
+        Self { targets }
+    }
+
+    /// Returns the target position of each element.
+    pub fn targets(&self) -> &[usize] {
+        &self.targets
+    }
+
+    /// Returns the number of positions.
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns `true` if the permutation has no positions.
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Returns `true` if the permutation does not move any element.
+    pub fn is_identity(&self) -> bool {
+        self.targets
+            .iter()
+            .enumerate()
+            .all(|(i, &target)| i == target)
+    }
+
+    /// Returns the permutation undoing this one.
+    pub fn inverse(&self) -> Self {
+        Self::from_origins(&self.targets)
+    }
+
+    /// Permutes a slice, moving the element at `i` to `targets()[i]`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the length of `data` differs from the number of positions.
+    pub fn apply<T>(&self, data: &mut [T]) {
+        if let Err(error) = apply_permutation(data, &self.targets) {
+            panic!("{error}");
+        }
+    }
+
+    /// Undoes the permutation on a slice it was applied to.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the length of `data` differs from the number of positions.
+    pub fn revert<T>(&self, data: &mut [T]) {
+        self.inverse().apply(data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Applies the permutation by moving the elements into a new vector.
+    fn reference(data: &[u32], perm: &[usize]) -> Vec<u32> {
+        let mut result = vec![0; data.len()];
+        for (i, &target) in perm.iter().enumerate() {
+            result[target] = data[i];
+        }
+        result
+    }
+
+    /// Enumerates all permutations of `0..n` using Heap's algorithm.
+    fn permutations(n: usize) -> Vec<Vec<usize>> {
+        // `usize::is_multiple_of` requires Rust 1.87.
+        #[allow(clippy::manual_is_multiple_of)]
+        fn generate(k: usize, perm: &mut Vec<usize>, out: &mut Vec<Vec<usize>>) {
+            if k <= 1 {
+                out.push(perm.clone());
+                return;
+            }
+            for i in 0..k {
+                generate(k - 1, perm, out);
+                let j = if k % 2 == 0 { i } else { 0 };
+                perm.swap(j, k - 1);
+            }
+        }
+        let mut out = Vec::new();
+        generate(n, &mut (0..n).collect(), &mut out);
+        out
+    }
+
+    #[test]
+    fn test_matches_reference_for_all_permutations() {
+        for n in 0..=6 {
+            let data: Vec<u32> = (0..n as u32).map(|v| v * 10).collect();
+            for perm in permutations(n) {
+                let mut actual = data.clone();
+                apply_permutation(&mut actual, &perm).unwrap();
+                assert_eq!(actual, reference(&data, &perm), "with {perm:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_move_counts() {
+        let mut data = [0; 7];
+        assert_eq!(apply_permutation(&mut data, &[0, 1, 2, 3, 4, 5, 6]), Ok(0));
+        assert_eq!(apply_permutation(&mut data, &[1, 0, 2, 3, 4, 5, 6]), Ok(1));
+        assert_eq!(apply_permutation(&mut data, &[1, 2, 0, 3, 4, 5, 6]), Ok(1));
+        assert_eq!(apply_permutation(&mut data, &[1, 2, 3, 0, 5, 6, 4]), Ok(3));
+    }
+
+    #[test]
+    fn test_invalid_permutations() {
+        let mut data = [1, 2, 3];
+        assert_eq!(
+            apply_permutation(&mut data, &[0, 1]),
+            Err(PermutationError::LengthMismatch {
+                len: 3,
+                perm_len: 2
+            })
+        );
+        assert_eq!(
+            apply_permutation(&mut data, &[2, 3, 0]),
+            Err(PermutationError::OutOfBounds {
+                index: 1,
+                target: 3
+            })
+        );
+        assert_eq!(
+            apply_permutation(&mut data, &[1, 2, 1]),
+            Err(PermutationError::DuplicateTarget {
+                index: 2,
+                target: 1
+            })
+        );
+        assert_eq!(data, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            PermutationError::DuplicateTarget {
+                index: 2,
+                target: 1
+            }
+            .to_string(),
+            "the target 1 at index 2 of the permutation was given more than once"
+        );
+    }
+
+    #[test]
+    fn test_permutation() {
+        assert!(Permutation::identity(4).is_identity());
+        assert_eq!(
+            Permutation::new(vec![0, 0]),
+            Err(PermutationError::DuplicateTarget {
+                index: 1,
+                target: 0
+            })
+        );
+
+        let permutation = Permutation::new(vec![3, 0, 4, 1, 2]).unwrap();
+        let inverse = permutation.inverse();
+        assert_eq!(inverse.targets(), [1, 3, 4, 0, 2]);
+        assert_eq!(inverse.inverse(), permutation);
+
+        let mut data = [10, 20, 30, 40, 50];
+        permutation.apply(&mut data);
+        assert_eq!(data, [20, 40, 50, 10, 30]);
+        permutation.revert(&mut data);
+        assert_eq!(data, [10, 20, 30, 40, 50]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 2 but the permutation has 3 entries")]
+    fn test_permutation_length_mismatch() {
+        Permutation::identity(3).apply(&mut [1, 2]);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Moves the elements at three positions to the given target positions, using as few
+/// swaps as possible.
+///
+/// The element at `from[i]` ends up at `to[i]`. Both arrays must hold the same three
+/// positions, so that `to` is a permutation of `from`. Depending on the permutation,
+/// nothing is moved (the identity), a single swap is performed (a transposition), or the
+/// elements are rotated like [`swap3_bca_slice`] or [`swap3_cab_slice`] (a three-cycle),
+/// the latter using two swaps.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to permute.
+/// * `from` - The current positions of the three elements.
+/// * `to` - The target positions of the elements at the respective `from` positions.
+///
+/// ## Returns
+///
+/// The number of swaps performed, i.e. `0`, `1` or `2`.
+///
+/// ## Panics
+///
+/// Panics if any of the positions is out of bounds, if the positions in `from` are not
+/// pairwise distinct, or if `to` is not a permutation of `from`.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec!['a', 'b', 'c', 'd', 'e'];
+///
+/// // Moves 'a' to position 4 and 'e' to position 0, leaving 'c' in place.
+/// assert_eq!(swap3::permute3(&mut vec, [0, 2, 4], [4, 2, 0]), 1);
+/// assert_eq!(vec, &['e', 'b', 'c', 'd', 'a']);
+///
+/// // The rotations are special cases.
+/// assert_eq!(swap3::permute3(&mut vec, [0, 2, 4], [4, 0, 2]), 2);
+/// assert_eq!(vec, &['c', 'b', 'a', 'd', 'e']);
+/// ```
+pub fn permute3<T>(data: &mut [T], from: [usize; 3], to: [usize; 3]) -> usize {
+    let [a, b, c] = from;
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+
+    // `target[i]` is the index into `from` the element at `from[i]` moves to.
+    let target = to.map(|position| {
+        from.iter().position(|&p| p == position).unwrap_or_else(|| {
+            panic!("the target positions {to:?} must be a permutation of {from:?}")
+        })
+    });
+    let [x, y, z] = target;
+    assert!(
+        x != y && y != z && x != z,
+        "the target positions {to:?} must be a permutation of {from:?}"
+    );
+
+    match target {
+        [0, 1, 2] => 0,
+        [0, 2, 1] => {
+            data.swap(b, c);
+            1
+        }
+        [2, 1, 0] => {
+            data.swap(a, c);
+            1
+        }
+        [1, 0, 2] => {
+            data.swap(a, b);
+            1
+        }
+        // Each element moves to the next position: `b` receives `a`, `c` receives `b`.
+        [1, 2, 0] => {
+            swap3_cab_slice(data, a, b, c);
+            2
+        }
+        // Each element moves to the previous position: `a` receives `b`, `b` receives `c`.
+        _ => {
+            swap3_bca_slice(data, a, b, c);
+            2
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERMUTATIONS: [[usize; 3]; 6] = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+
+    #[test]
+    fn test_all_permutations() {
+        let from = [4, 0, 2];
+        for permutation in PERMUTATIONS {
+            let to = permutation.map(|i| from[i]);
+            let mut data = [10, 11, 12, 13, 14];
+            let swaps = permute3(&mut data, from, to);
+            for i in 0..3 {
+                assert_eq!(data[to[i]], 10 + from[i], "{permutation:?}");
+            }
+            assert_eq!(data[1], 11);
+            assert_eq!(data[3], 13);
+
+            let fixed = (0..3).filter(|&i| permutation[i] == i).count();
+            let expected = match fixed {
+                3 => 0,
+                1 => 1,
+                _ => 2,
+            };
+            assert_eq!(swaps, expected, "{permutation:?}");
+        }
+    }
+
+    #[test]
+    fn test_rotations_are_special_cases() {
+        let mut expected = [1, 2, 3, 4, 5];
+        let mut actual = expected;
+        swap3_bca_slice(&mut expected, 0, 2, 4);
+        permute3(&mut actual, [0, 2, 4], [4, 0, 2]);
+        assert_eq!(actual, expected);
+        swap3_cab_slice(&mut expected, 0, 2, 4);
+        permute3(&mut actual, [0, 2, 4], [2, 4, 0]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be a permutation of")]
+    fn test_foreign_target() {
+        permute3(&mut [1, 2, 3, 4], [0, 1, 2], [0, 1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be a permutation of")]
+    fn test_repeated_target() {
+        permute3(&mut [1, 2, 3, 4], [0, 1, 2], [0, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_repeated_source() {
+        permute3(&mut [1, 2, 3, 4], [0, 1, 1], [0, 1, 1]);
+    }
+}
@@ -0,0 +1,150 @@
+//! Adapters for the [`bytemuck`](::bytemuck) crate.
+
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use ::bytemuck::{Pod, PodCastError};
+
+/// Rotates three records of a byte buffer to the left.
+///
+/// The buffer is viewed as a slice of `T` records without copying, and the records
+/// at the given indices are rotated.
+///
+/// ## Arguments
+///
+/// * `data` - The buffer holding the records.
+/// * `a` - The first record index, to be assigned with the record at `b`.
+/// * `b` - The second record index, to be assigned with the record at `c`.
+/// * `c` - The third record index, to be assigned with the record at `a`.
+///
+/// ## Errors
+///
+/// Returns a [`PodCastError`] if the buffer is not suitably aligned for `T` or if its
+/// length is not a multiple of the size of `T`. The buffer is left unchanged in this case.
+///
+/// ## Panics
+///
+/// Panics if any of the record indices is out of bounds or if the indices
+/// are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut records = [1u16, 2, 3, 4];
+/// let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut records);
+/// swap3::swap3_bca_pod::<u16>(bytes, 0, 1, 3).unwrap();
+/// assert_eq!(records, [2, 4, 3, 1]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+pub fn swap3_bca_pod<T: Pod>(
+    data: &mut [u8],
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Result<(), PodCastError> {
+    let records: &mut [T] = ::bytemuck::try_cast_slice_mut(data)?;
+    swap3_bca_slice(records, a, b, c);
+    Ok(())
+}
+
+/// Rotates three records of a byte buffer to the right.
+///
+/// The buffer is viewed as a slice of `T` records without copying, and the records
+/// at the given indices are rotated.
+///
+/// ## Arguments
+///
+/// * `data` - The buffer holding the records.
+/// * `a` - The first record index, to be assigned with the record at `c`.
+/// * `b` - The second record index, to be assigned with the record at `a`.
+/// * `c` - The third record index, to be assigned with the record at `b`.
+///
+/// ## Errors
+///
+/// Returns a [`PodCastError`] if the buffer is not suitably aligned for `T` or if its
+/// length is not a multiple of the size of `T`. The buffer is left unchanged in this case.
+///
+/// ## Panics
+///
+/// Panics if any of the record indices is out of bounds or if the indices
+/// are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut records = [1u16, 2, 3, 4];
+/// let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut records);
+/// swap3::swap3_cab_pod::<u16>(bytes, 0, 1, 3).unwrap();
+/// assert_eq!(records, [4, 1, 3, 2]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+pub fn swap3_cab_pod<T: Pod>(
+    data: &mut [u8],
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Result<(), PodCastError> {
+    let records: &mut [T] = ::bytemuck::try_cast_slice_mut(data)?;
+    swap3_cab_slice(records, a, b, c);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, ::bytemuck::Zeroable, ::bytemuck::Pod)]
+    #[repr(C)]
+    struct Record {
+        id: u32,
+        value: u32,
+    }
+
+    fn records() -> [Record; 3] {
+        [
+            Record { id: 1, value: 10 },
+            Record { id: 2, value: 20 },
+            Record { id: 3, value: 30 },
+        ]
+    }
+
+    #[test]
+    fn test_swap3_pod_records() {
+        let mut data = records();
+        let bytes: &mut [u8] = ::bytemuck::cast_slice_mut(&mut data);
+        assert_eq!(swap3_bca_pod::<Record>(bytes, 0, 1, 2), Ok(()));
+        assert_eq!(data.map(|r| r.id), [2, 3, 1]);
+
+        let bytes: &mut [u8] = ::bytemuck::cast_slice_mut(&mut data);
+        assert_eq!(swap3_cab_pod::<Record>(bytes, 0, 1, 2), Ok(()));
+        assert_eq!(data, records());
+    }
+
+    #[test]
+    fn test_mis_sized_buffer() {
+        let mut data = records();
+        let bytes: &mut [u8] = ::bytemuck::cast_slice_mut(&mut data);
+        let len = bytes.len();
+        assert_eq!(
+            swap3_bca_pod::<Record>(&mut bytes[..len - 1], 0, 1, 2),
+            Err(PodCastError::OutputSliceWouldHaveSlop)
+        );
+        assert_eq!(data, records());
+    }
+
+    #[test]
+    fn test_misaligned_buffer() {
+        let mut data = [0u32; 7];
+        let bytes: &mut [u8] = ::bytemuck::cast_slice_mut(&mut data);
+        assert_eq!(
+            swap3_cab_pod::<u32>(&mut bytes[1..25], 0, 1, 2),
+            Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_record_out_of_bounds() {
+        let mut data = [0u32; 4];
+        let bytes: &mut [u8] = ::bytemuck::cast_slice_mut(&mut data);
+        let _ = swap3_bca_pod::<u32>(bytes, 0, 1, 4);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::Rotation3;
+
+impl Rotation3 {
+    /// The maximum length of the [`postcard`](https://docs.rs/postcard) encoding of a rotation,
+    /// in bytes.
+    ///
+    /// The direction takes one byte and each index a variable-length integer of up to
+    /// `⌈usize::BITS / 7⌉` bytes, i.e. 31 bytes in total on 64-bit and 16 bytes on 32-bit
+    /// targets. A buffer of this size can hold any rotation.
+    #[cfg_attr(docsrs, doc(cfg(feature = "postcard")))]
+    pub const POSTCARD_MAX_SIZE: usize = 1 + 3 * (usize::BITS as usize).div_ceil(7);
+
+    /// Encodes the rotation using [`postcard`](https://docs.rs/postcard) into the front of
+    /// `buf`, returning the encoded bytes.
+    ///
+    /// The direction is encoded as a single byte, `0` for [`Left`](crate::Rotation::Left) and
+    /// `1` for [`Right`](crate::Rotation::Right), followed by the indices `a`, `b` and `c` as
+    /// variable-length integers of seven bits per byte. Rotations of indices below 128 thus
+    /// take four bytes. The encoding does not depend on the target, so rotations can be sent
+    /// between hosts and devices of different pointer widths. This does not allocate and is
+    /// available in `no_std` environments.
+    ///
+    /// ## Arguments
+    ///
+    /// * `buf` - The buffer to encode into; [`POSTCARD_MAX_SIZE`](Self::POSTCARD_MAX_SIZE)
+    ///   bytes always suffice.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`postcard::Error::SerializeBufferFull`] if `buf` is too short.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use swap3::{Rotation, Rotation3};
+    ///
+    /// let mut buf = [0; Rotation3::POSTCARD_MAX_SIZE];
+    /// let bytes = Rotation3::new(Rotation::Right, 0, 1, 300).to_postcard_bytes(&mut buf).unwrap();
+    /// assert_eq!(bytes, &[1, 0, 1, 0xAC, 0x02]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "postcard")))]
+    pub fn to_postcard_bytes<'a>(
+        &self,
+        buf: &'a mut [u8],
+    ) -> Result<&'a mut [u8], postcard::Error> {
+        postcard::to_slice(self, buf)
+    }
+
+    /// Decodes a rotation encoded by [`to_postcard_bytes`](Self::to_postcard_bytes) from the
+    /// front of `bytes`.
+    ///
+    /// Bytes following the encoded rotation are ignored; use [`postcard::take_from_bytes`] to
+    /// decode a stream of consecutive rotations. The indices are not validated, as they are
+    /// only meaningful for a given slice; [`try_apply`](Self::try_apply) rejects invalid ones.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bytes` - The encoded rotation.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `bytes` ends before the rotation is complete, if the direction is
+    /// neither `0` nor `1`, or if an index does not fit into a `usize` of the target.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use swap3::{Rotation, Rotation3};
+    ///
+    /// let rotation = Rotation3::from_postcard_bytes(&[0, 3, 0, 1]).unwrap();
+    /// assert_eq!(rotation, Rotation3::new(Rotation::Left, 3, 0, 1));
+    ///
+    /// let mut vec = vec![10, 20, 30, 40];
+    /// rotation.try_apply(&mut vec).unwrap();
+    /// assert_eq!(vec, &[20, 40, 30, 10]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "postcard")))]
+    pub fn from_postcard_bytes(bytes: &[u8]) -> Result<Self, postcard::Error> {
+        postcard::from_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rotation;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut buf = [0; Rotation3::POSTCARD_MAX_SIZE];
+        for rotation in [
+            Rotation3::new(Rotation::Left, 0, 1, 2),
+            Rotation3::new(Rotation::Right, 127, 128, 16_384),
+            Rotation3::new(Rotation::Left, usize::MAX, 0, usize::MAX - 1),
+        ] {
+            let bytes = rotation.to_postcard_bytes(&mut buf).unwrap();
+            assert_eq!(Rotation3::from_postcard_bytes(bytes), Ok(rotation));
+        }
+    }
+
+    #[test]
+    fn test_max_size() {
+        let mut buf = [0; Rotation3::POSTCARD_MAX_SIZE];
+        let rotation = Rotation3::new(Rotation::Right, usize::MAX, usize::MAX, usize::MAX);
+        let bytes = rotation.to_postcard_bytes(&mut buf).unwrap();
+        assert_eq!(bytes.len(), Rotation3::POSTCARD_MAX_SIZE);
+    }
+
+    #[test]
+    fn test_buffer_too_short() {
+        let mut buf = [0; 3];
+        assert_eq!(
+            Rotation3::new(Rotation::Left, 0, 1, 2).to_postcard_bytes(&mut buf),
+            Err(postcard::Error::SerializeBufferFull)
+        );
+    }
+
+    #[test]
+    fn test_invalid_encodings() {
+        assert_eq!(
+            Rotation3::from_postcard_bytes(&[0, 1, 2]),
+            Err(postcard::Error::DeserializeUnexpectedEnd)
+        );
+        assert!(Rotation3::from_postcard_bytes(&[2, 0, 1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut buf = [0; 2 * Rotation3::POSTCARD_MAX_SIZE];
+        let first = Rotation3::new(Rotation::Left, 4, 0, 200);
+        let second = Rotation3::new(Rotation::Right, 1, 2, 3);
+        let len = first.to_postcard_bytes(&mut buf).unwrap().len();
+        let end = len + second.to_postcard_bytes(&mut buf[len..]).unwrap().len();
+
+        let (decoded, rest) = postcard::take_from_bytes::<Rotation3>(&buf[..end]).unwrap();
+        assert_eq!(decoded, first);
+        assert_eq!(Rotation3::from_postcard_bytes(rest), Ok(second));
+    }
+}
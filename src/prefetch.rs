@@ -0,0 +1,150 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// The assumed size of a cache line in bytes.
+const CACHE_LINE: usize = 64;
+
+/// Rotates three values to the left, issuing software prefetch hints for them first.
+///
+/// On x86 and x86-64 targets with SSE, every cache line spanned by the three elements is
+/// prefetched into all cache levels before the rotation. Elements larger than a cache line,
+/// such as wide SIMD vectors or arrays, are thus fetched in full. On other targets, no hints
+/// are issued and this is equivalent to [`swap3_bca_slice`].
+///
+/// The hints target far-apart elements of slices much larger than the caches. As modern
+/// out-of-order processors already overlap the three loads, the benefit depends on the
+/// processor and should be measured, e.g. using the `prefetch` benchmark.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// No hints are issued in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec: Vec<u64> = (0..1 << 20).collect();
+/// swap3::swap3_bca_slice_prefetch(&mut vec, 0, 1 << 19, (1 << 20) - 1);
+/// assert_eq!([vec[0], vec[1 << 19], vec[(1 << 20) - 1]], [1 << 19, (1 << 20) - 1, 0]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "prefetch")))]
+#[inline(always)]
+pub fn swap3_bca_slice_prefetch<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+    prefetch3(data, [a, b, c]);
+    swap3_bca_slice(data, a, b, c);
+}
+
+/// Rotates three values to the right, issuing software prefetch hints for them first.
+///
+/// On x86 and x86-64 targets with SSE, every cache line spanned by the three elements is
+/// prefetched into all cache levels before the rotation. Elements larger than a cache line,
+/// such as wide SIMD vectors or arrays, are thus fetched in full. On other targets, no hints
+/// are issued and this is equivalent to [`swap3_cab_slice`].
+///
+/// The hints target far-apart elements of slices much larger than the caches. As modern
+/// out-of-order processors already overlap the three loads, the benefit depends on the
+/// processor and should be measured, e.g. using the `prefetch` benchmark.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// No hints are issued in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec: Vec<u64> = (0..1 << 20).collect();
+/// swap3::swap3_cab_slice_prefetch(&mut vec, 0, 1 << 19, (1 << 20) - 1);
+/// assert_eq!([vec[0], vec[1 << 19], vec[(1 << 20) - 1]], [(1 << 20) - 1, 0, 1 << 19]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "prefetch")))]
+#[inline(always)]
+pub fn swap3_cab_slice_prefetch<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+    prefetch3(data, [a, b, c]);
+    swap3_cab_slice(data, a, b, c);
+}
+
+/// Issues prefetch hints for every cache line spanned by the elements at the indices.
+#[inline(always)]
+fn prefetch3<T>(data: &[T], indices: [usize; 3]) {
+    for index in indices {
+        let Some(element) = data.get(index) else {
+            continue;
+        };
+        let element = (element as *const T).cast::<u8>();
+        for offset in (0..core::mem::size_of::<T>()).step_by(CACHE_LINE) {
+            prefetch(element.wrapping_add(offset));
+        }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse"))]
+#[inline(always)]
+fn prefetch(address: *const u8) {
+    use core::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+    // SAFETY: Prefetching is a hint that never faults, regardless of the address.
+    unsafe { _mm_prefetch::<_MM_HINT_T0>(address.cast()) }
+}
+
+#[cfg(all(target_arch = "x86", target_feature = "sse"))]
+#[inline(always)]
+fn prefetch(address: *const u8) {
+    use core::arch::x86::{_mm_prefetch, _MM_HINT_T0};
+    // SAFETY: Prefetching is a hint that never faults, regardless of the address.
+    unsafe { _mm_prefetch::<_MM_HINT_T0>(address.cast()) }
+}
+
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse"
+)))]
+#[inline(always)]
+fn prefetch(_address: *const u8) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_slice_prefetch() {
+        let mut vec = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        swap3_bca_slice_prefetch(&mut vec, 9, 0, 5);
+        assert_eq!(vec, [5, 1, 2, 3, 4, 9, 6, 7, 8, 0]);
+        swap3_cab_slice_prefetch(&mut vec, 9, 0, 5);
+        assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_elements_spanning_cache_lines() {
+        let mut vec = [[1u8; 200], [2; 200], [3; 200]];
+        swap3_bca_slice_prefetch(&mut vec, 0, 1, 2);
+        assert_eq!(vec, [[2; 200], [3; 200], [1; 200]]);
+    }
+
+    #[test]
+    fn test_zero_sized() {
+        let mut vec = [(), (), ()];
+        swap3_cab_slice_prefetch(&mut vec, 2, 1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_out_of_bounds() {
+        swap3_bca_slice_prefetch(&mut [1, 2, 3], 0, 1, 3);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{swap3_bca, swap3_cab};
+
+/// Rotates projected parts of three values to the left.
+///
+/// Only the parts returned by `project` are rotated, the remainder of each value stays in
+/// place. This allows e.g. rotating the payloads of enum variants while keeping their tags.
+///
+/// The projection is applied to `a`, `b` and `c`, in this order, before any value is moved.
+/// If the projection panics, the values are therefore left unchanged.
+///
+/// ## Arguments
+///
+/// * `a` - The first value, whose projection is assigned with the projection of `b`.
+/// * `b` - The second value, whose projection is assigned with the projection of `c`.
+/// * `c` - The third value, whose projection is assigned with the projection of `a`.
+/// * `project` - The function selecting the part to rotate.
+///
+/// ## Example
+///
+/// ```
+/// #[derive(Debug, PartialEq)]
+/// enum Shape {
+///     Circle(u32),
+///     Square(u32),
+/// }
+///
+/// fn size(shape: &mut Shape) -> &mut u32 {
+///     match shape {
+///         Shape::Circle(size) | Shape::Square(size) => size,
+///     }
+/// }
+///
+/// let mut a = Shape::Circle(10);
+/// let mut b = Shape::Square(20);
+/// let mut c = Shape::Circle(30);
+/// swap3::swap3_bca_by(&mut a, &mut b, &mut c, size);
+/// assert_eq!(a, Shape::Circle(20));
+/// assert_eq!(b, Shape::Square(30));
+/// assert_eq!(c, Shape::Circle(10));
+/// ```
+#[inline(always)]
+pub fn swap3_bca_by<T, P, F>(a: &mut T, b: &mut T, c: &mut T, project: F)
+where
+    F: Fn(&mut T) -> &mut P,
+{
+    swap3_bca(project(a), project(b), project(c));
+}
+
+/// Rotates projected parts of three values to the right.
+///
+/// Only the parts returned by `project` are rotated, the remainder of each value stays in
+/// place. This allows e.g. rotating the payloads of enum variants while keeping their tags.
+///
+/// The projection is applied to `a`, `b` and `c`, in this order, before any value is moved.
+/// If the projection panics, the values are therefore left unchanged.
+///
+/// ## Arguments
+///
+/// * `a` - The first value, whose projection is assigned with the projection of `c`.
+/// * `b` - The second value, whose projection is assigned with the projection of `a`.
+/// * `c` - The third value, whose projection is assigned with the projection of `b`.
+/// * `project` - The function selecting the part to rotate.
+///
+/// ## Example
+///
+/// ```
+/// let mut a = (1, "a");
+/// let mut b = (2, "b");
+/// let mut c = (3, "c");
+/// swap3::swap3_cab_by(&mut a, &mut b, &mut c, |v| &mut v.1);
+/// assert_eq!([a, b, c], [(1, "c"), (2, "a"), (3, "b")]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_by<T, P, F>(a: &mut T, b: &mut T, c: &mut T, project: F)
+where
+    F: Fn(&mut T) -> &mut P,
+{
+    swap3_cab(project(a), project(b), project(c));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Slot {
+        Full(String),
+        Empty,
+    }
+
+    fn payload(slot: &mut Slot) -> &mut String {
+        match slot {
+            Slot::Full(payload) => payload,
+            Slot::Empty => panic!("empty slot"),
+        }
+    }
+
+    #[test]
+    fn test_swap3_bca_by() {
+        let mut a = (1, 'a');
+        let mut b = (2, 'b');
+        let mut c = (3, 'c');
+        swap3_bca_by(&mut a, &mut b, &mut c, |v| &mut v.0);
+        assert_eq!([a, b, c], [(2, 'a'), (3, 'b'), (1, 'c')]);
+    }
+
+    #[test]
+    fn test_swap3_cab_by_enum() {
+        let mut a = Slot::Full("a".to_string());
+        let mut b = Slot::Full("b".to_string());
+        let mut c = Slot::Full("c".to_string());
+        swap3_cab_by(&mut a, &mut b, &mut c, payload);
+        assert_eq!(a, Slot::Full("c".to_string()));
+        assert_eq!(b, Slot::Full("a".to_string()));
+        assert_eq!(c, Slot::Full("b".to_string()));
+    }
+
+    #[test]
+    fn test_panicking_projection_leaves_values_unchanged() {
+        let mut a = Slot::Full("a".to_string());
+        let mut b = Slot::Full("b".to_string());
+        let mut c = Slot::Empty;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_bca_by(&mut a, &mut b, &mut c, payload)
+        }));
+        assert!(result.is_err());
+        assert_eq!(a, Slot::Full("a".to_string()));
+        assert_eq!(b, Slot::Full("b".to_string()));
+        assert_eq!(c, Slot::Empty);
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{Rotation, Rotation3};
+use alloc::vec::Vec;
+use proptest::arbitrary::Arbitrary;
+use proptest::bool::BoolValueTree;
+use proptest::collection::SizeRange;
+use proptest::num::usize::BinarySearch;
+use proptest::strategy::{Map, NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+use proptest::tuple::TupleValueTree;
+
+/// Returns a strategy generating rotations of three positions of slices of length `len`.
+///
+/// The generated rotations always have pairwise distinct indices below `len`. They shrink
+/// towards the simplest rotation, which is [`Rotation::Left`] of the positions `0`, `1` and
+/// `2`: first the direction, and then the indices, each towards the smallest index not yet
+/// taken by the previous ones.
+///
+/// ## Arguments
+///
+/// * `len` - The length of the slices the rotations apply to.
+///
+/// ## Panics
+///
+/// Panics if `len` is less than three.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+///
+/// proptest!(|(rotation in swap3::rotation3(10))| {
+///     let mut data: Vec<u32> = (0..10).collect();
+///     rotation.apply(&mut data);
+///     rotation.inverse().apply(&mut data);
+///     prop_assert_eq!(data, (0..10).collect::<Vec<_>>());
+/// });
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub fn rotation3(len: usize) -> Rotation3Strategy {
+    assert!(
+        len >= 3,
+        "rotations require a len of at least 3, but the len is {len}"
+    );
+    Rotation3Strategy { len }
+}
+
+/// Returns a strategy generating sequences of rotations of slices of length `len`.
+///
+/// The sequences shrink towards fewer rotations, and then towards simpler rotations as
+/// described for [`rotation3`]. Unlike the shrinking of [`proptest::collection::vec`], the
+/// removal of rotations is retried after the remaining ones were simplified, since the
+/// simplified rotations frequently no longer contribute to the failure. A failing sequence of
+/// rotations thus is reduced to a minimal reproduction.
+///
+/// ## Arguments
+///
+/// * `len` - The length of the slices the rotations apply to.
+/// * `size` - The range of the number of rotations, e.g. `0..100`.
+///
+/// ## Panics
+///
+/// Panics if `len` is less than three.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+///
+/// proptest!(|(rotations in swap3::rotations3(8, 0..50))| {
+///     let mut data: Vec<u32> = (0..8).collect();
+///     for rotation in &rotations {
+///         rotation.apply(&mut data);
+///     }
+///     for rotation in rotations.iter().rev() {
+///         rotation.inverse().apply(&mut data);
+///     }
+///     prop_assert_eq!(data, (0..8).collect::<Vec<_>>());
+/// });
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub fn rotations3(len: usize, size: impl Into<SizeRange>) -> Rotations3Strategy {
+    Rotations3Strategy {
+        element: rotation3(len),
+        size: size.into(),
+    }
+}
+
+/// The strategy generating rotations of three positions, see [`rotation3`].
+#[derive(Debug, Clone, Copy)]
+#[must_use = "strategies do nothing unless used"]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub struct Rotation3Strategy {
+    len: usize,
+}
+
+impl Strategy for Rotation3Strategy {
+    type Tree = Rotation3ValueTree;
+    type Value = Rotation3;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let len = self.len;
+        let ranks = (proptest::bool::ANY, 0..len, 0..len - 1, 0..len - 2).new_tree(runner)?;
+        Ok(Rotation3ValueTree { ranks })
+    }
+}
+
+/// The value tree of a generated rotation, see [`rotation3`].
+///
+/// Each index is tracked as its rank among the indices not taken by the previous positions,
+/// so that shrinking any of them keeps the indices pairwise distinct.
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub struct Rotation3ValueTree {
+    ranks: TupleValueTree<(BoolValueTree, BinarySearch, BinarySearch, BinarySearch)>,
+}
+
+impl ValueTree for Rotation3ValueTree {
+    type Value = Rotation3;
+
+    fn current(&self) -> Rotation3 {
+        let (right, a, b, c) = self.ranks.current();
+        let rotation = if right {
+            Rotation::Right
+        } else {
+            Rotation::Left
+        };
+
+        // Skip the indices taken by the previous positions, in ascending order.
+        let b = b + usize::from(b >= a);
+        let mut c = c;
+        for taken in [a.min(b), a.max(b)] {
+            c += usize::from(c >= taken);
+        }
+        Rotation3::new(rotation, a, b, c)
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.ranks.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.ranks.complicate()
+    }
+}
+
+/// The strategy generating sequences of rotations, see [`rotations3`].
+#[derive(Debug, Clone)]
+#[must_use = "strategies do nothing unless used"]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub struct Rotations3Strategy {
+    element: Rotation3Strategy,
+    size: SizeRange,
+}
+
+impl Strategy for Rotations3Strategy {
+    type Tree = Rotations3ValueTree;
+    type Value = Vec<Rotation3>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let (min_size, max_size) = self.size.start_end_incl();
+        let len = (min_size..=max_size).new_tree(runner)?.current();
+        let elements = (0..len)
+            .map(|_| self.element.new_tree(runner))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Rotations3ValueTree {
+            included: alloc::vec![true; len],
+            elements,
+            min_size,
+            shrink: Shrink::Remove(0),
+            prev_shrink: None,
+            simplified: false,
+        })
+    }
+}
+
+/// The next shrinking step of a [`Rotations3ValueTree`].
+#[derive(Debug, Clone, Copy)]
+enum Shrink {
+    /// Removes the rotation at the index.
+    Remove(usize),
+    /// Simplifies the rotation at the index.
+    Simplify(usize),
+}
+
+/// The value tree of a generated sequence of rotations, see [`rotations3`].
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub struct Rotations3ValueTree {
+    elements: Vec<Rotation3ValueTree>,
+    included: Vec<bool>,
+    min_size: usize,
+    shrink: Shrink,
+    prev_shrink: Option<Shrink>,
+    /// Whether any rotation was simplified since the last pass of removals.
+    simplified: bool,
+}
+
+impl ValueTree for Rotations3ValueTree {
+    type Value = Vec<Rotation3>;
+
+    fn current(&self) -> Vec<Rotation3> {
+        self.elements
+            .iter()
+            .zip(&self.included)
+            .filter(|(_, &included)| included)
+            .map(|(element, _)| element.current())
+            .collect()
+    }
+
+    fn simplify(&mut self) -> bool {
+        // Alternates between passes removing each rotation and passes simplifying each
+        // rotation, until a pass of simplifications makes no progress.
+        loop {
+            match self.shrink {
+                Shrink::Remove(ix) => {
+                    let len = self.included.iter().filter(|&&included| included).count();
+                    if ix >= self.elements.len() || len == self.min_size {
+                        self.shrink = Shrink::Simplify(0);
+                    } else if !self.included[ix] {
+                        self.shrink = Shrink::Remove(ix + 1);
+                    } else {
+                        self.included[ix] = false;
+                        self.prev_shrink = Some(self.shrink);
+                        self.shrink = Shrink::Remove(ix + 1);
+                        return true;
+                    }
+                }
+                Shrink::Simplify(ix) => {
+                    if ix >= self.elements.len() {
+                        if !core::mem::take(&mut self.simplified) {
+                            return false;
+                        }
+                        self.shrink = Shrink::Remove(0);
+                    } else if self.included[ix] && self.elements[ix].simplify() {
+                        self.prev_shrink = Some(self.shrink);
+                        self.simplified = true;
+                        return true;
+                    } else {
+                        self.shrink = Shrink::Simplify(ix + 1);
+                    }
+                }
+            }
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self.prev_shrink {
+            None => false,
+            Some(Shrink::Remove(ix)) => {
+                // Restores the rotation, which cannot be complicated any further.
+                self.included[ix] = true;
+                self.prev_shrink = None;
+                true
+            }
+            Some(Shrink::Simplify(ix)) => {
+                if self.elements[ix].complicate() {
+                    true
+                } else {
+                    self.prev_shrink = None;
+                    false
+                }
+            }
+        }
+    }
+}
+
+/// The parameters of [`Rotation3`] as an [`Arbitrary`] type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub struct Rotation3Params {
+    /// The length of the slices the rotations apply to; at least three.
+    pub len: usize,
+}
+
+impl Default for Rotation3Params {
+    /// Returns parameters for slices of length 8.
+    fn default() -> Self {
+        Self { len: 8 }
+    }
+}
+
+/// Generates rotations as described for [`rotation3`].
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+/// use swap3::{Rotation3, Rotation3Params};
+///
+/// proptest!(|(rotation in any_with::<Rotation3>(Rotation3Params { len: 5 }))| {
+///     prop_assert!(rotation.try_apply(&mut [0; 5]).is_ok());
+/// });
+/// ```
+impl Arbitrary for Rotation3 {
+    type Parameters = Rotation3Params;
+    type Strategy = Rotation3Strategy;
+
+    fn arbitrary_with(params: Rotation3Params) -> Rotation3Strategy {
+        rotation3(params.len)
+    }
+}
+
+/// Generates both directions, shrinking towards [`Rotation::Left`].
+impl Arbitrary for Rotation {
+    type Parameters = ();
+    type Strategy = Map<proptest::bool::Any, fn(bool) -> Rotation>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        proptest::bool::ANY.prop_map(|right| {
+            if right {
+                Rotation::Right
+            } else {
+                Rotation::Left
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::test_runner::{Config, TestError};
+
+    proptest! {
+        #[test]
+        fn test_rotations_are_valid(rotation in rotation3(3)) {
+            prop_assert!(rotation.try_apply(&mut [0; 3]).is_ok());
+        }
+
+        #[test]
+        fn test_arbitrary_rotations_are_valid(rotation in any::<Rotation3>()) {
+            prop_assert!(rotation.try_apply(&mut [0; 8]).is_ok());
+        }
+    }
+
+    /// Returns the minimal input for which `test` fails.
+    fn minimize<S: Strategy>(strategy: S, test: impl Fn(S::Value) -> bool) -> S::Value {
+        let mut runner = TestRunner::new(Config {
+            failure_persistence: None,
+            ..Config::default()
+        });
+        match runner.run(&strategy, |value| {
+            prop_assert!(test(value));
+            Ok(())
+        }) {
+            Err(TestError::Fail(_, value)) => value,
+            result => panic!("expected a failure, but got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_shrinks_to_simplest_rotation() {
+        let minimal = minimize(rotation3(20), |_| false);
+        assert_eq!(minimal, Rotation3::new(Rotation::Left, 0, 1, 2));
+
+        // Failing for right rotations only keeps the direction but still simplifies the indices.
+        let minimal = minimize(rotation3(20), |r| r.rotation == Rotation::Left);
+        assert_eq!(minimal, Rotation3::new(Rotation::Right, 0, 1, 2));
+    }
+
+    #[test]
+    fn test_shrinks_to_fewest_rotations() {
+        // Fails as soon as any rotation touches the last position.
+        let minimal = minimize(rotations3(10, 0..50), |rotations| {
+            let mut data: Vec<u32> = (0..10).collect();
+            for rotation in &rotations {
+                rotation.apply(&mut data);
+            }
+            data[9] == 9
+        });
+        // Which of the positions `a`, `b` and `c` is the last one depends on the generated input.
+        let [rotation] = minimal[..] else {
+            panic!("expected a single rotation, but got {minimal:?}");
+        };
+        let mut indices = rotation.indices;
+        indices.sort_unstable();
+        assert_eq!((rotation.rotation, indices), (Rotation::Left, [0, 1, 9]));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 3")]
+    fn test_len_too_small() {
+        let _ = rotation3(2);
+    }
+}
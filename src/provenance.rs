@@ -0,0 +1,139 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values to the left, applying the same rotation to a provenance log.
+///
+/// If `provenance` is initialized with the identity, i.e. `provenance[i] == i`, and every
+/// rotation of `data` goes through this function (or [`swap3_cab_slice_provenance`]),
+/// `provenance[i]` always holds the original position of the value currently at `data[i]`.
+/// This helps debugging permutation algorithms built from many rotations.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `provenance` - The provenance log, rotated in lockstep with `data`.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if `data` and `provenance` differ in length, if any of the indices is out of
+/// bounds, or if the indices are not pairwise distinct. All checks are done before either
+/// slice is modified.
+///
+/// ## Example
+///
+/// ```
+/// let mut data = vec!['a', 'b', 'c', 'd'];
+/// let mut provenance: Vec<usize> = (0..data.len()).collect();
+///
+/// swap3::swap3_bca_slice_provenance(&mut data, &mut provenance, 0, 1, 2);
+/// swap3::swap3_bca_slice_provenance(&mut data, &mut provenance, 1, 2, 3);
+/// assert_eq!(data, ['b', 'a', 'd', 'c']);
+/// assert_eq!(provenance, [1, 0, 3, 2]);
+/// ```
+pub fn swap3_bca_slice_provenance<T>(
+    data: &mut [T],
+    provenance: &mut [usize],
+    a: usize,
+    b: usize,
+    c: usize,
+) {
+    check_lengths(data.len(), provenance.len());
+    swap3_bca_slice(data, a, b, c);
+    swap3_bca_slice(provenance, a, b, c);
+}
+
+/// Rotates three values to the right, applying the same rotation to a provenance log.
+///
+/// If `provenance` is initialized with the identity, i.e. `provenance[i] == i`, and every
+/// rotation of `data` goes through this function (or [`swap3_bca_slice_provenance`]),
+/// `provenance[i]` always holds the original position of the value currently at `data[i]`.
+/// This helps debugging permutation algorithms built from many rotations.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `provenance` - The provenance log, rotated in lockstep with `data`.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if `data` and `provenance` differ in length, if any of the indices is out of
+/// bounds, or if the indices are not pairwise distinct. All checks are done before either
+/// slice is modified.
+///
+/// ## Example
+///
+/// ```
+/// let mut data = vec!['a', 'b', 'c', 'd'];
+/// let mut provenance: Vec<usize> = (0..data.len()).collect();
+///
+/// swap3::swap3_cab_slice_provenance(&mut data, &mut provenance, 0, 1, 3);
+/// assert_eq!(data, ['d', 'a', 'c', 'b']);
+/// assert_eq!(provenance, [3, 0, 2, 1]);
+/// ```
+pub fn swap3_cab_slice_provenance<T>(
+    data: &mut [T],
+    provenance: &mut [usize],
+    a: usize,
+    b: usize,
+    c: usize,
+) {
+    check_lengths(data.len(), provenance.len());
+    swap3_cab_slice(data, a, b, c);
+    swap3_cab_slice(provenance, a, b, c);
+}
+
+#[inline(always)]
+fn check_lengths(len: usize, provenance_len: usize) {
+    assert!(
+        len == provenance_len,
+        "the provenance log must match the data: the len is {len} but the log has {provenance_len} entries"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provenance_tracks_original_positions() {
+        let original = [10, 20, 30, 40, 50];
+        let mut data = original;
+        let mut provenance = [0, 1, 2, 3, 4];
+        for (i, (a, b, c)) in [(0, 1, 2), (4, 2, 0), (3, 1, 4), (2, 3, 1)]
+            .into_iter()
+            .enumerate()
+        {
+            if i % 2 == 0 {
+                swap3_bca_slice_provenance(&mut data, &mut provenance, a, b, c);
+            } else {
+                swap3_cab_slice_provenance(&mut data, &mut provenance, a, b, c);
+            }
+            for (value, origin) in data.iter().zip(provenance) {
+                assert_eq!(*value, original[origin]);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 3 but the log has 2 entries")]
+    fn test_length_mismatch() {
+        let mut data = [1, 2, 3];
+        swap3_bca_slice_provenance(&mut data, &mut [0, 1], 0, 1, 2);
+    }
+
+    #[test]
+    fn test_invalid_indices_leave_both_slices_unchanged() {
+        let mut data = [1, 2, 3];
+        let mut provenance = [0, 1, 2];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_cab_slice_provenance(&mut data, &mut provenance, 0, 1, 1);
+        }));
+        assert!(result.is_err());
+        assert_eq!((data, provenance), ([1, 2, 3], [0, 1, 2]));
+    }
+}
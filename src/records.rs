@@ -0,0 +1,120 @@
+/// Rotates three fixed-size records of a byte buffer to the left.
+///
+/// The buffer is treated as a sequence of records of `record_size` bytes each. The records
+/// are rotated in place by swapping byte ranges, without any scratch buffer or element type.
+/// Unlike `swap3_bca_pod`, this imposes no alignment requirements.
+///
+/// ## Arguments
+///
+/// * `data` - The buffer holding the records.
+/// * `record_size` - The size of each record in bytes.
+/// * `a` - The first record index, to be assigned with the record at `b`.
+/// * `b` - The second record index, to be assigned with the record at `c`.
+/// * `c` - The third record index, to be assigned with the record at `a`.
+///
+/// ## Panics
+///
+/// Panics if `record_size` is zero or does not evenly divide the length of the buffer, if
+/// any of the record indices is out of bounds, or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut data = *b"aaabbbcccddd";
+/// swap3::swap3_bca_records(&mut data, 3, 0, 1, 3);
+/// assert_eq!(&data, b"bbbdddcccaaa");
+/// ```
+pub fn swap3_bca_records(data: &mut [u8], record_size: usize, a: usize, b: usize, c: usize) {
+    check_records(data.len(), record_size, a, b, c);
+    swap_records(data, record_size, a, b);
+    swap_records(data, record_size, b, c);
+}
+
+/// Rotates three fixed-size records of a byte buffer to the right.
+///
+/// The buffer is treated as a sequence of records of `record_size` bytes each. The records
+/// are rotated in place by swapping byte ranges, without any scratch buffer or element type.
+/// Unlike `swap3_cab_pod`, this imposes no alignment requirements.
+///
+/// ## Arguments
+///
+/// * `data` - The buffer holding the records.
+/// * `record_size` - The size of each record in bytes.
+/// * `a` - The first record index, to be assigned with the record at `c`.
+/// * `b` - The second record index, to be assigned with the record at `a`.
+/// * `c` - The third record index, to be assigned with the record at `b`.
+///
+/// ## Panics
+///
+/// Panics if `record_size` is zero or does not evenly divide the length of the buffer, if
+/// any of the record indices is out of bounds, or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut data = *b"aaabbbcccddd";
+/// swap3::swap3_cab_records(&mut data, 3, 0, 1, 3);
+/// assert_eq!(&data, b"dddaaacccbbb");
+/// ```
+pub fn swap3_cab_records(data: &mut [u8], record_size: usize, a: usize, b: usize, c: usize) {
+    check_records(data.len(), record_size, a, b, c);
+    swap_records(data, record_size, b, c);
+    swap_records(data, record_size, a, b);
+}
+
+/// Ensures that the buffer consists of whole records and the record indices are valid.
+// `usize::is_multiple_of` requires Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+fn check_records(len: usize, record_size: usize, a: usize, b: usize, c: usize) {
+    assert!(record_size > 0, "the record size must be non-zero");
+    assert!(
+        len % record_size == 0,
+        "the record size must divide the len: the len is {len} but the record size is {record_size}"
+    );
+    let count = len / record_size;
+    crate::disjoint::assert_indices(count, a, b, c);
+}
+
+/// Swaps two distinct, valid records.
+fn swap_records(data: &mut [u8], record_size: usize, i: usize, j: usize) {
+    let (lo, hi) = if i < j { (i, j) } else { (j, i) };
+    let (head, tail) = data.split_at_mut(hi * record_size);
+    head[lo * record_size..][..record_size].swap_with_slice(&mut tail[..record_size]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_slice_rotation() {
+        let mut bytes = *b"0123456789ab";
+        let mut records = [*b"01", *b"23", *b"45", *b"67", *b"89", *b"ab"];
+        for (a, b, c) in [(5, 0, 2), (1, 4, 3), (2, 1, 0)] {
+            swap3_bca_records(&mut bytes, 2, a, b, c);
+            crate::swap3_bca_slice(&mut records, a, b, c);
+            assert_eq!(bytes, *records.as_flattened());
+            swap3_cab_records(&mut bytes, 2, c, a, b);
+            crate::swap3_cab_slice(&mut records, c, a, b);
+            assert_eq!(bytes, *records.as_flattened());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 10 but the record size is 3")]
+    fn test_partial_record() {
+        swap3_bca_records(&mut [0; 10], 3, 0, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the record size must be non-zero")]
+    fn test_zero_record_size() {
+        swap3_cab_records(&mut [0; 10], 0, 0, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_out_of_bounds() {
+        swap3_bca_records(&mut [0; 12], 4, 0, 1, 3);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{swap3_bca, swap3_cab};
+use core::cell::{BorrowMutError, RefCell};
+
+/// Rotates the contents of three [`RefCell`]s to the left.
+///
+/// ## Arguments
+///
+/// * `a` - The first cell, to be assigned with the value of `b`.
+/// * `b` - The second cell, to be assigned with the value of `c`.
+/// * `c` - The third cell, to be assigned with the value of `a`.
+///
+/// ## Panics
+///
+/// Panics if any of the cells is currently borrowed, or if the same cell was passed
+/// more than once. See [`try_swap3_bca_refcells`] for a non-panicking variant.
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+///
+/// let a = RefCell::new(10);
+/// let b = RefCell::new(20);
+/// let c = RefCell::new(30);
+/// swap3::swap3_bca_refcells(&a, &b, &c);
+/// assert_eq!([a.into_inner(), b.into_inner(), c.into_inner()], [20, 30, 10]);
+/// ```
+pub fn swap3_bca_refcells<T>(a: &RefCell<T>, b: &RefCell<T>, c: &RefCell<T>) {
+    assert_distinct(a, b, c);
+    swap3_bca(
+        &mut *a.borrow_mut(),
+        &mut *b.borrow_mut(),
+        &mut *c.borrow_mut(),
+    );
+}
+
+/// Rotates the contents of three [`RefCell`]s to the right.
+///
+/// ## Arguments
+///
+/// * `a` - The first cell, to be assigned with the value of `c`.
+/// * `b` - The second cell, to be assigned with the value of `a`.
+/// * `c` - The third cell, to be assigned with the value of `b`.
+///
+/// ## Panics
+///
+/// Panics if any of the cells is currently borrowed, or if the same cell was passed
+/// more than once. See [`try_swap3_cab_refcells`] for a non-panicking variant.
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+///
+/// let a = RefCell::new(10);
+/// let b = RefCell::new(20);
+/// let c = RefCell::new(30);
+/// swap3::swap3_cab_refcells(&a, &b, &c);
+/// assert_eq!([a.into_inner(), b.into_inner(), c.into_inner()], [30, 10, 20]);
+/// ```
+pub fn swap3_cab_refcells<T>(a: &RefCell<T>, b: &RefCell<T>, c: &RefCell<T>) {
+    assert_distinct(a, b, c);
+    swap3_cab(
+        &mut *a.borrow_mut(),
+        &mut *b.borrow_mut(),
+        &mut *c.borrow_mut(),
+    );
+}
+
+/// Rotates the contents of three [`RefCell`]s to the left, unless any of them is borrowed.
+///
+/// ## Arguments
+///
+/// * `a` - The first cell, to be assigned with the value of `b`.
+/// * `b` - The second cell, to be assigned with the value of `c`.
+/// * `c` - The third cell, to be assigned with the value of `a`.
+///
+/// ## Errors
+///
+/// Returns a [`BorrowMutError`] if any of the cells is currently borrowed. Passing the same
+/// cell more than once also results in an error, as the cell is already borrowed by this
+/// function at the time of the second borrow. The contents are left unchanged in either case.
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+///
+/// let a = RefCell::new(10);
+/// let b = RefCell::new(20);
+/// let c = RefCell::new(30);
+/// assert!(swap3::try_swap3_bca_refcells(&a, &b, &c).is_ok());
+///
+/// let guard = b.borrow();
+/// assert!(swap3::try_swap3_bca_refcells(&a, &b, &c).is_err());
+/// drop(guard);
+///
+/// assert_eq!([a.into_inner(), b.into_inner(), c.into_inner()], [20, 30, 10]);
+/// ```
+pub fn try_swap3_bca_refcells<T>(
+    a: &RefCell<T>,
+    b: &RefCell<T>,
+    c: &RefCell<T>,
+) -> Result<(), BorrowMutError> {
+    let mut a = a.try_borrow_mut()?;
+    let mut b = b.try_borrow_mut()?;
+    let mut c = c.try_borrow_mut()?;
+    swap3_bca(&mut *a, &mut *b, &mut *c);
+    Ok(())
+}
+
+/// Rotates the contents of three [`RefCell`]s to the right, unless any of them is borrowed.
+///
+/// ## Arguments
+///
+/// * `a` - The first cell, to be assigned with the value of `c`.
+/// * `b` - The second cell, to be assigned with the value of `a`.
+/// * `c` - The third cell, to be assigned with the value of `b`.
+///
+/// ## Errors
+///
+/// Returns a [`BorrowMutError`] if any of the cells is currently borrowed. Passing the same
+/// cell more than once also results in an error, as the cell is already borrowed by this
+/// function at the time of the second borrow. The contents are left unchanged in either case.
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+///
+/// let a = RefCell::new(10);
+/// let b = RefCell::new(20);
+/// assert!(swap3::try_swap3_cab_refcells(&a, &b, &a).is_err());
+/// assert_eq!([a.into_inner(), b.into_inner()], [10, 20]);
+/// ```
+pub fn try_swap3_cab_refcells<T>(
+    a: &RefCell<T>,
+    b: &RefCell<T>,
+    c: &RefCell<T>,
+) -> Result<(), BorrowMutError> {
+    let mut a = a.try_borrow_mut()?;
+    let mut b = b.try_borrow_mut()?;
+    let mut c = c.try_borrow_mut()?;
+    swap3_cab(&mut *a, &mut *b, &mut *c);
+    Ok(())
+}
+
+#[inline(always)]
+fn assert_distinct<T>(a: &RefCell<T>, b: &RefCell<T>, c: &RefCell<T>) {
+    assert!(
+        !core::ptr::eq(a, b) && !core::ptr::eq(b, c) && !core::ptr::eq(a, c),
+        "the cells must be pairwise distinct"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_refcells() {
+        let a = RefCell::new(String::from("a"));
+        let b = RefCell::new(String::from("b"));
+        let c = RefCell::new(String::from("c"));
+        swap3_bca_refcells(&a, &b, &c);
+        assert_eq!([&*a.borrow(), &*b.borrow(), &*c.borrow()], ["b", "c", "a"]);
+        swap3_cab_refcells(&a, &b, &c);
+        assert_eq!([&*a.borrow(), &*b.borrow(), &*c.borrow()], ["a", "b", "c"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_aliased_cells() {
+        let a = RefCell::new(1);
+        let b = RefCell::new(2);
+        swap3_bca_refcells(&a, &b, &b);
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn test_borrowed_cell() {
+        let a = RefCell::new(1);
+        let b = RefCell::new(2);
+        let c = RefCell::new(3);
+        let _guard = c.borrow();
+        swap3_cab_refcells(&a, &b, &c);
+    }
+
+    #[test]
+    fn test_try_swap3_refcells() {
+        let a = RefCell::new(1);
+        let b = RefCell::new(2);
+        let c = RefCell::new(3);
+        assert!(try_swap3_bca_refcells(&a, &b, &c).is_ok());
+        assert_eq!([*a.borrow(), *b.borrow(), *c.borrow()], [2, 3, 1]);
+        assert!(try_swap3_cab_refcells(&a, &a, &c).is_err());
+        {
+            let _guard = a.borrow_mut();
+            assert!(try_swap3_cab_refcells(&a, &b, &c).is_err());
+        }
+        assert!(try_swap3_cab_refcells(&a, &b, &c).is_ok());
+        assert_eq!([a.into_inner(), b.into_inner(), c.into_inner()], [1, 2, 3]);
+    }
+}
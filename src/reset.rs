@@ -0,0 +1,173 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use core::mem;
+
+/// Rotates three values to the left, then lets `reset` decide which of the moved elements
+/// to reset to their default.
+///
+/// This is intended for object pools, in which elements moved into a free region have to be
+/// reinitialized before they can be handed out again. After the rotation, `reset` is called
+/// with the index and the new contents of the positions `a`, `b` and `c`, in this order. It may
+/// reinitialize the element in place; if it returns `true`, the element is replaced with
+/// `T::default()` and its previous value is dropped, before `reset` is called for the next
+/// position.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `reset` - The function deciding whether to reset the element moved into a position.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `reset` is not called in this case. If `reset` panics, the rotation remains applied and
+/// the remaining positions are left as they are.
+///
+/// ## Example
+///
+/// ```
+/// // The live connections precede the free ones, which start at index 2.
+/// let mut pool = vec![
+///     String::from("db"),
+///     String::from("cache"),
+///     String::new(),
+///     String::new(),
+/// ];
+///
+/// // Release the connection at index 0 by rotating it into the free region.
+/// swap3::swap3_bca_slice_reset(&mut pool, 0, 1, 2, |index, _| index >= 2);
+/// assert_eq!(pool, ["cache", "", "", ""]);
+/// ```
+pub fn swap3_bca_slice_reset<T, F>(data: &mut [T], a: usize, b: usize, c: usize, reset: F)
+where
+    T: Default,
+    F: FnMut(usize, &mut T) -> bool,
+{
+    swap3_bca_slice(data, a, b, c);
+    reset_moved(data, [a, b, c], reset);
+}
+
+/// Rotates three values to the right, then lets `reset` decide which of the moved elements
+/// to reset to their default.
+///
+/// This is intended for object pools, in which elements moved into a free region have to be
+/// reinitialized before they can be handed out again. After the rotation, `reset` is called
+/// with the index and the new contents of the positions `a`, `b` and `c`, in this order. It may
+/// reinitialize the element in place; if it returns `true`, the element is replaced with
+/// `T::default()` and its previous value is dropped, before `reset` is called for the next
+/// position.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `reset` - The function deciding whether to reset the element moved into a position.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `reset` is not called in this case. If `reset` panics, the rotation remains applied and
+/// the remaining positions are left as they are.
+///
+/// ## Example
+///
+/// ```
+/// let mut buffers = vec![vec![0_u8; 4], vec![1; 2], vec![2; 8]];
+/// swap3::swap3_cab_slice_reset(&mut buffers, 0, 1, 2, |_, buffer| {
+///     // Keep large buffers for reuse, but clear their contents.
+///     buffer.clear();
+///     buffer.capacity() < 4
+/// });
+/// assert!(buffers.iter().all(Vec::is_empty));
+/// assert_eq!(buffers.iter().map(Vec::capacity).collect::<Vec<_>>(), [8, 4, 0]);
+/// ```
+pub fn swap3_cab_slice_reset<T, F>(data: &mut [T], a: usize, b: usize, c: usize, reset: F)
+where
+    T: Default,
+    F: FnMut(usize, &mut T) -> bool,
+{
+    swap3_cab_slice(data, a, b, c);
+    reset_moved(data, [a, b, c], reset);
+}
+
+#[inline(always)]
+fn reset_moved<T, F>(data: &mut [T], indices: [usize; 3], mut reset: F)
+where
+    T: Default,
+    F: FnMut(usize, &mut T) -> bool,
+{
+    for index in indices {
+        let element = &mut data[index];
+        if reset(index, element) {
+            drop(mem::take(element));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Records its id in the shared log when dropped.
+    #[derive(Debug, Default)]
+    struct Pooled<'a> {
+        id: usize,
+        log: Option<&'a RefCell<Vec<String>>>,
+    }
+
+    impl Drop for Pooled<'_> {
+        fn drop(&mut self) {
+            if let Some(log) = self.log {
+                log.borrow_mut().push(format!("drop {}", self.id));
+            }
+        }
+    }
+
+    #[test]
+    fn test_observation_and_drop_order() {
+        let log = RefCell::new(Vec::new());
+        let mut data: Vec<_> = (0..5)
+            .map(|id| Pooled {
+                id,
+                log: Some(&log),
+            })
+            .collect();
+        swap3_bca_slice_reset(&mut data, 4, 0, 2, |index, element| {
+            log.borrow_mut()
+                .push(format!("visit {index}: {}", element.id));
+            index != 0
+        });
+        assert_eq!(
+            log.take(),
+            ["visit 4: 0", "drop 0", "visit 0: 2", "visit 2: 4", "drop 4"]
+        );
+        let ids: Vec<_> = data.iter().map(|p| (p.id, p.log.is_some())).collect();
+        assert_eq!(
+            ids,
+            [(2, true), (1, true), (0, false), (3, true), (0, false)]
+        );
+    }
+
+    #[test]
+    fn test_reinitialize_in_place() {
+        let mut data = [1, 2, 3, 4];
+        swap3_cab_slice_reset(&mut data, 3, 1, 0, |index, value| {
+            *value += 10 * index;
+            false
+        });
+        assert_eq!(data, [2, 14, 3, 31]);
+    }
+
+    #[test]
+    fn test_invalid_indices_skip_reset() {
+        let result = std::panic::catch_unwind(|| {
+            swap3_cab_slice_reset(&mut [1, 2, 3], 0, 1, 3, |_, _| unreachable!());
+        });
+        assert!(result.is_err());
+    }
+}
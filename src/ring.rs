@@ -0,0 +1,319 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// A fixed-capacity ring buffer supporting rotations of three logical positions.
+///
+/// Elements are addressed by their logical index, where `0` is the front of the buffer.
+/// As elements are pushed to the back and popped from the front, the logical positions
+/// wrap around the end of the underlying storage; [`RingBuffer::rotate_left`] and
+/// [`RingBuffer::rotate_right`] take care of resolving them to physical slots.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::RingBuffer;
+///
+/// let mut ring = RingBuffer::<i32, 4>::new();
+/// for x in [1, 2, 3, 4] {
+///     ring.push_back(x).unwrap();
+/// }
+/// assert_eq!(ring.pop_front(), Some(1));
+/// ring.push_back(5).unwrap();
+///
+/// // The logical positions 0, 1 and 3 hold 2, 3 and 5, the latter wrapping around.
+/// ring.rotate_left(0, 1, 3);
+/// assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [3, 5, 4, 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T, const N: usize> {
+    slots: [Option<T>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates an empty ring buffer with a capacity of `N` elements.
+    pub fn new() -> Self {
+        Self {
+            slots: core::array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of elements, `N`.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of elements.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer holds no elements.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the buffer holds `N` elements.
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Appends an element to the back, or returns it if the buffer is full.
+    pub fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        let slot = self.physical(self.len);
+        self.slots[slot] = Some(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the front element, or `None` if the buffer is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = self.slots[self.head].take();
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        value
+    }
+
+    /// Returns a reference to the element at the logical `index`, if any.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            self.slots[self.physical(index)].as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the elements, from front to back.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len).filter_map(move |index| self.get(index))
+    }
+
+    /// Rotates the elements at three logical positions to the left.
+    ///
+    /// ## Arguments
+    ///
+    /// * `a` - The first logical index, to be assigned with the element at `b`.
+    /// * `b` - The second logical index, to be assigned with the element at `c`.
+    /// * `c` - The third logical index, to be assigned with the element at `a`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is not less than [`RingBuffer::len`] or if the indices
+    /// are not pairwise distinct.
+    pub fn rotate_left(&mut self, a: usize, b: usize, c: usize) {
+        let [a, b, c] = self.resolve(a, b, c);
+        swap3_bca_slice(&mut self.slots, a, b, c);
+    }
+
+    /// Rotates the elements at three logical positions to the right.
+    ///
+    /// ## Arguments
+    ///
+    /// * `a` - The first logical index, to be assigned with the element at `c`.
+    /// * `b` - The second logical index, to be assigned with the element at `a`.
+    /// * `c` - The third logical index, to be assigned with the element at `b`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is not less than [`RingBuffer::len`] or if the indices
+    /// are not pairwise distinct.
+    pub fn rotate_right(&mut self, a: usize, b: usize, c: usize) {
+        let [a, b, c] = self.resolve(a, b, c);
+        swap3_cab_slice(&mut self.slots, a, b, c);
+    }
+
+    /// Maps a logical index to its physical slot.
+    #[inline(always)]
+    fn physical(&self, index: usize) -> usize {
+        (self.head + index) % N
+    }
+
+    /// Validates three logical indices against the length and maps them to physical slots.
+    fn resolve(&self, a: usize, b: usize, c: usize) -> [usize; 3] {
+        crate::disjoint::assert_indices(self.len, a, b, c);
+        // Distinct logical indices below `N` map to distinct physical slots.
+        [a, b, c].map(|index| self.physical(index))
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rotates three logical positions of a circular buffer to the left.
+///
+/// The buffer is stored as `[T; N]` with its front at the physical slot `head`, so the
+/// logical index `i` refers to the slot `(head + i) % N`. Unlike [`RingBuffer`], the buffer
+/// is always considered full; tracking the number of valid elements is up to the caller.
+///
+/// ## Arguments
+///
+/// * `buf` - The storage of the circular buffer.
+/// * `head` - The physical slot of the logical index `0`; taken modulo `N`.
+/// * `a` - The first logical index, to be assigned with the element at `b`.
+/// * `b` - The second logical index, to be assigned with the element at `c`.
+/// * `c` - The third logical index, to be assigned with the element at `a`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is not less than `N` or if the indices are not pairwise
+/// distinct.
+///
+/// ## Example
+///
+/// ```
+/// // The logical order is 3, 4, 1, 2.
+/// let mut buf = [1, 2, 3, 4];
+/// swap3::swap3_bca_circular(&mut buf, 2, 0, 1, 3);
+/// assert_eq!(buf, [1, 3, 4, 2]);
+/// ```
+pub fn swap3_bca_circular<T, const N: usize>(
+    buf: &mut [T; N],
+    head: usize,
+    a: usize,
+    b: usize,
+    c: usize,
+) {
+    let [a, b, c] = resolve_circular::<N>(head, a, b, c);
+    swap3_bca_slice(buf, a, b, c);
+}
+
+/// Rotates three logical positions of a circular buffer to the right.
+///
+/// The buffer is stored as `[T; N]` with its front at the physical slot `head`, so the
+/// logical index `i` refers to the slot `(head + i) % N`. Unlike [`RingBuffer`], the buffer
+/// is always considered full; tracking the number of valid elements is up to the caller.
+///
+/// ## Arguments
+///
+/// * `buf` - The storage of the circular buffer.
+/// * `head` - The physical slot of the logical index `0`; taken modulo `N`.
+/// * `a` - The first logical index, to be assigned with the element at `c`.
+/// * `b` - The second logical index, to be assigned with the element at `a`.
+/// * `c` - The third logical index, to be assigned with the element at `b`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is not less than `N` or if the indices are not pairwise
+/// distinct.
+///
+/// ## Example
+///
+/// ```
+/// // The logical order is 3, 4, 1, 2.
+/// let mut buf = [1, 2, 3, 4];
+/// swap3::swap3_cab_circular(&mut buf, 2, 0, 1, 3);
+/// assert_eq!(buf, [1, 4, 2, 3]);
+/// ```
+pub fn swap3_cab_circular<T, const N: usize>(
+    buf: &mut [T; N],
+    head: usize,
+    a: usize,
+    b: usize,
+    c: usize,
+) {
+    let [a, b, c] = resolve_circular::<N>(head, a, b, c);
+    swap3_cab_slice(buf, a, b, c);
+}
+
+/// Validates three logical indices of a full circular buffer and maps them to physical slots.
+fn resolve_circular<const N: usize>(head: usize, a: usize, b: usize, c: usize) -> [usize; 3] {
+    crate::disjoint::assert_indices(N, a, b, c);
+    // `N` is non-zero here. Reducing `head` first keeps the sum from overflowing.
+    let head = head % N;
+    [a, b, c].map(|index| (head + index) % N)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrapped() -> RingBuffer<u8, 5> {
+        let mut ring = RingBuffer::new();
+        for x in 0..5 {
+            ring.push_back(x).unwrap();
+        }
+        for _ in 0..3 {
+            ring.pop_front();
+        }
+        for x in 5..8 {
+            ring.push_back(x).unwrap();
+        }
+        ring
+    }
+
+    fn contents<const N: usize>(ring: &RingBuffer<u8, N>) -> Vec<u8> {
+        ring.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut ring = wrapped();
+        assert!(ring.is_full());
+        assert_eq!(ring.push_back(8), Err(8));
+        assert_eq!(contents(&ring), [3, 4, 5, 6, 7]);
+        assert_eq!(ring.get(4), Some(&7));
+        assert_eq!(ring.get(5), None);
+        assert_eq!(ring.pop_front(), Some(3));
+        assert_eq!(ring.len(), 4);
+    }
+
+    #[test]
+    fn test_rotate_across_wraparound() {
+        let mut ring = wrapped();
+        ring.rotate_left(0, 2, 4);
+        assert_eq!(contents(&ring), [5, 4, 7, 6, 3]);
+        ring.rotate_right(0, 2, 4);
+        assert_eq!(contents(&ring), [3, 4, 5, 6, 7]);
+        ring.rotate_right(4, 1, 3);
+        assert_eq!(contents(&ring), [3, 7, 5, 4, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 2 but the index is 3")]
+    fn test_index_beyond_len() {
+        // Slot 3 exists in the storage, but holds no element.
+        let mut ring = RingBuffer::<u8, 5>::new();
+        ring.push_back(1).unwrap();
+        ring.push_back(2).unwrap();
+        ring.rotate_left(0, 1, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_duplicate_index() {
+        wrapped().rotate_right(1, 1, 2);
+    }
+
+    #[test]
+    fn test_circular() {
+        let mut buf = [3, 4, 0, 1, 2];
+        swap3_bca_circular(&mut buf, 2, 0, 2, 4);
+        assert_eq!(buf, [3, 0, 2, 1, 4]);
+        // Heads beyond `N` wrap around as well.
+        swap3_cab_circular(&mut buf, 7, 0, 2, 4);
+        assert_eq!(buf, [3, 4, 0, 1, 2]);
+        swap3_bca_circular(&mut buf, usize::MAX - 1, 1, 2, 0);
+        assert_eq!(buf, [4, 2, 0, 1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 5 but the index is 5")]
+    fn test_circular_index_beyond_capacity() {
+        swap3_bca_circular(&mut [0; 5], 3, 0, 1, 5);
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    swap3_bca_slice, swap3_cab_slice, try_swap3_bca_slice, try_swap3_cab_slice, Swap3Error,
+};
+
+/// The direction of a rotation of three values.
+///
+/// This allows selecting the direction at runtime, e.g. when the rotations to apply are
+/// read from input data.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Rotation;
+///
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// Rotation::Left.apply(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+///
+/// Rotation::Left.inverse().apply(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[50, 10, 90, 25, 30, 75]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rotation {
+    /// Rotates to the left (`abc` → `bca`), see [`swap3_bca_slice`].
+    Left,
+    /// Rotates to the right (`abc` → `cab`), see [`swap3_cab_slice`].
+    Right,
+}
+
+impl Rotation {
+    /// Returns the rotation undoing this one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use swap3::Rotation;
+    ///
+    /// assert_eq!(Rotation::Left.inverse(), Rotation::Right);
+    /// assert_eq!(Rotation::Right.inverse(), Rotation::Left);
+    /// ```
+    #[inline(always)]
+    pub const fn inverse(self) -> Self {
+        match self {
+            Rotation::Left => Rotation::Right,
+            Rotation::Right => Rotation::Left,
+        }
+    }
+
+    /// Rotates three values of a slice in this direction.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to swap.
+    /// * `a` - The first index.
+    /// * `b` - The second index.
+    /// * `c` - The third index.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+    #[inline(always)]
+    pub fn apply<T>(self, data: &mut [T], a: usize, b: usize, c: usize) {
+        match self {
+            Rotation::Left => swap3_bca_slice(data, a, b, c),
+            Rotation::Right => swap3_cab_slice(data, a, b, c),
+        }
+    }
+
+    /// Rotates three values of a slice in this direction, returning an error if the
+    /// indices are invalid.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to swap.
+    /// * `a` - The first index.
+    /// * `b` - The second index.
+    /// * `c` - The third index.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the same errors as [`try_swap3_bca_slice`]. The slice is left unchanged
+    /// in this case.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use swap3::{Rotation, Swap3Error};
+    ///
+    /// let mut vec = vec![10, 20, 30];
+    /// assert_eq!(Rotation::Right.try_apply(&mut vec, 0, 1, 2), Ok(()));
+    /// assert_eq!(vec, &[30, 10, 20]);
+    /// assert_eq!(
+    ///     Rotation::Right.try_apply(&mut vec, 0, 1, 3),
+    ///     Err(Swap3Error::IndexOutOfBounds { index: 3, len: 3 })
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn try_apply<T>(
+        self,
+        data: &mut [T],
+        a: usize,
+        b: usize,
+        c: usize,
+    ) -> Result<(), Swap3Error> {
+        match self {
+            Rotation::Left => try_swap3_bca_slice(data, a, b, c),
+            Rotation::Right => try_swap3_cab_slice(data, a, b, c),
+        }
+    }
+}
+
+/// A rotation of three positions of a slice in a given direction.
+///
+/// This describes a single call of [`swap3_bca_slice`] or [`swap3_cab_slice`] as a value,
+/// e.g. for recording sequences of rotations or generating them in property tests (see the
+/// `proptest` feature). With the `postcard` feature, rotations can be sent to other devices in
+/// a compact binary encoding, see `Rotation3::to_postcard_bytes`.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::{Rotation, Rotation3};
+///
+/// let rotation = Rotation3::new(Rotation::Left, 0, 1, 4);
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// rotation.apply(&mut vec);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+///
+/// rotation.inverse().apply(&mut vec);
+/// assert_eq!(vec, &[50, 10, 90, 25, 30, 75]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rotation3 {
+    /// The direction of the rotation.
+    pub rotation: Rotation,
+    /// The indices `a`, `b` and `c` of the rotated positions.
+    pub indices: [usize; 3],
+}
+
+impl Rotation3 {
+    /// Describes a rotation of the positions `a`, `b` and `c` in the given direction.
+    #[inline(always)]
+    pub const fn new(rotation: Rotation, a: usize, b: usize, c: usize) -> Self {
+        Self {
+            rotation,
+            indices: [a, b, c],
+        }
+    }
+
+    /// Returns the rotation undoing this one.
+    #[inline(always)]
+    pub const fn inverse(self) -> Self {
+        Self {
+            rotation: self.rotation.inverse(),
+            indices: self.indices,
+        }
+    }
+
+    /// Rotates the described positions of a slice.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+    #[inline(always)]
+    pub fn apply<T>(&self, data: &mut [T]) {
+        let [a, b, c] = self.indices;
+        self.rotation.apply(data, a, b, c);
+    }
+
+    /// Rotates the described positions of a slice, returning an error if the indices are
+    /// invalid.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if any of the indices is out of bounds or if the indices are not
+    /// pairwise distinct. The slice is left unchanged in this case.
+    #[inline(always)]
+    pub fn try_apply<T>(&self, data: &mut [T]) -> Result<(), Swap3Error> {
+        let [a, b, c] = self.indices;
+        self.rotation.try_apply(data, a, b, c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut data = [1, 2, 3, 4, 5];
+        Rotation::Left.apply(&mut data, 0, 2, 4);
+        assert_eq!(data, [3, 2, 5, 4, 1]);
+        Rotation::Right.apply(&mut data, 0, 2, 4);
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_inverse_roundtrip() {
+        for rotation in [Rotation::Left, Rotation::Right] {
+            let mut data = [1, 2, 3, 4];
+            rotation.apply(&mut data, 3, 0, 1);
+            assert_ne!(data, [1, 2, 3, 4]);
+            rotation.inverse().apply(&mut data, 3, 0, 1);
+            assert_eq!(data, [1, 2, 3, 4]);
+            assert_eq!(rotation.inverse().inverse(), rotation);
+        }
+    }
+
+    #[test]
+    fn test_try_apply() {
+        let mut data = [1, 2, 3];
+        assert_eq!(
+            Rotation::Left.try_apply(&mut data, 0, 2, 2),
+            Err(Swap3Error::DuplicateIndex { index: 2 })
+        );
+        assert_eq!(data, [1, 2, 3]);
+        assert_eq!(Rotation::Left.try_apply(&mut data, 0, 1, 2), Ok(()));
+        assert_eq!(data, [2, 3, 1]);
+    }
+
+    #[test]
+    fn test_rotation3() {
+        let mut data = [1, 2, 3, 4, 5];
+        let rotation = Rotation3::new(Rotation::Right, 4, 0, 2);
+        rotation.apply(&mut data);
+        assert_eq!(data, [5, 2, 1, 4, 3]);
+        rotation.inverse().apply(&mut data);
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+        assert_eq!(
+            Rotation3::new(Rotation::Left, 0, 1, 5).try_apply(&mut data),
+            Err(Swap3Error::IndexOutOfBounds { index: 5, len: 5 })
+        );
+    }
+}
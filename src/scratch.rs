@@ -0,0 +1,155 @@
+use core::mem::MaybeUninit;
+use core::ptr;
+
+/// Rotates three values to the left, using caller-provided scratch space as the temporary.
+///
+/// This moves `data[a]` into `scratch`, moves `data[b]` to `a` and `data[c]` to `b`, and
+/// finally moves the scratch value to `c`. No temporary of type `T` is placed on the stack,
+/// which matters for large element types in constrained environments, e.g. when `scratch`
+/// is a `static` buffer.
+///
+/// After returning, `scratch` holds a stale bitwise copy of the value now stored at `c`.
+/// It must be treated as uninitialized: reading it as a `T`, e.g. via
+/// [`MaybeUninit::assume_init_read`], duplicates that value and may result in a double drop.
+/// Any value stored in `scratch` before the call is overwritten without being dropped.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `scratch` - The space holding the value of `data[a]` during the rotation.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::mem::MaybeUninit;
+///
+/// let mut scratch = MaybeUninit::uninit();
+/// let mut vec = vec![[1u8; 4096], [2; 4096], [3; 4096]];
+/// swap3::swap3_bca_slice_scratch(&mut vec, 0, 1, 2, &mut scratch);
+/// assert_eq!([vec[0][0], vec[1][0], vec[2][0]], [2, 3, 1]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "unsafe")))]
+#[inline(always)]
+pub fn swap3_bca_slice_scratch<T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+    scratch: &mut MaybeUninit<T>,
+) {
+    let [pa, pb, pc] = element_ptrs(data, a, b, c);
+    // SAFETY: See `element_ptrs` for the validity of the pointers into `data`; `scratch` is a
+    // distinct, valid allocation of a `T`. Each value is moved exactly once into a location
+    // whose previous value has been moved out before, and nothing in between can panic.
+    unsafe {
+        ptr::copy_nonoverlapping(pa, scratch.as_mut_ptr(), 1);
+        ptr::copy_nonoverlapping(pb, pa, 1);
+        ptr::copy_nonoverlapping(pc, pb, 1);
+        ptr::copy_nonoverlapping(scratch.as_ptr(), pc, 1);
+    }
+}
+
+/// Rotates three values to the right, using caller-provided scratch space as the temporary.
+///
+/// This moves `data[c]` into `scratch`, moves `data[b]` to `c` and `data[a]` to `b`, and
+/// finally moves the scratch value to `a`. No temporary of type `T` is placed on the stack,
+/// which matters for large element types in constrained environments, e.g. when `scratch`
+/// is a `static` buffer.
+///
+/// After returning, `scratch` holds a stale bitwise copy of the value now stored at `a`.
+/// See [`swap3_bca_slice_scratch`] for why it must be treated as uninitialized.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `scratch` - The space holding the value of `data[c]` during the rotation.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::mem::MaybeUninit;
+///
+/// let mut scratch = MaybeUninit::uninit();
+/// let mut vec = vec![String::from("a"), String::from("b"), String::from("c")];
+/// swap3::swap3_cab_slice_scratch(&mut vec, 0, 1, 2, &mut scratch);
+/// assert_eq!(vec, ["c", "a", "b"]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "unsafe")))]
+#[inline(always)]
+pub fn swap3_cab_slice_scratch<T>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+    scratch: &mut MaybeUninit<T>,
+) {
+    let [pa, pb, pc] = element_ptrs(data, a, b, c);
+    // SAFETY: See `swap3_bca_slice_scratch`.
+    unsafe {
+        ptr::copy_nonoverlapping(pc, scratch.as_mut_ptr(), 1);
+        ptr::copy_nonoverlapping(pb, pc, 1);
+        ptr::copy_nonoverlapping(pa, pb, 1);
+        ptr::copy_nonoverlapping(scratch.as_ptr(), pa, 1);
+    }
+}
+
+/// Returns pointers to three elements of `data`, which are valid, aligned and pairwise
+/// non-overlapping, as the indices are checked to be in bounds and pairwise distinct.
+#[inline(always)]
+fn element_ptrs<T>(data: &mut [T], a: usize, b: usize, c: usize) -> [*mut T; 3] {
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+    // All pointers are derived from the same base pointer, so that none of them invalidates
+    // the others.
+    let base = data.as_mut_ptr();
+    // SAFETY: The indices are in bounds of `data`.
+    [a, b, c].map(|index| unsafe { base.add(index) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_swap3_slice_scratch() {
+        let mut scratch = MaybeUninit::uninit();
+        let mut data = [1, 2, 3, 4, 5];
+        swap3_bca_slice_scratch(&mut data, 4, 0, 2, &mut scratch);
+        assert_eq!(data, [3, 2, 5, 4, 1]);
+        swap3_cab_slice_scratch(&mut data, 4, 0, 2, &mut scratch);
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_values_are_moved() {
+        // Neither drops nor duplicates any value; the stale copy in `scratch` is never dropped.
+        let values = [Rc::new(1), Rc::new(2), Rc::new(3)];
+        let mut data = values.clone();
+        let mut scratch = MaybeUninit::uninit();
+        swap3_bca_slice_scratch(&mut data, 0, 1, 2, &mut scratch);
+        swap3_bca_slice_scratch(&mut data, 0, 1, 2, &mut scratch);
+        assert_eq!(data.each_ref().map(|v| **v), [3, 1, 2]);
+        assert!(values.iter().all(|v| Rc::strong_count(v) == 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_overlapping() {
+        let mut scratch = MaybeUninit::uninit();
+        swap3_bca_slice_scratch(&mut [1, 2, 3], 0, 1, 0, &mut scratch);
+    }
+}
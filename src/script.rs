@@ -0,0 +1,180 @@
+use crate::{Rotation, Swap3Error};
+use core::fmt::{Display, Formatter};
+
+/// The error returned by [`apply_script`].
+///
+/// Each variant carries the one-based number of the offending line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScriptError {
+    /// The line does not start with a known command.
+    UnknownCommand {
+        /// The one-based line number.
+        line: usize,
+    },
+    /// The command is not followed by exactly three indices.
+    InvalidArguments {
+        /// The one-based line number.
+        line: usize,
+    },
+    /// The indices are invalid for the slice.
+    Rotation {
+        /// The one-based line number.
+        line: usize,
+        /// The reason the rotation was rejected.
+        error: Swap3Error,
+    },
+}
+
+impl Display for ScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ScriptError::UnknownCommand { line } => write!(f, "line {line}: unknown command"),
+            ScriptError::InvalidArguments { line } => {
+                write!(f, "line {line}: expected exactly three indices")
+            }
+            ScriptError::Rotation { line, error } => write!(f, "line {line}: {error}"),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for ScriptError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ScriptError::Rotation { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(not(has_core_error), feature = "std"))]
+impl std::error::Error for ScriptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScriptError::Rotation { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Applies a textual script of rotation commands to a slice.
+///
+/// Each line of the script holds one command: `L` (rotate left, see
+/// [`swap3_bca_slice`](crate::swap3_bca_slice)) or `R` (rotate right, see
+/// [`swap3_cab_slice`](crate::swap3_cab_slice)), followed by three indices, all separated
+/// by whitespace. Empty lines and lines starting with `#` are ignored.
+///
+/// The commands are applied in order. Parsing and validation happen line by line, so if
+/// an error is returned, the commands preceding the offending line have already been applied.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `script` - The rotation commands.
+///
+/// ## Returns
+///
+/// The number of applied commands.
+///
+/// ## Errors
+///
+/// Returns a [`ScriptError`] identifying the first line that is malformed or whose
+/// indices are invalid for `data`.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// let script = "L 0 1 4\n\n# a comment\nR 2 3 5\n";
+/// assert_eq!(swap3::apply_script(&mut vec, script), Ok(2));
+/// assert_eq!(vec, &[10, 30, 75, 90, 50, 25]);
+/// ```
+pub fn apply_script<T>(data: &mut [T], script: &str) -> Result<usize, ScriptError> {
+    let mut applied = 0;
+    for (number, text) in script.lines().enumerate() {
+        let line = number + 1;
+        let text = text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+
+        let (rotation, [a, b, c]) = parse_command(text, line)?;
+        rotation
+            .try_apply(data, a, b, c)
+            .map_err(|error| ScriptError::Rotation { line, error })?;
+        applied += 1;
+    }
+    Ok(applied)
+}
+
+/// Parses a single non-empty command line.
+fn parse_command(text: &str, line: usize) -> Result<(Rotation, [usize; 3]), ScriptError> {
+    let mut tokens = text.split_whitespace();
+    let rotation = match tokens.next() {
+        Some("L") => Rotation::Left,
+        Some("R") => Rotation::Right,
+        _ => return Err(ScriptError::UnknownCommand { line }),
+    };
+
+    let mut indices = [0; 3];
+    for index in indices.iter_mut() {
+        *index = tokens
+            .next()
+            .and_then(|token| token.parse().ok())
+            .ok_or(ScriptError::InvalidArguments { line })?;
+    }
+    if tokens.next().is_some() {
+        return Err(ScriptError::InvalidArguments { line });
+    }
+    Ok((rotation, indices))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_script() {
+        let mut data = [1, 2, 3, 4, 5];
+        let script = "L 0 2 4\n\n# undo\nR\t0  2 4\r\nL 4 3 2\n";
+        assert_eq!(apply_script(&mut data, script), Ok(3));
+        assert_eq!(data, [1, 2, 5, 3, 4]);
+        assert_eq!(apply_script(&mut data, ""), Ok(0));
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let mut data = [1, 2, 3];
+        assert_eq!(
+            apply_script(&mut data, "L 0 1 2\nX 0 1 2"),
+            Err(ScriptError::UnknownCommand { line: 2 })
+        );
+        assert_eq!(data, [2, 3, 1], "the first command was applied");
+        for script in ["R 0 1", "R 0 1 2 3", "R 0 1 -2", "l 0 1 2 x"] {
+            assert!(apply_script(&mut data, script).is_err(), "{script}");
+        }
+        assert_eq!(
+            apply_script(&mut data, "R 0 1 two"),
+            Err(ScriptError::InvalidArguments { line: 1 })
+        );
+        assert_eq!(data, [2, 3, 1]);
+    }
+
+    #[test]
+    fn test_rotation_errors() {
+        let mut data = [1, 2, 3];
+        let error = apply_script(&mut data, "# comment\nL 0 1 3").unwrap_err();
+        assert_eq!(
+            error,
+            ScriptError::Rotation {
+                line: 2,
+                error: Swap3Error::IndexOutOfBounds { index: 3, len: 3 }
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2: index out of bounds: the len is 3 but the index is 3"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::sync::{fence, AtomicUsize, Ordering};
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values to the left while bumping a seqlock-style sequence counter.
+///
+/// The counter is incremented before the rotation, making it odd to signal a write in
+/// progress, and incremented again after the rotation, making it even. Readers following
+/// the seqlock protocol load the counter (with [`Ordering::Acquire`]), read the data, and load
+/// the counter again; the read is consistent only if both values are equal and even.
+///
+/// The first increment is followed by a [`Release`](Ordering::Release) fence, so that readers
+/// observing any of the rotated values also observe the odd counter. The second increment is a
+/// `Release` store, so that readers observing the final even counter also observe all
+/// rotated values.
+///
+/// Writers must be serialized by the caller, e.g. by holding a lock; concurrent writers
+/// would corrupt the counter protocol.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `seq` - The sequence counter, expected to be even when called.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// The indices are validated before the counter is bumped, so the counter and the data are left
+/// unchanged in this case.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let seq = AtomicUsize::new(0);
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_bca_slice_seqlock(&mut vec, 0, 1, 4, &seq);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+/// assert_eq!(seq.load(Ordering::Acquire), 2);
+/// ```
+pub fn swap3_bca_slice_seqlock<T>(data: &mut [T], a: usize, b: usize, c: usize, seq: &AtomicUsize) {
+    // A panic in the middle of the write would leave the counter odd and wedge every reader.
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+    write(seq, || swap3_bca_slice(data, a, b, c));
+}
+
+/// Rotates three values to the right while bumping a seqlock-style sequence counter.
+///
+/// See [`swap3_bca_slice_seqlock`] for the counter protocol and its ordering guarantees.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `seq` - The sequence counter, expected to be even when called.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// The indices are validated before the counter is bumped, so the counter and the data are left
+/// unchanged in this case.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let seq = AtomicUsize::new(0);
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_cab_slice_seqlock(&mut vec, 0, 1, 4, &seq);
+/// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+/// assert_eq!(seq.load(Ordering::Acquire), 2);
+/// ```
+pub fn swap3_cab_slice_seqlock<T>(data: &mut [T], a: usize, b: usize, c: usize, seq: &AtomicUsize) {
+    crate::disjoint::assert_indices(data.len(), a, b, c);
+    write(seq, || swap3_cab_slice(data, a, b, c));
+}
+
+/// Runs `f` between the odd and the even increment of the counter.
+#[inline(always)]
+fn write<R>(seq: &AtomicUsize, f: impl FnOnce() -> R) -> R {
+    begin_write(seq);
+    let result = f();
+    end_write(seq);
+    result
+}
+
+#[inline(always)]
+fn begin_write(seq: &AtomicUsize) {
+    let previous = seq.fetch_add(1, Ordering::Relaxed);
+    debug_assert!(previous & 1 == 0, "concurrent seqlock writers detected");
+    fence(Ordering::Release);
+}
+
+#[inline(always)]
+fn end_write(seq: &AtomicUsize) {
+    seq.fetch_add(1, Ordering::Release);
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_is_bumped_twice() {
+        let seq = AtomicUsize::new(4);
+        let mut vec = vec![1, 2, 3];
+        swap3_bca_slice_seqlock(&mut vec, 0, 1, 2, &seq);
+        assert_eq!(vec, &[2, 3, 1]);
+        assert_eq!(seq.load(Ordering::Acquire), 6);
+        swap3_cab_slice_seqlock(&mut vec, 0, 1, 2, &seq);
+        assert_eq!(vec, &[1, 2, 3]);
+        assert_eq!(seq.load(Ordering::Acquire), 8);
+    }
+
+    #[test]
+    fn test_counter_unchanged_on_invalid_indices() {
+        let seq = AtomicUsize::new(2);
+        let mut vec = vec![1, 2, 3];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_bca_slice_seqlock(&mut vec, 0, 1, 3, &seq)
+        }));
+        assert!(result.is_err());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_cab_slice_seqlock(&mut vec, 0, 1, 1, &seq)
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec, &[1, 2, 3]);
+        assert_eq!(seq.load(Ordering::Acquire), 2);
+
+        // The counter is still usable afterwards.
+        swap3_bca_slice_seqlock(&mut vec, 0, 1, 2, &seq);
+        assert_eq!(vec, &[2, 3, 1]);
+        assert_eq!(seq.load(Ordering::Acquire), 4);
+    }
+}
+
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use super::*;
+    use loom::sync::Arc;
+    use loom::thread;
+
+    #[test]
+    fn test_readers_observe_consistent_data() {
+        loom::model(|| {
+            let seq = Arc::new(AtomicUsize::new(0));
+            let data = Arc::new([
+                AtomicUsize::new(1),
+                AtomicUsize::new(2),
+                AtomicUsize::new(3),
+            ]);
+
+            // The writer uses the same counter protocol as the public functions, rotating
+            // atomic data so that the reader may access it concurrently.
+            let writer = {
+                let (seq, data) = (Arc::clone(&seq), Arc::clone(&data));
+                thread::spawn(move || {
+                    write(&seq, || {
+                        let [a, b, c] = data.as_ref();
+                        let (x, y, z) = (
+                            a.load(Ordering::Relaxed),
+                            b.load(Ordering::Relaxed),
+                            c.load(Ordering::Relaxed),
+                        );
+                        a.store(y, Ordering::Relaxed);
+                        b.store(z, Ordering::Relaxed);
+                        c.store(x, Ordering::Relaxed);
+                    })
+                })
+            };
+
+            let reader = {
+                let (seq, data) = (Arc::clone(&seq), Arc::clone(&data));
+                thread::spawn(move || {
+                    let first = seq.load(Ordering::Acquire);
+                    let values = [
+                        data[0].load(Ordering::Relaxed),
+                        data[1].load(Ordering::Relaxed),
+                        data[2].load(Ordering::Relaxed),
+                    ];
+                    fence(Ordering::Acquire);
+                    let second = seq.load(Ordering::Relaxed);
+
+                    if first == second && first & 1 == 0 {
+                        match first {
+                            0 => assert_eq!(values, [1, 2, 3]),
+                            2 => assert_eq!(values, [2, 3, 1]),
+                            _ => unreachable!("unexpected counter value {first}"),
+                        }
+                    }
+                })
+            };
+
+            writer.join().unwrap();
+            reader.join().unwrap();
+            assert_eq!(seq.load(Ordering::Acquire), 2);
+        });
+    }
+}
@@ -0,0 +1,231 @@
+//! Deterministic shuffling and chaos testing using random three-cycles.
+
+use crate::{
+    swap3_bca_slice_many, swap3_bca_slice_provenance, swap3_cab_slice_many,
+    swap3_cab_slice_provenance, Permutation,
+};
+use alloc::vec::Vec;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Shuffles a slice by applying a seeded sequence of random left rotations.
+///
+/// Each step picks three distinct indices uniformly at random and rotates them to the left
+/// using [`swap3_bca_slice_many`]. The same `seed`, `steps` and slice length always
+/// produce the same permutation, and the shuffle can be undone using
+/// [`unshuffle_via_rotations`].
+///
+/// ## Statistical properties
+///
+/// Every three-cycle is an even permutation, hence the resulting permutation is always even
+/// as well; only half of all possible arrangements can be reached. With an increasing
+/// number of steps, the distribution approaches the uniform distribution over all even
+/// permutations. This is suitable for reproducible test data, but not as a replacement
+/// for an unbiased shuffle such as [`SliceRandom::shuffle`](rand::seq::SliceRandom::shuffle).
+///
+/// Slices with fewer than three elements are left unchanged.
+///
+/// ## Arguments
+///
+/// * `data` - The slice to shuffle.
+/// * `seed` - The seed of the random number generator.
+/// * `steps` - The number of rotations to apply.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec: Vec<_> = (0..10).collect();
+/// swap3::shuffle_via_rotations(&mut vec, 42, 100);
+/// assert_ne!(vec, (0..10).collect::<Vec<_>>());
+///
+/// swap3::unshuffle_via_rotations(&mut vec, 42, 100);
+/// assert_eq!(vec, (0..10).collect::<Vec<_>>());
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn shuffle_via_rotations<T>(data: &mut [T], seed: u64, steps: usize) {
+    let indexes = random_triples(data.len(), seed, steps);
+    swap3_bca_slice_many(data, &indexes);
+}
+
+/// Reverts a shuffle applied by [`shuffle_via_rotations`].
+///
+/// This replays the same sequence of rotations in reverse order and direction. The `seed`,
+/// `steps` and slice length must match the ones used for shuffling.
+///
+/// ## Arguments
+///
+/// * `data` - The slice to restore.
+/// * `seed` - The seed used for shuffling.
+/// * `steps` - The number of rotations used for shuffling.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec!['a', 'b', 'c', 'd', 'e'];
+/// swap3::shuffle_via_rotations(&mut vec, 7, 3);
+/// swap3::unshuffle_via_rotations(&mut vec, 7, 3);
+/// assert_eq!(vec, &['a', 'b', 'c', 'd', 'e']);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn unshuffle_via_rotations<T>(data: &mut [T], seed: u64, steps: usize) {
+    let mut indexes = random_triples(data.len(), seed, steps);
+    indexes.reverse();
+    swap3_cab_slice_many(data, &indexes);
+}
+
+/// Applies a seeded sequence of random rotations, returning the resulting permutation.
+///
+/// This is intended for fault-injection testing: each step picks three distinct indices
+/// uniformly at random and rotates them in a random direction. The same `seed`, `steps`
+/// and slice length always produce the same rotations. The returned [`Permutation`]
+/// describes where each element ended up, so that the caller can verify invariants
+/// against it or undo the rotations using [`Permutation::revert`].
+///
+/// Slices with fewer than three elements are left unchanged.
+///
+/// ## Arguments
+///
+/// * `data` - The slice to rotate.
+/// * `seed` - The seed of the random number generator.
+/// * `steps` - The number of rotations to apply.
+///
+/// ## Example
+///
+/// ```
+/// let original = vec!['a', 'b', 'c', 'd', 'e'];
+/// let mut vec = original.clone();
+/// let permutation = swap3::chaos_rotate(&mut vec, 42, 25);
+///
+/// // The element originally at index `i` is now at `targets()[i]`.
+/// for (i, &target) in permutation.targets().iter().enumerate() {
+///     assert_eq!(vec[target], original[i]);
+/// }
+///
+/// permutation.revert(&mut vec);
+/// assert_eq!(vec, original);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn chaos_rotate<T>(data: &mut [T], seed: u64, steps: usize) -> Permutation {
+    let len = data.len();
+    // `origins[i]` is the original index of the element currently at `i`.
+    let mut origins: Vec<usize> = (0..len).collect();
+    if len >= 3 {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        for _ in 0..steps {
+            let (a, b, c) = random_triple(&mut rng, len);
+            if rng.gen() {
+                swap3_bca_slice_provenance(data, &mut origins, a, b, c);
+            } else {
+                swap3_cab_slice_provenance(data, &mut origins, a, b, c);
+            }
+        }
+    }
+    Permutation::from_origins(&origins)
+}
+
+/// Generates `steps` triples of pairwise distinct indices below `len`.
+fn random_triples(len: usize, seed: u64, steps: usize) -> Vec<(usize, usize, usize)> {
+    if len < 3 {
+        return Vec::new();
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..steps).map(|_| random_triple(&mut rng, len)).collect()
+}
+
+/// Picks a triple of pairwise distinct indices below `len`, which must be at least 3.
+fn random_triple(rng: &mut impl Rng, len: usize) -> (usize, usize, usize) {
+    let a = rng.gen_range(0..len);
+    let mut b = rng.gen_range(0..len);
+    while b == a {
+        b = rng.gen_range(0..len);
+    }
+    let mut c = rng.gen_range(0..len);
+    while c == a || c == b {
+        c = rng.gen_range(0..len);
+    }
+    (a, b, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shuffle_is_deterministic() {
+        let mut first: Vec<_> = (0..50).collect();
+        let mut second = first.clone();
+        shuffle_via_rotations(&mut first, 1337, 200);
+        shuffle_via_rotations(&mut second, 1337, 200);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_shuffle_is_reversible() {
+        let original: Vec<_> = (0..50).collect();
+        let mut vec = original.clone();
+        shuffle_via_rotations(&mut vec, 1337, 200);
+        assert_ne!(vec, original);
+        unshuffle_via_rotations(&mut vec, 1337, 200);
+        assert_eq!(vec, original);
+    }
+
+    #[test]
+    fn test_shuffle_is_even() {
+        let mut vec: Vec<usize> = (0..20).collect();
+        shuffle_via_rotations(&mut vec, 5, 17);
+
+        // Count the inversions to determine the parity.
+        let mut inversions = 0;
+        for i in 0..vec.len() {
+            for j in (i + 1)..vec.len() {
+                if vec[i] > vec[j] {
+                    inversions += 1;
+                }
+            }
+        }
+        assert_eq!(inversions % 2, 0);
+    }
+
+    #[test]
+    fn test_short_slices_unchanged() {
+        let mut vec = vec![1, 2];
+        shuffle_via_rotations(&mut vec, 5, 10);
+        assert_eq!(vec, &[1, 2]);
+    }
+
+    #[test]
+    fn test_chaos_rotate_is_deterministic_and_reversible() {
+        let original: Vec<u32> = (0..30).collect();
+        let mut first = original.clone();
+        let mut second = original.clone();
+        let permutation = chaos_rotate(&mut first, 99, 500);
+        assert_eq!(chaos_rotate(&mut second, 99, 500), permutation);
+        assert_eq!(first, second);
+        assert!(!permutation.is_identity());
+
+        let mut replayed = original.clone();
+        permutation.apply(&mut replayed);
+        assert_eq!(replayed, first);
+
+        permutation.revert(&mut first);
+        assert_eq!(first, original);
+    }
+
+    #[test]
+    fn test_chaos_rotate_uses_both_directions() {
+        // Left rotations only would reproduce the shuffle.
+        let mut chaos: Vec<u32> = (0..30).collect();
+        let mut shuffled = chaos.clone();
+        chaos_rotate(&mut chaos, 3, 50);
+        shuffle_via_rotations(&mut shuffled, 3, 50);
+        assert_ne!(chaos, shuffled);
+    }
+
+    #[test]
+    fn test_chaos_rotate_short_slices() {
+        let mut vec = vec![1, 2];
+        assert!(chaos_rotate(&mut vec, 5, 10).is_identity());
+        assert_eq!(vec, &[1, 2]);
+    }
+}
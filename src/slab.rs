@@ -0,0 +1,163 @@
+//! Adapters for the [`slab`](::slab) crate.
+
+use crate::{swap3_bca, swap3_cab, MapKeyError};
+use ::slab::Slab;
+
+/// Rotates the values at three occupied keys of a [`Slab`] to the left.
+///
+/// Only the values are rotated; all keys stay occupied and continue to refer to the
+/// same slots.
+///
+/// ## Arguments
+///
+/// * `slab` - The slab whose values to swap.
+/// * `a` - The first key, to be assigned with the value at `b`.
+/// * `b` - The second key, to be assigned with the value at `c`.
+/// * `c` - The third key, to be assigned with the value at `a`.
+///
+/// ## Errors
+///
+/// Returns [`MapKeyError::MissingKey`] if any of the keys is vacant, or
+/// [`MapKeyError::DuplicateKey`] if the keys are not pairwise distinct. The slab is left
+/// unchanged in either case.
+///
+/// ## Example
+///
+/// ```
+/// use slab::Slab;
+///
+/// let mut slab = Slab::new();
+/// let a = slab.insert("a");
+/// let b = slab.insert("b");
+/// let c = slab.insert("c");
+///
+/// swap3::swap3_bca_slab(&mut slab, a, b, c).unwrap();
+/// assert_eq!([slab[a], slab[b], slab[c]], ["b", "c", "a"]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "slab")))]
+pub fn swap3_bca_slab<T>(
+    slab: &mut Slab<T>,
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Result<(), MapKeyError> {
+    let [a, b, c] = get3_mut(slab, [a, b, c])?;
+    swap3_bca(a, b, c);
+    Ok(())
+}
+
+/// Rotates the values at three occupied keys of a [`Slab`] to the right.
+///
+/// Only the values are rotated; all keys stay occupied and continue to refer to the
+/// same slots.
+///
+/// ## Arguments
+///
+/// * `slab` - The slab whose values to swap.
+/// * `a` - The first key, to be assigned with the value at `c`.
+/// * `b` - The second key, to be assigned with the value at `a`.
+/// * `c` - The third key, to be assigned with the value at `b`.
+///
+/// ## Errors
+///
+/// Returns [`MapKeyError::MissingKey`] if any of the keys is vacant, or
+/// [`MapKeyError::DuplicateKey`] if the keys are not pairwise distinct. The slab is left
+/// unchanged in either case.
+///
+/// ## Example
+///
+/// ```
+/// use slab::Slab;
+/// use swap3::MapKeyError;
+///
+/// let mut slab = Slab::new();
+/// let a = slab.insert("a");
+/// let b = slab.insert("b");
+/// let c = slab.insert("c");
+///
+/// swap3::swap3_cab_slab(&mut slab, a, b, c).unwrap();
+/// assert_eq!([slab[a], slab[b], slab[c]], ["c", "a", "b"]);
+///
+/// slab.remove(b);
+/// assert_eq!(
+///     swap3::swap3_cab_slab(&mut slab, a, b, c),
+///     Err(MapKeyError::MissingKey { position: 1 })
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "slab")))]
+pub fn swap3_cab_slab<T>(
+    slab: &mut Slab<T>,
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Result<(), MapKeyError> {
+    let [a, b, c] = get3_mut(slab, [a, b, c])?;
+    swap3_cab(a, b, c);
+    Ok(())
+}
+
+/// Borrows the values at three distinct, occupied keys, reporting the first offending
+/// key argument otherwise.
+fn get3_mut<T>(slab: &mut Slab<T>, keys: [usize; 3]) -> Result<[&mut T; 3], MapKeyError> {
+    for (position, key) in keys.iter().enumerate() {
+        if !slab.contains(*key) {
+            return Err(MapKeyError::MissingKey { position });
+        }
+        if keys[..position].contains(key) {
+            return Err(MapKeyError::DuplicateKey { position });
+        }
+    }
+    Ok(slab
+        .get_disjoint_mut(keys)
+        .expect("the keys were checked to be occupied and distinct"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// Creates a slab with the keys `0..6`, of which `2` is vacant.
+    fn slab() -> Slab<u32> {
+        let mut slab: Slab<u32> = (0..6).map(|k| (k, k as u32 * 10)).collect();
+        slab.remove(2);
+        slab
+    }
+
+    fn entries(slab: &Slab<u32>) -> Vec<(usize, u32)> {
+        slab.iter().map(|(key, value)| (key, *value)).collect()
+    }
+
+    #[test]
+    fn test_swap3_slab() {
+        let mut slab = slab();
+        assert_eq!(swap3_bca_slab(&mut slab, 5, 0, 3), Ok(()));
+        assert_eq!(entries(&slab), [(0, 30), (1, 10), (3, 50), (4, 40), (5, 0)]);
+        assert_eq!(swap3_cab_slab(&mut slab, 5, 0, 3), Ok(()));
+        assert_eq!(entries(&slab), [(0, 0), (1, 10), (3, 30), (4, 40), (5, 50)]);
+    }
+
+    #[test]
+    fn test_vacant_key() {
+        let mut slab = slab();
+        assert_eq!(
+            swap3_bca_slab(&mut slab, 0, 1, 2),
+            Err(MapKeyError::MissingKey { position: 2 })
+        );
+        assert_eq!(
+            swap3_cab_slab(&mut slab, 0, 6, 1),
+            Err(MapKeyError::MissingKey { position: 1 })
+        );
+        assert_eq!(entries(&slab), [(0, 0), (1, 10), (3, 30), (4, 40), (5, 50)]);
+    }
+
+    #[test]
+    fn test_duplicate_key() {
+        let mut slab = slab();
+        assert_eq!(
+            swap3_bca_slab(&mut slab, 3, 4, 3),
+            Err(MapKeyError::DuplicateKey { position: 2 })
+        );
+        assert_eq!(entries(&slab), [(0, 0), (1, 10), (3, 30), (4, 40), (5, 50)]);
+    }
+}
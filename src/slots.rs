@@ -0,0 +1,126 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three slots to the left, then calls `fixup` for each of them.
+///
+/// This is intended for open-addressing hash tables, where moving an entry to another
+/// slot requires updating derived metadata such as its probe distance. After the rotation,
+/// `fixup` is called with the index and the new contents of the slots `a`, `b` and `c`,
+/// in this order.
+///
+/// ## Arguments
+///
+/// * `data` - The slots whose entries to swap.
+/// * `a` - The first slot, to be assigned with the entry of slot `b`.
+/// * `b` - The second slot, to be assigned with the entry of slot `c`.
+/// * `c` - The third slot, to be assigned with the entry of slot `a`.
+/// * `fixup` - The function updating the entry moved into a slot.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `fixup` is not called in this case.
+///
+/// ## Example
+///
+/// ```
+/// #[derive(Debug, PartialEq)]
+/// struct Entry {
+///     home: usize,
+///     distance: usize,
+/// }
+///
+/// let mut slots = vec![
+///     Entry { home: 0, distance: 0 },
+///     Entry { home: 0, distance: 1 },
+///     Entry { home: 1, distance: 1 },
+/// ];
+/// swap3::swap3_bca_slots(&mut slots, 0, 1, 2, |index, entry| {
+///     entry.distance = index - entry.home;
+/// });
+/// assert_eq!(slots.iter().map(|e| e.distance).collect::<Vec<_>>(), [0, 0, 2]);
+/// ```
+pub fn swap3_bca_slots<T, F>(data: &mut [T], a: usize, b: usize, c: usize, fixup: F)
+where
+    F: FnMut(usize, &mut T),
+{
+    swap3_bca_slice(data, a, b, c);
+    fix_up(data, [a, b, c], fixup);
+}
+
+/// Rotates three slots to the right, then calls `fixup` for each of them.
+///
+/// This is intended for open-addressing hash tables, where moving an entry to another
+/// slot requires updating derived metadata such as its probe distance. After the rotation,
+/// `fixup` is called with the index and the new contents of the slots `a`, `b` and `c`,
+/// in this order.
+///
+/// ## Arguments
+///
+/// * `data` - The slots whose entries to swap.
+/// * `a` - The first slot, to be assigned with the entry of slot `c`.
+/// * `b` - The second slot, to be assigned with the entry of slot `a`.
+/// * `c` - The third slot, to be assigned with the entry of slot `b`.
+/// * `fixup` - The function updating the entry moved into a slot.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `fixup` is not called in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut slots = vec![(0, 'a'), (1, 'b'), (2, 'c')];
+/// swap3::swap3_cab_slots(&mut slots, 0, 1, 2, |index, slot| slot.0 = index);
+/// assert_eq!(slots, [(0, 'c'), (1, 'a'), (2, 'b')]);
+/// ```
+pub fn swap3_cab_slots<T, F>(data: &mut [T], a: usize, b: usize, c: usize, fixup: F)
+where
+    F: FnMut(usize, &mut T),
+{
+    swap3_cab_slice(data, a, b, c);
+    fix_up(data, [a, b, c], fixup);
+}
+
+#[inline(always)]
+fn fix_up<T, F>(data: &mut [T], indices: [usize; 3], mut fixup: F)
+where
+    F: FnMut(usize, &mut T),
+{
+    for index in indices {
+        fixup(index, &mut data[index]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixup_order() {
+        let mut data = [10, 20, 30, 40, 50];
+        let mut calls = [(0, 0); 3];
+        let mut n = 0;
+        swap3_bca_slots(&mut data, 4, 0, 2, |index, value| {
+            calls[n] = (index, *value);
+            n += 1;
+        });
+        assert_eq!(calls, [(4, 10), (0, 30), (2, 50)]);
+        assert_eq!(data, [30, 20, 50, 40, 10]);
+    }
+
+    #[test]
+    fn test_fixup_updates_slots() {
+        let mut data = [(0, 'a'), (0, 'b'), (0, 'c'), (0, 'd')];
+        swap3_cab_slots(&mut data, 3, 1, 0, |index, slot| slot.0 = index);
+        assert_eq!(data, [(0, 'b'), (1, 'd'), (0, 'c'), (3, 'a')]);
+    }
+
+    #[test]
+    fn test_invalid_indices_skip_fixup() {
+        let result = std::panic::catch_unwind(|| {
+            swap3_bca_slots(&mut [1, 2, 3], 0, 0, 1, |_, _| unreachable!());
+        });
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three records of a struct-of-arrays layout to the left.
+///
+/// The rotation is applied at the indices `a`, `b` and `c` of every field slice,
+/// keeping the records consistent across all fields.
+///
+/// ## Arguments
+///
+/// * `fields` - The field slices, all of which must have the same length.
+/// * `a` - The first index, to be assigned with the record at `b`.
+/// * `b` - The second index, to be assigned with the record at `c`.
+/// * `c` - The third index, to be assigned with the record at `a`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// Also panics if the field slices differ in length. All checks are done before any field is
+/// modified.
+///
+/// ## Example
+///
+/// ```
+/// let mut x = vec![1, 2, 3, 4];
+/// let mut y = vec![10, 20, 30, 40];
+/// swap3::swap3_bca_soa(&mut [&mut x, &mut y], 0, 1, 3);
+/// assert_eq!(x, &[2, 4, 3, 1]);
+/// assert_eq!(y, &[20, 40, 30, 10]);
+/// ```
+pub fn swap3_bca_soa<T>(fields: &mut [&mut [T]], a: usize, b: usize, c: usize) {
+    check_fields(fields, a, b, c);
+    for field in fields.iter_mut() {
+        swap3_bca_slice(field, a, b, c);
+    }
+}
+
+/// Rotates three records of a struct-of-arrays layout to the right.
+///
+/// The rotation is applied at the indices `a`, `b` and `c` of every field slice,
+/// keeping the records consistent across all fields.
+///
+/// ## Arguments
+///
+/// * `fields` - The field slices, all of which must have the same length.
+/// * `a` - The first index, to be assigned with the record at `c`.
+/// * `b` - The second index, to be assigned with the record at `a`.
+/// * `c` - The third index, to be assigned with the record at `b`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// Also panics if the field slices differ in length. All checks are done before any field is
+/// modified.
+///
+/// ## Example
+///
+/// ```
+/// let mut x = vec![1, 2, 3, 4];
+/// let mut y = vec![10, 20, 30, 40];
+/// swap3::swap3_cab_soa(&mut [&mut x, &mut y], 0, 1, 3);
+/// assert_eq!(x, &[4, 1, 3, 2]);
+/// assert_eq!(y, &[40, 10, 30, 20]);
+/// ```
+pub fn swap3_cab_soa<T>(fields: &mut [&mut [T]], a: usize, b: usize, c: usize) {
+    check_fields(fields, a, b, c);
+    for field in fields.iter_mut() {
+        swap3_cab_slice(field, a, b, c);
+    }
+}
+
+fn check_fields<T>(fields: &[&mut [T]], a: usize, b: usize, c: usize) {
+    let Some(len) = fields.first().map(|field| field.len()) else {
+        return;
+    };
+    assert!(
+        fields.iter().all(|field| field.len() == len),
+        "all field slices must have the same length"
+    );
+    crate::disjoint::assert_indices(len, a, b, c);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_bca_soa() {
+        let mut x = vec![1, 2, 3];
+        let mut y = vec![4, 5, 6];
+        let mut z = vec![7, 8, 9];
+        swap3_bca_soa(&mut [&mut x, &mut y, &mut z], 0, 1, 2);
+        assert_eq!(x, &[2, 3, 1]);
+        assert_eq!(y, &[5, 6, 4]);
+        assert_eq!(z, &[8, 9, 7]);
+    }
+
+    #[test]
+    fn test_swap3_cab_soa() {
+        let mut x = vec![1, 2, 3];
+        let mut y = vec![4, 5, 6];
+        swap3_cab_soa(&mut [&mut x, &mut y], 0, 1, 2);
+        assert_eq!(x, &[3, 1, 2]);
+        assert_eq!(y, &[6, 4, 5]);
+    }
+
+    #[test]
+    fn test_no_fields() {
+        let mut fields: [&mut [u8]; 0] = [];
+        swap3_bca_soa(&mut fields, 0, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_mismatching_lengths() {
+        let mut x = vec![1, 2, 3];
+        let mut y = vec![4, 5];
+        swap3_bca_soa(&mut [&mut x, &mut y], 0, 1, 2);
+    }
+
+    #[test]
+    fn test_out_of_bounds_leaves_fields_unchanged() {
+        let mut x = vec![1, 2, 3];
+        let mut y = vec![4, 5, 6];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_bca_soa(&mut [&mut x, &mut y], 0, 1, 3)
+        }));
+        assert!(result.is_err());
+        assert_eq!(x, &[1, 2, 3]);
+        assert_eq!(y, &[4, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_coinciding_indices() {
+        let mut x = vec![1, 2, 3];
+        let mut y = vec![4, 5, 6];
+        swap3_cab_soa(&mut [&mut x, &mut y], 0, 2, 0);
+    }
+}
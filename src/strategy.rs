@@ -0,0 +1,209 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Selects the implementation strategy of a rotation, see [`swap3_bca_slice_via`].
+///
+/// Every strategy panics if any of the indices is out of bounds or if the indices are not
+/// pairwise distinct, so that switching strategies never changes which calls succeed.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SwapStrategy: sealed::Sealed {
+    /// Rotates three values to the left.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to swap.
+    /// * `a` - The first index, to be assigned with the value of `data[b]`.
+    /// * `b` - The second index, to be assigned with the value of `data[c]`.
+    /// * `c` - The third index, to be assigned with the value of `data[a]`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+    fn bca<T>(data: &mut [T], a: usize, b: usize, c: usize);
+
+    /// Rotates three values to the right.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice whose elements to swap.
+    /// * `a` - The first index, to be assigned with the value of `data[c]`.
+    /// * `b` - The second index, to be assigned with the value of `data[a]`.
+    /// * `c` - The third index, to be assigned with the value of `data[b]`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+    fn cab<T>(data: &mut [T], a: usize, b: usize, c: usize);
+}
+
+/// Rotates using two pairwise swaps, as done by [`swap3_bca_slice`] and [`swap3_cab_slice`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DoubleSwap;
+
+/// Rotates using a single temporary and three moves, as done by
+/// [`crate::slice::bca_single_temp`] and [`crate::slice::cab_single_temp`].
+#[cfg_attr(docsrs, doc(cfg(feature = "unsafe")))]
+#[cfg(feature = "unsafe")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SingleTemp;
+
+impl sealed::Sealed for DoubleSwap {}
+
+impl SwapStrategy for DoubleSwap {
+    #[inline(always)]
+    fn bca<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+        swap3_bca_slice(data, a, b, c)
+    }
+
+    #[inline(always)]
+    fn cab<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+        swap3_cab_slice(data, a, b, c)
+    }
+}
+
+#[cfg(feature = "unsafe")]
+impl sealed::Sealed for SingleTemp {}
+
+#[cfg(feature = "unsafe")]
+impl SwapStrategy for SingleTemp {
+    #[inline(always)]
+    fn bca<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+        crate::disjoint::assert_distinct(a, b, c);
+        crate::slice::bca_single_temp(data, a, b, c)
+    }
+
+    #[inline(always)]
+    fn cab<T>(data: &mut [T], a: usize, b: usize, c: usize) {
+        crate::disjoint::assert_distinct(a, b, c);
+        crate::slice::cab_single_temp(data, a, b, c)
+    }
+}
+
+/// Rotates three values to the left using the selected [`SwapStrategy`].
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::DoubleSwap;
+///
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_bca_slice_via::<DoubleSwap, _>(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_slice_via<S: SwapStrategy, T>(data: &mut [T], a: usize, b: usize, c: usize) {
+    S::bca(data, a, b, c)
+}
+
+/// Rotates three values to the right using the selected [`SwapStrategy`].
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::DoubleSwap;
+///
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_cab_slice_via::<DoubleSwap, _>(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_slice_via<S: SwapStrategy, T>(data: &mut [T], a: usize, b: usize, c: usize) {
+    S::cab(data, a, b, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// All index triples for a slice of length 4, including coinciding indices.
+    #[cfg(feature = "unsafe")]
+    fn triples() -> impl Iterator<Item = (usize, usize, usize)> {
+        (0..4).flat_map(|a| (0..4).flat_map(move |b| (0..4).map(move |c| (a, b, c))))
+    }
+
+    #[test]
+    fn test_double_swap() {
+        let mut vec = vec![1, 2, 3, 4];
+        swap3_bca_slice_via::<DoubleSwap, _>(&mut vec, 0, 1, 3);
+        assert_eq!(vec, &[2, 4, 3, 1]);
+        swap3_cab_slice_via::<DoubleSwap, _>(&mut vec, 0, 1, 3);
+        assert_eq!(vec, &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_single_temp() {
+        let mut vec = vec![1, 2, 3, 4];
+        swap3_bca_slice_via::<SingleTemp, _>(&mut vec, 0, 1, 3);
+        assert_eq!(vec, &[2, 4, 3, 1]);
+        swap3_cab_slice_via::<SingleTemp, _>(&mut vec, 0, 1, 3);
+        assert_eq!(vec, &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_strategies_agree_on_distinct_indices() {
+        for (a, b, c) in triples().filter(|&(a, b, c)| a != b && b != c && a != c) {
+            let mut double = vec![1, 2, 3, 4];
+            let mut single = double.clone();
+            swap3_bca_slice_via::<DoubleSwap, _>(&mut double, a, b, c);
+            swap3_bca_slice_via::<SingleTemp, _>(&mut single, a, b, c);
+            assert_eq!(double, single, "bca with {a}, {b}, {c}");
+
+            swap3_cab_slice_via::<DoubleSwap, _>(&mut double, a, b, c);
+            swap3_cab_slice_via::<SingleTemp, _>(&mut single, a, b, c);
+            assert_eq!(double, single, "cab with {a}, {b}, {c}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_strategies_panic_on_coinciding_indices() {
+        fn check(rotate: fn(&mut [String], usize, usize, usize), a: usize, b: usize, c: usize) {
+            let mut vec = vec![
+                String::from("1"),
+                String::from("2"),
+                String::from("3"),
+                String::from("4"),
+            ];
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                rotate(&mut vec, a, b, c)
+            }));
+            assert!(result.is_err(), "with {a}, {b}, {c}");
+            assert_eq!(vec, &["1", "2", "3", "4"], "with {a}, {b}, {c}");
+        }
+
+        for (a, b, c) in triples().filter(|&(a, b, c)| a == b || b == c || a == c) {
+            check(swap3_bca_slice_via::<DoubleSwap, _>, a, b, c);
+            check(swap3_cab_slice_via::<DoubleSwap, _>, a, b, c);
+            check(swap3_bca_slice_via::<SingleTemp, _>, a, b, c);
+            check(swap3_cab_slice_via::<SingleTemp, _>, a, b, c);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three elements of a strided view to the left.
+///
+/// The element `k` of the view is located at `data[base + k * stride]`, e.g. the red channel
+/// of interleaved RGB pixels for `base = 0` and `stride = 3`.
+///
+/// ## Arguments
+///
+/// * `data` - The slice holding the interleaved data.
+/// * `base` - The index of the first element of the view.
+/// * `stride` - The distance between consecutive elements of the view.
+/// * `a` - The first element of the view, to be assigned with the value of element `b`.
+/// * `b` - The second element of the view, to be assigned with the value of element `c`.
+/// * `c` - The third element of the view, to be assigned with the value of element `a`.
+///
+/// ## Panics
+///
+/// Panics if computing any of the indices overflows, if any of the indices is out of
+/// bounds, or if the indices are not pairwise distinct (which includes a `stride` of zero).
+///
+/// ## Example
+///
+/// ```
+/// // Three RGB pixels; rotate the green channel.
+/// let mut pixels = vec![
+///     10, 11, 12,
+///     20, 21, 22,
+///     30, 31, 32,
+/// ];
+/// swap3::swap3_bca_strided(&mut pixels, 1, 3, 0, 1, 2);
+/// assert_eq!(pixels, &[
+///     10, 21, 12,
+///     20, 31, 22,
+///     30, 11, 32,
+/// ]);
+/// ```
+pub fn swap3_bca_strided<T>(
+    data: &mut [T],
+    base: usize,
+    stride: usize,
+    a: usize,
+    b: usize,
+    c: usize,
+) {
+    let [a, b, c] = [a, b, c].map(|k| strided_index(base, stride, k));
+    swap3_bca_slice(data, a, b, c);
+}
+
+/// Rotates three elements of a strided view to the right.
+///
+/// The element `k` of the view is located at `data[base + k * stride]`, e.g. the red channel
+/// of interleaved RGB pixels for `base = 0` and `stride = 3`.
+///
+/// ## Arguments
+///
+/// * `data` - The slice holding the interleaved data.
+/// * `base` - The index of the first element of the view.
+/// * `stride` - The distance between consecutive elements of the view.
+/// * `a` - The first element of the view, to be assigned with the value of element `c`.
+/// * `b` - The second element of the view, to be assigned with the value of element `a`.
+/// * `c` - The third element of the view, to be assigned with the value of element `b`.
+///
+/// ## Panics
+///
+/// Panics if computing any of the indices overflows, if any of the indices is out of
+/// bounds, or if the indices are not pairwise distinct (which includes a `stride` of zero).
+///
+/// ## Example
+///
+/// ```
+/// // Stereo audio with interleaved samples; rotate three samples of the right channel.
+/// let mut samples = vec![0, 100, 1, 101, 2, 102, 3, 103];
+/// swap3::swap3_cab_strided(&mut samples, 1, 2, 0, 1, 3);
+/// assert_eq!(samples, &[0, 103, 1, 100, 2, 102, 3, 101]);
+/// ```
+pub fn swap3_cab_strided<T>(
+    data: &mut [T],
+    base: usize,
+    stride: usize,
+    a: usize,
+    b: usize,
+    c: usize,
+) {
+    let [a, b, c] = [a, b, c].map(|k| strided_index(base, stride, k));
+    swap3_cab_slice(data, a, b, c);
+}
+
+/// Computes `base + k * stride`, panicking on overflow.
+#[inline(always)]
+fn strided_index(base: usize, stride: usize, k: usize) -> usize {
+    k.checked_mul(stride)
+        .and_then(|offset| base.checked_add(offset))
+        .unwrap_or_else(|| panic!("index overflow: {base} + {k} * {stride} exceeds usize::MAX"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_strided() {
+        let mut data: Vec<u32> = (0..12).collect();
+        swap3_bca_strided(&mut data, 2, 4, 0, 1, 2);
+        assert_eq!(data, [0, 1, 6, 3, 4, 5, 10, 7, 8, 9, 2, 11]);
+        swap3_cab_strided(&mut data, 2, 4, 0, 1, 2);
+        assert_eq!(data, (0..12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 12 but the index is 14")]
+    fn test_out_of_bounds() {
+        let mut data = [0; 12];
+        swap3_bca_strided(&mut data, 2, 4, 0, 3, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "index overflow")]
+    fn test_overflow() {
+        let mut data = [0; 12];
+        swap3_cab_strided(&mut data, 1, usize::MAX / 2 + 1, 0, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_zero_stride() {
+        let mut data = [0; 12];
+        swap3_bca_strided(&mut data, 1, 0, 0, 1, 2);
+    }
+}
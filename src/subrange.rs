@@ -0,0 +1,109 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use core::ops::Range;
+
+/// Rotates three values within a sub-range of a slice to the left.
+///
+/// The indices are relative to `range.start` and are validated against the length of the
+/// range, i.e. this behaves like [`swap3_bca_slice`] on `&mut data[range]`.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `range` - The sub-range of `data` the indices refer to.
+/// * `a` - The first index, to be assigned with the value of `data[range.start + b]`.
+/// * `b` - The second index, to be assigned with the value of `data[range.start + c]`.
+/// * `c` - The third index, to be assigned with the value of `data[range.start + a]`.
+///
+/// ## Panics
+///
+/// Panics if the range is decreasing or out of bounds of `data`, if any of the indices is
+/// out of bounds of the range, or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20, 30, 40, 50, 60];
+/// swap3::swap3_bca_subrange(&mut vec, 2..5, 0, 1, 2);
+/// assert_eq!(vec, &[10, 20, 40, 50, 30, 60]);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_subrange<T>(data: &mut [T], range: Range<usize>, a: usize, b: usize, c: usize) {
+    swap3_bca_slice(subrange(data, range), a, b, c);
+}
+
+/// Rotates three values within a sub-range of a slice to the right.
+///
+/// The indices are relative to `range.start` and are validated against the length of the
+/// range, i.e. this behaves like [`swap3_cab_slice`] on `&mut data[range]`.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `range` - The sub-range of `data` the indices refer to.
+/// * `a` - The first index, to be assigned with the value of `data[range.start + c]`.
+/// * `b` - The second index, to be assigned with the value of `data[range.start + a]`.
+/// * `c` - The third index, to be assigned with the value of `data[range.start + b]`.
+///
+/// ## Panics
+///
+/// Panics if the range is decreasing or out of bounds of `data`, if any of the indices is
+/// out of bounds of the range, or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20, 30, 40, 50, 60];
+/// swap3::swap3_cab_subrange(&mut vec, 2..5, 0, 1, 2);
+/// assert_eq!(vec, &[10, 20, 50, 30, 40, 60]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_subrange<T>(data: &mut [T], range: Range<usize>, a: usize, b: usize, c: usize) {
+    swap3_cab_slice(subrange(data, range), a, b, c);
+}
+
+#[inline(always)]
+fn subrange<T>(data: &mut [T], range: Range<usize>) -> &mut [T] {
+    let len = data.len();
+    let Range { start, end } = range;
+    assert!(
+        start <= end && end <= len,
+        "range out of bounds: the len is {len} but the range is {start}..{end}"
+    );
+    &mut data[start..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_subrange() {
+        let mut data = [0, 1, 2, 3, 4, 5, 6, 7];
+        swap3_bca_subrange(&mut data, 3..8, 4, 0, 2);
+        assert_eq!(data, [0, 1, 2, 5, 4, 7, 6, 3]);
+        swap3_cab_subrange(&mut data, 3..8, 4, 0, 2);
+        assert_eq!(data, [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_index_out_of_range() {
+        let mut data = [0; 8];
+        swap3_bca_subrange(&mut data, 2..5, 0, 1, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "range out of bounds: the len is 8 but the range is 6..9")]
+    fn test_range_out_of_bounds() {
+        let mut data = [0; 8];
+        swap3_cab_subrange(&mut data, 6..9, 0, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "range out of bounds: the len is 8 but the range is 5..2")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_decreasing_range() {
+        let mut data = [0; 8];
+        swap3_bca_subrange(&mut data, 5..2, 0, 1, 2);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values to the left, addressing the second one from the back of the slice.
+///
+/// The index `back_j` is resolved to `data.len() - 1 - back_j`, i.e. `0` refers to the
+/// last element. This avoids repetitive index arithmetic in two-pointer algorithms.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `front_i` - The first index, counted from the front, to be assigned with the value at `back_j`.
+/// * `back_j` - The second index, counted from the back, to be assigned with the value at `front_k`.
+/// * `front_k` - The third index, counted from the front, to be assigned with the value at `front_i`.
+///
+/// ## Panics
+///
+/// Panics if any of the resolved indices is out of bounds or if the resolved indices
+/// are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![1, 2, 3, 4, 5, 6];
+/// swap3::swap3_bca_symmetric(&mut vec, 0, 0, 2);
+/// assert_eq!(vec, &[6, 2, 1, 4, 5, 3]);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_symmetric<T>(data: &mut [T], front_i: usize, back_j: usize, front_k: usize) {
+    let j = from_back(data.len(), back_j);
+    swap3_bca_slice(data, front_i, j, front_k);
+}
+
+/// Rotates three values to the right, addressing the second one from the back of the slice.
+///
+/// The index `back_j` is resolved to `data.len() - 1 - back_j`, i.e. `0` refers to the
+/// last element. This avoids repetitive index arithmetic in two-pointer algorithms.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `front_i` - The first index, counted from the front, to be assigned with the value at `front_k`.
+/// * `back_j` - The second index, counted from the back, to be assigned with the value at `front_i`.
+/// * `front_k` - The third index, counted from the front, to be assigned with the value at `back_j`.
+///
+/// ## Panics
+///
+/// Panics if any of the resolved indices is out of bounds or if the resolved indices
+/// are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![1, 2, 3, 4, 5, 6];
+/// swap3::swap3_cab_symmetric(&mut vec, 0, 0, 2);
+/// assert_eq!(vec, &[3, 2, 6, 4, 5, 1]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_symmetric<T>(data: &mut [T], front_i: usize, back_j: usize, front_k: usize) {
+    let j = from_back(data.len(), back_j);
+    swap3_cab_slice(data, front_i, j, front_k);
+}
+
+/// Resolves an index counted from the back of a slice of length `len`.
+#[inline(always)]
+pub(crate) fn from_back(len: usize, back: usize) -> usize {
+    match len.checked_sub(back) {
+        Some(remaining) if remaining > 0 => remaining - 1,
+        _ => panic!("index out of bounds: the len is {len} but the index from the back is {back}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_bca_symmetric() {
+        let mut vec = vec![1, 2, 3, 4, 5];
+        swap3_bca_symmetric(&mut vec, 1, 1, 0);
+        assert_eq!(vec, &[2, 4, 3, 1, 5]);
+    }
+
+    #[test]
+    fn test_swap3_cab_symmetric() {
+        let mut vec = vec![1, 2, 3, 4, 5];
+        swap3_cab_symmetric(&mut vec, 1, 1, 0);
+        assert_eq!(vec, &[4, 1, 3, 2, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 5 but the index from the back is 5")]
+    fn test_back_index_out_of_bounds() {
+        let mut vec = vec![1, 2, 3, 4, 5];
+        swap3_bca_symmetric(&mut vec, 0, 5, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_empty_slice() {
+        let mut vec: Vec<u8> = Vec::new();
+        swap3_bca_symmetric(&mut vec, 0, 0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_front_and_back_coincide() {
+        let mut vec = vec![1, 2, 3, 4, 5];
+        swap3_cab_symmetric(&mut vec, 4, 0, 1);
+    }
+}
@@ -0,0 +1,19 @@
+//! The synchronization primitives used by the concurrent rotations.
+//!
+//! When testing with `--cfg loom`, these are replaced by the model-checked primitives of
+//! [`loom`](https://docs.rs/loom), which explores all interleavings of the tests in the
+//! `loom_tests` modules. Run these using
+//!
+//! ```text
+//! RUSTFLAGS="--cfg loom" cargo test --release --lib loom_tests
+//! ```
+
+#[cfg(not(loom))]
+pub(crate) use core::sync::atomic::{fence, AtomicUsize, Ordering};
+#[cfg(loom)]
+pub(crate) use loom::sync::atomic::{fence, AtomicUsize, Ordering};
+
+#[cfg(all(feature = "std", loom))]
+pub(crate) use loom::sync::{Mutex, MutexGuard};
+#[cfg(all(feature = "std", not(loom)))]
+pub(crate) use std::sync::{Mutex, MutexGuard};
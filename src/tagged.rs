@@ -0,0 +1,293 @@
+use crate::{try_swap3_bca_slice, try_swap3_cab_slice, Swap3Error};
+use core::fmt::{Display, Formatter};
+
+/// A lookup table mapping tags to the offsets of elements in a buffer.
+///
+/// This allows addressing the elements of a structured buffer symbolically, e.g. by an enum
+/// naming the regions of the buffer, rather than by raw indices. The table borrows a slice of
+/// `(tag, offset)` pairs, so it can be a `const` or `static` in `no_std` environments. Lookups
+/// scan the entries in order; if a tag occurs more than once, its first entry is used.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::TagTable;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Region {
+///     Header,
+///     Payload,
+///     Footer,
+/// }
+///
+/// const LAYOUT: TagTable<'static, Region> =
+///     TagTable::new(&[(Region::Header, 0), (Region::Payload, 3), (Region::Footer, 7)]);
+///
+/// assert_eq!(LAYOUT.offset(&Region::Payload), Some(3));
+/// ```
+#[derive(Debug)]
+pub struct TagTable<'a, K> {
+    entries: &'a [(K, usize)],
+}
+
+impl<'a, K> TagTable<'a, K> {
+    /// Creates a table from `(tag, offset)` pairs.
+    #[inline(always)]
+    pub const fn new(entries: &'a [(K, usize)]) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the `(tag, offset)` pairs of the table.
+    #[inline(always)]
+    pub const fn entries(&self) -> &'a [(K, usize)] {
+        self.entries
+    }
+
+    /// Returns the offset of the first entry with the given tag, if any.
+    pub fn offset(&self, tag: &K) -> Option<usize>
+    where
+        K: PartialEq,
+    {
+        self.entries
+            .iter()
+            .find(|(key, _)| key == tag)
+            .map(|&(_, offset)| offset)
+    }
+}
+
+impl<K> Clone for TagTable<'_, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for TagTable<'_, K> {}
+
+/// The error returned by [`swap3_bca_tagged`] and [`swap3_cab_tagged`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TagError {
+    /// The tag is not contained in the table.
+    UnknownTag {
+        /// The position of the tag argument, i.e. `0` for `ta`, `1` for `tb` and `2` for `tc`.
+        position: usize,
+    },
+    /// The offsets of the tags are invalid for the buffer.
+    Rotation {
+        /// The reason the rotation was rejected.
+        error: Swap3Error,
+    },
+}
+
+impl Display for TagError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TagError::UnknownTag { position } => {
+                write!(
+                    f,
+                    "the tag at position {position} is not contained in the table"
+                )
+            }
+            TagError::Rotation { error } => write!(f, "invalid tag offsets: {error}"),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for TagError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            TagError::Rotation { error } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(not(has_core_error), feature = "std"))]
+impl std::error::Error for TagError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TagError::Rotation { error } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Rotates the elements at the offsets of three tags to the left.
+///
+/// ## Arguments
+///
+/// * `data` - The buffer whose elements to swap.
+/// * `table` - The table mapping the tags to offsets in `data`.
+/// * `ta` - The first tag, to be assigned with the element at `tb`.
+/// * `tb` - The second tag, to be assigned with the element at `tc`.
+/// * `tc` - The third tag, to be assigned with the element at `ta`.
+///
+/// ## Errors
+///
+/// Returns [`TagError::UnknownTag`] for the first tag not contained in the table, or
+/// [`TagError::Rotation`] if any of the offsets is out of bounds or the offsets are not
+/// pairwise distinct, e.g. because a tag was given twice. The buffer is left unchanged in
+/// either case.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::{TagError, TagTable};
+///
+/// let table = TagTable::new(&[("len", 0), ("crc", 1), ("id", 4)]);
+/// let mut data = vec![10, 20, 30, 40, 50];
+/// swap3::swap3_bca_tagged(&mut data, &table, "len", "crc", "id").unwrap();
+/// assert_eq!(data, &[20, 50, 30, 40, 10]);
+///
+/// assert_eq!(
+///     swap3::swap3_bca_tagged(&mut data, &table, "len", "flags", "id"),
+///     Err(TagError::UnknownTag { position: 1 })
+/// );
+/// ```
+pub fn swap3_bca_tagged<T, K: PartialEq>(
+    data: &mut [T],
+    table: &TagTable<'_, K>,
+    ta: K,
+    tb: K,
+    tc: K,
+) -> Result<(), TagError> {
+    let [a, b, c] = resolve(table, [ta, tb, tc])?;
+    try_swap3_bca_slice(data, a, b, c).map_err(|error| TagError::Rotation { error })
+}
+
+/// Rotates the elements at the offsets of three tags to the right.
+///
+/// ## Arguments
+///
+/// * `data` - The buffer whose elements to swap.
+/// * `table` - The table mapping the tags to offsets in `data`.
+/// * `ta` - The first tag, to be assigned with the element at `tc`.
+/// * `tb` - The second tag, to be assigned with the element at `ta`.
+/// * `tc` - The third tag, to be assigned with the element at `tb`.
+///
+/// ## Errors
+///
+/// Returns [`TagError::UnknownTag`] for the first tag not contained in the table, or
+/// [`TagError::Rotation`] if any of the offsets is out of bounds or the offsets are not
+/// pairwise distinct, e.g. because a tag was given twice. The buffer is left unchanged in
+/// either case.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::{Swap3Error, TagError, TagTable};
+///
+/// let table = TagTable::new(&[("len", 0), ("crc", 1), ("id", 4)]);
+/// let mut data = vec![10, 20, 30, 40, 50];
+/// swap3::swap3_cab_tagged(&mut data, &table, "len", "crc", "id").unwrap();
+/// assert_eq!(data, &[50, 10, 30, 40, 20]);
+///
+/// assert_eq!(
+///     swap3::swap3_cab_tagged(&mut data, &table, "id", "crc", "id"),
+///     Err(TagError::Rotation {
+///         error: Swap3Error::DuplicateIndex { index: 4 }
+///     })
+/// );
+/// ```
+pub fn swap3_cab_tagged<T, K: PartialEq>(
+    data: &mut [T],
+    table: &TagTable<'_, K>,
+    ta: K,
+    tb: K,
+    tc: K,
+) -> Result<(), TagError> {
+    let [a, b, c] = resolve(table, [ta, tb, tc])?;
+    try_swap3_cab_slice(data, a, b, c).map_err(|error| TagError::Rotation { error })
+}
+
+/// Looks up the offsets of three tags.
+fn resolve<K: PartialEq>(table: &TagTable<'_, K>, tags: [K; 3]) -> Result<[usize; 3], TagError> {
+    let mut offsets = [0; 3];
+    for (position, tag) in tags.iter().enumerate() {
+        offsets[position] = table.offset(tag).ok_or(TagError::UnknownTag { position })?;
+    }
+    Ok(offsets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Region {
+        Header,
+        Payload,
+        Footer,
+        Padding,
+    }
+
+    const TABLE: TagTable<'static, Region> = TagTable::new(&[
+        (Region::Header, 0),
+        (Region::Payload, 2),
+        (Region::Footer, 5),
+        (Region::Padding, 6),
+    ]);
+
+    #[test]
+    fn test_swap3_tagged() {
+        use Region::*;
+
+        let mut data = [1, 2, 3, 4, 5, 6];
+        assert_eq!(
+            swap3_bca_tagged(&mut data, &TABLE, Footer, Header, Payload),
+            Ok(())
+        );
+        assert_eq!(data, [3, 2, 6, 4, 5, 1]);
+        assert_eq!(
+            swap3_cab_tagged(&mut data, &TABLE, Footer, Header, Payload),
+            Ok(())
+        );
+        assert_eq!(data, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_unknown_tag() {
+        let table = TagTable::new(&[(Region::Header, 0), (Region::Payload, 1)]);
+        let mut data = [1, 2, 3];
+        assert_eq!(
+            swap3_bca_tagged(
+                &mut data,
+                &table,
+                Region::Header,
+                Region::Footer,
+                Region::Padding
+            ),
+            Err(TagError::UnknownTag { position: 1 })
+        );
+        assert_eq!(data, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_invalid_offsets() {
+        use Region::*;
+
+        // The padding is beyond the end of the buffer.
+        let mut data = [1, 2, 3, 4, 5, 6];
+        assert_eq!(
+            swap3_bca_tagged(&mut data, &TABLE, Header, Payload, Padding),
+            Err(TagError::Rotation {
+                error: Swap3Error::IndexOutOfBounds { index: 6, len: 6 }
+            })
+        );
+        assert_eq!(
+            swap3_cab_tagged(&mut data, &TABLE, Header, Payload, Header),
+            Err(TagError::Rotation {
+                error: Swap3Error::DuplicateIndex { index: 0 }
+            })
+        );
+        assert_eq!(data, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_first_entry_wins() {
+        let table = TagTable::new(&[('a', 0), ('b', 1), ('a', 2)]);
+        assert_eq!(table.offset(&'a'), Some(0));
+        assert_eq!(table.offset(&'c'), None);
+    }
+}
@@ -0,0 +1,83 @@
+//! Coarse timing of individual rotations for ad hoc profiling.
+
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Rotates three values to the left and returns the time the rotation took.
+///
+/// This is meant for quick sanity checks, e.g. comparing builds with and without the `unsafe`
+/// feature. Timing a single call is subject to timer resolution and noise; use a proper
+/// benchmark harness such as `criterion` for reliable numbers.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// let elapsed = swap3::swap3_bca_slice_timed(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+/// println!("rotation took {elapsed:?}");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "timing")))]
+pub fn swap3_bca_slice_timed<T>(data: &mut [T], a: usize, b: usize, c: usize) -> Duration {
+    let start = Instant::now();
+    swap3_bca_slice(black_box(&mut *data), a, b, c);
+    start.elapsed()
+}
+
+/// Rotates three values to the right and returns the time the rotation took.
+///
+/// See [`swap3_bca_slice_timed`] for the caveats of timing individual calls.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// let elapsed = swap3::swap3_cab_slice_timed(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+/// println!("rotation took {elapsed:?}");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "timing")))]
+pub fn swap3_cab_slice_timed<T>(data: &mut [T], a: usize, b: usize, c: usize) -> Duration {
+    let start = Instant::now();
+    swap3_cab_slice(black_box(&mut *data), a, b, c);
+    start.elapsed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timed_rotations() {
+        let mut vec = vec![1, 2, 3];
+        let bca = swap3_bca_slice_timed(&mut vec, 0, 1, 2);
+        assert_eq!(vec, &[2, 3, 1]);
+        let cab = swap3_cab_slice_timed(&mut vec, 0, 1, 2);
+        assert_eq!(vec, &[1, 2, 3]);
+        assert!(bca < Duration::from_secs(1));
+        assert!(cab < Duration::from_secs(1));
+    }
+}
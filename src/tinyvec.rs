@@ -0,0 +1,185 @@
+//! Adapters for the [`tinyvec`](::tinyvec) crate.
+//!
+//! Both [`ArrayVec`] and [`TinyVec`](::tinyvec::TinyVec) dereference to slices, so the slice
+//! functions and the [`Swap3`](crate::Swap3) trait can be used on them directly. The functions
+//! in this module are thin wrappers that make this explicit. [`ArrayVec`] does not allocate
+//! and is available in `no_std` builds; `TinyVec` additionally requires the `alloc` feature.
+
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use ::tinyvec::{Array, ArrayVec};
+
+/// Rotates three values of an [`ArrayVec`] to the left.
+///
+/// ## Arguments
+///
+/// * `vec` - The vector whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `vec[b]`.
+/// * `b` - The second index, to be assigned with the value of `vec[c]`.
+/// * `c` - The third index, to be assigned with the value of `vec[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds of the vector's length or if the indices
+/// are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use tinyvec::array_vec;
+///
+/// let mut vec = array_vec!([i32; 8] => 50, 10, 90, 25, 30, 75);
+/// swap3::swap3_bca_arrayvec(&mut vec, 0, 1, 4);
+/// assert_eq!(vec.as_slice(), &[10, 30, 90, 25, 50, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "tinyvec")))]
+#[inline(always)]
+pub fn swap3_bca_arrayvec<A: Array>(vec: &mut ArrayVec<A>, a: usize, b: usize, c: usize) {
+    swap3_bca_slice(vec.as_mut_slice(), a, b, c);
+}
+
+/// Rotates three values of an [`ArrayVec`] to the right.
+///
+/// ## Arguments
+///
+/// * `vec` - The vector whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `vec[c]`.
+/// * `b` - The second index, to be assigned with the value of `vec[a]`.
+/// * `c` - The third index, to be assigned with the value of `vec[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds of the vector's length or if the indices
+/// are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use tinyvec::array_vec;
+///
+/// let mut vec = array_vec!([i32; 8] => 50, 10, 90, 25, 30, 75);
+/// swap3::swap3_cab_arrayvec(&mut vec, 0, 1, 4);
+/// assert_eq!(vec.as_slice(), &[30, 50, 90, 25, 10, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "tinyvec")))]
+#[inline(always)]
+pub fn swap3_cab_arrayvec<A: Array>(vec: &mut ArrayVec<A>, a: usize, b: usize, c: usize) {
+    swap3_cab_slice(vec.as_mut_slice(), a, b, c);
+}
+
+/// Rotates three values of a [`TinyVec`](::tinyvec::TinyVec) to the left.
+///
+/// ## Arguments
+///
+/// * `vec` - The vector whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `vec[b]`.
+/// * `b` - The second index, to be assigned with the value of `vec[c]`.
+/// * `c` - The third index, to be assigned with the value of `vec[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds of the vector's length or if the indices
+/// are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use tinyvec::tiny_vec;
+///
+/// let mut vec = tiny_vec!([i32; 4] => 50, 10, 90, 25, 30, 75);
+/// swap3::swap3_bca_tinyvec(&mut vec, 0, 1, 4);
+/// assert_eq!(vec.as_slice(), &[10, 30, 90, 25, 50, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(all(feature = "tinyvec", feature = "alloc"))))]
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn swap3_bca_tinyvec<A: Array>(vec: &mut ::tinyvec::TinyVec<A>, a: usize, b: usize, c: usize) {
+    swap3_bca_slice(vec.as_mut_slice(), a, b, c);
+}
+
+/// Rotates three values of a [`TinyVec`](::tinyvec::TinyVec) to the right.
+///
+/// ## Arguments
+///
+/// * `vec` - The vector whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `vec[c]`.
+/// * `b` - The second index, to be assigned with the value of `vec[a]`.
+/// * `c` - The third index, to be assigned with the value of `vec[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds of the vector's length or if the indices
+/// are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use tinyvec::tiny_vec;
+///
+/// let mut vec = tiny_vec!([i32; 4] => 50, 10, 90, 25, 30, 75);
+/// swap3::swap3_cab_tinyvec(&mut vec, 0, 1, 4);
+/// assert_eq!(vec.as_slice(), &[30, 50, 90, 25, 10, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(all(feature = "tinyvec", feature = "alloc"))))]
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn swap3_cab_tinyvec<A: Array>(vec: &mut ::tinyvec::TinyVec<A>, a: usize, b: usize, c: usize) {
+    swap3_cab_slice(vec.as_mut_slice(), a, b, c);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Swap3;
+    use ::tinyvec::array_vec;
+
+    #[test]
+    fn test_swap3_bca_arrayvec() {
+        let mut vec = array_vec!([u8; 6] => 1, 2, 3, 4, 5);
+        swap3_bca_arrayvec(&mut vec, 0, 2, 4);
+        assert_eq!(vec.as_slice(), &[3, 2, 5, 4, 1]);
+    }
+
+    #[test]
+    fn test_swap3_cab_arrayvec() {
+        let mut vec = array_vec!([u8; 6] => 1, 2, 3, 4, 5);
+        swap3_cab_arrayvec(&mut vec, 0, 2, 4);
+        assert_eq!(vec.as_slice(), &[5, 2, 1, 4, 3]);
+    }
+
+    #[test]
+    fn test_arrayvec_trait() {
+        let mut vec = array_vec!([u8; 6] => 1, 2, 3, 4, 5);
+        vec.swap3_bca(0, 2, 4);
+        vec.swap3_cab(0, 2, 4);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_arrayvec_beyond_len() {
+        // Index 5 is within the capacity, but not the length.
+        let mut vec = array_vec!([u8; 6] => 1, 2, 3, 4, 5);
+        swap3_bca_arrayvec(&mut vec, 0, 2, 5);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn test_arrayvec_unsafe() {
+        let mut vec = array_vec!([u8; 6] => 1, 2, 3, 4, 5);
+        crate::slice::bca_unsafe(&mut vec, 0, 2, 4);
+        assert_eq!(vec.as_slice(), &[3, 2, 5, 4, 1]);
+        crate::slice::cab_unsafe(&mut vec, 0, 2, 4);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_tinyvec_spilled() {
+        let mut vec = ::tinyvec::tiny_vec!([u8; 2] => 1, 2, 3, 4, 5);
+        assert!(vec.is_heap());
+        swap3_bca_tinyvec(&mut vec, 0, 2, 4);
+        assert_eq!(vec.as_slice(), &[3, 2, 5, 4, 1]);
+        swap3_cab_tinyvec(&mut vec, 0, 2, 4);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+}
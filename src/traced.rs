@@ -0,0 +1,236 @@
+//! Adapters for the [`tracing`](::tracing) crate.
+
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use ::tracing::{span, Level, Span};
+
+/// Rotates three values to the left within a `tracing` span.
+///
+/// A span named `swap3` is entered for the duration of the rotation. It records the
+/// `direction` (`"left"`), the indices `a`, `b` and `c` and the length `len` of the slice,
+/// so that the rotation can be correlated with other events of a trace. If the indices are
+/// invalid, the span is still entered while the panic unwinds.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `level` - The verbosity level of the span.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use tracing::Level;
+///
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_bca_slice_traced(&mut vec, 0, 1, 4, Level::DEBUG);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub fn swap3_bca_slice_traced<T>(data: &mut [T], a: usize, b: usize, c: usize, level: Level) {
+    let _entered = rotation_span(level, "left", data.len(), a, b, c).entered();
+    swap3_bca_slice(data, a, b, c);
+}
+
+/// Rotates three values to the right within a `tracing` span.
+///
+/// A span named `swap3` is entered for the duration of the rotation. It records the
+/// `direction` (`"right"`), the indices `a`, `b` and `c` and the length `len` of the slice,
+/// so that the rotation can be correlated with other events of a trace. If the indices are
+/// invalid, the span is still entered while the panic unwinds.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `level` - The verbosity level of the span.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use tracing::Level;
+///
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// swap3::swap3_cab_slice_traced(&mut vec, 0, 1, 4, Level::TRACE);
+/// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub fn swap3_cab_slice_traced<T>(data: &mut [T], a: usize, b: usize, c: usize, level: Level) {
+    let _entered = rotation_span(level, "right", data.len(), a, b, c).entered();
+    swap3_cab_slice(data, a, b, c);
+}
+
+/// Creates the span of a rotation.
+///
+/// The level of a span is part of its static metadata, hence each level needs its own
+/// invocation of the macro.
+fn rotation_span(
+    level: Level,
+    direction: &'static str,
+    len: usize,
+    a: usize,
+    b: usize,
+    c: usize,
+) -> Span {
+    macro_rules! rotation_span {
+        ($level:expr) => {
+            span!($level, "swap3", direction, len, a, b, c)
+        };
+    }
+
+    match level {
+        Level::TRACE => rotation_span!(Level::TRACE),
+        Level::DEBUG => rotation_span!(Level::DEBUG),
+        Level::INFO => rotation_span!(Level::INFO),
+        Level::WARN => rotation_span!(Level::WARN),
+        _ => rotation_span!(Level::ERROR),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// A span as seen by the [`Recorder`].
+    #[derive(Debug, Default, PartialEq)]
+    struct Recorded {
+        name: &'static str,
+        level: Option<Level>,
+        fields: Vec<(&'static str, String)>,
+        entered: usize,
+    }
+
+    /// Records all spans created and entered while it is the default subscriber.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<Recorded>>>);
+
+    impl Visit for Recorded {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.fields.push((field.name(), format!("{value:?}")));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.fields.push((field.name(), value.to_string()));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut recorded = Recorded {
+                name: span.metadata().name(),
+                level: Some(*span.metadata().level()),
+                ..Recorded::default()
+            };
+            span.record(&mut recorded);
+            let mut spans = self.0.lock().unwrap();
+            spans.push(recorded);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, span: &Id) {
+            self.0.lock().unwrap()[span.into_u64() as usize - 1].entered += 1;
+        }
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    fn record(rotate: impl FnOnce()) -> Vec<Recorded> {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), rotate);
+        let spans = core::mem::take(&mut *recorder.0.lock().unwrap());
+        spans
+    }
+
+    fn fields(
+        direction: &str,
+        len: usize,
+        a: usize,
+        b: usize,
+        c: usize,
+    ) -> Vec<(&'static str, String)> {
+        [("direction", direction.to_string())]
+            .into_iter()
+            .chain(
+                [("len", len), ("a", a), ("b", b), ("c", c)]
+                    .map(|(name, value)| (name, value.to_string())),
+            )
+            .collect()
+    }
+
+    #[test]
+    fn test_span_per_rotation() {
+        let mut data = [1, 2, 3, 4];
+        let spans = record(|| {
+            swap3_bca_slice_traced(&mut data, 0, 1, 3, Level::DEBUG);
+            swap3_cab_slice_traced(&mut data, 3, 2, 1, Level::WARN);
+        });
+        assert_eq!(data, [2, 3, 1, 4]);
+        assert_eq!(
+            spans,
+            [
+                Recorded {
+                    name: "swap3",
+                    level: Some(Level::DEBUG),
+                    fields: fields("left", 4, 0, 1, 3),
+                    entered: 1,
+                },
+                Recorded {
+                    name: "swap3",
+                    level: Some(Level::WARN),
+                    fields: fields("right", 4, 3, 2, 1),
+                    entered: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_levels() {
+        let levels = [
+            Level::TRACE,
+            Level::DEBUG,
+            Level::INFO,
+            Level::WARN,
+            Level::ERROR,
+        ];
+        let spans = record(|| {
+            for level in levels {
+                swap3_bca_slice_traced(&mut [1, 2, 3], 0, 1, 2, level);
+            }
+        });
+        let recorded: Vec<_> = spans.iter().map(|span| span.level.unwrap()).collect();
+        assert_eq!(recorded, levels);
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_overlapping() {
+        swap3_bca_slice_traced(&mut [1, 2, 3], 0, 1, 1, Level::INFO);
+    }
+}
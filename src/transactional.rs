@@ -0,0 +1,193 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+
+/// Rotates three values to the left, rolling back unless `check` accepts the result.
+///
+/// The rotation is applied first, then `check` is called with the rotated slice. If it
+/// returns `false`, the inverse rotation restores the original order. If `check` panics,
+/// the rotation is likewise rolled back before the panic is propagated.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+/// * `check` - The post-condition the rotated slice must satisfy.
+///
+/// ## Returns
+///
+/// `true` if the rotation was kept, `false` if it was rolled back.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `check` is not called in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20, 30];
+/// let is_sorted = |data: &[i32]| data.windows(2).all(|w| w[0] <= w[1]);
+///
+/// assert!(!swap3::swap3_bca_slice_transactional(&mut vec, 0, 1, 2, is_sorted));
+/// assert_eq!(vec, &[10, 20, 30]);
+///
+/// assert!(swap3::swap3_bca_slice_transactional(&mut vec, 0, 1, 2, |data| data[0] == 20));
+/// assert_eq!(vec, &[20, 30, 10]);
+/// ```
+pub fn swap3_bca_slice_transactional<T, F>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+    check: F,
+) -> bool
+where
+    F: FnOnce(&[T]) -> bool,
+{
+    swap3_bca_slice(data, a, b, c);
+    Rollback::new(data, [a, b, c], swap3_cab_slice).check(check)
+}
+
+/// Rotates three values to the right, rolling back unless `check` accepts the result.
+///
+/// The rotation is applied first, then `check` is called with the rotated slice. If it
+/// returns `false`, the inverse rotation restores the original order. If `check` panics,
+/// the rotation is likewise rolled back before the panic is propagated.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+/// * `check` - The post-condition the rotated slice must satisfy.
+///
+/// ## Returns
+///
+/// `true` if the rotation was kept, `false` if it was rolled back.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+/// `check` is not called in this case.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![10, 20, 30];
+/// assert!(swap3::swap3_cab_slice_transactional(&mut vec, 0, 1, 2, |data| data[0] == 30));
+/// assert_eq!(vec, &[30, 10, 20]);
+/// ```
+pub fn swap3_cab_slice_transactional<T, F>(
+    data: &mut [T],
+    a: usize,
+    b: usize,
+    c: usize,
+    check: F,
+) -> bool
+where
+    F: FnOnce(&[T]) -> bool,
+{
+    swap3_cab_slice(data, a, b, c);
+    Rollback::new(data, [a, b, c], swap3_bca_slice).check(check)
+}
+
+/// Applies the inverse rotation when dropped, unless disarmed.
+pub(crate) struct Rollback<'a, T> {
+    data: &'a mut [T],
+    indices: [usize; 3],
+    inverse: fn(&mut [T], usize, usize, usize),
+    armed: bool,
+}
+
+impl<'a, T> Rollback<'a, T> {
+    pub(crate) fn new(
+        data: &'a mut [T],
+        indices: [usize; 3],
+        inverse: fn(&mut [T], usize, usize, usize),
+    ) -> Self {
+        Self {
+            data,
+            indices,
+            inverse,
+            armed: true,
+        }
+    }
+
+    fn check<F: FnOnce(&[T]) -> bool>(mut self, check: F) -> bool {
+        // If `check` panics, the guard is dropped while still armed.
+        let keep = check(self.data);
+        self.armed = !keep;
+        keep
+    }
+
+    pub(crate) fn validate<E, F: FnOnce(&[T]) -> Result<(), E>>(
+        mut self,
+        validate: F,
+    ) -> Result<(), E> {
+        // If `validate` panics, the guard is dropped while still armed.
+        let result = validate(self.data);
+        self.armed = result.is_err();
+        result
+    }
+}
+
+impl<T> Drop for Rollback<'_, T> {
+    fn drop(&mut self) {
+        if self.armed {
+            let [a, b, c] = self.indices;
+            (self.inverse)(self.data, a, b, c);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kept() {
+        let mut data = [1, 2, 3, 4, 5];
+        assert!(swap3_bca_slice_transactional(&mut data, 0, 2, 4, |d| d[0] == 3));
+        assert_eq!(data, [3, 2, 5, 4, 1]);
+        assert!(swap3_cab_slice_transactional(&mut data, 0, 2, 4, |_| true));
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_rolled_back() {
+        let mut data = [1, 2, 3, 4, 5];
+        let mut seen = [0; 5];
+        assert!(!swap3_bca_slice_transactional(&mut data, 0, 2, 4, |d| {
+            seen.copy_from_slice(d);
+            false
+        }));
+        assert_eq!(seen, [3, 2, 5, 4, 1], "check observes the rotated slice");
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+        assert!(!swap3_cab_slice_transactional(&mut data, 0, 2, 4, |_| {
+            false
+        }));
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_rolled_back_on_panic() {
+        let mut data = [1, 2, 3, 4, 5];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_cab_slice_transactional(&mut data, 0, 2, 4, |_| panic!("check failed"))
+        }));
+        assert!(result.is_err());
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_invalid_indices_skip_check() {
+        let mut data = [1, 2, 3];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            swap3_bca_slice_transactional(&mut data, 0, 1, 3, |_| unreachable!())
+        }));
+        assert!(result.is_err());
+        assert_eq!(data, [1, 2, 3]);
+    }
+}
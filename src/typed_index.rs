@@ -0,0 +1,173 @@
+//! Adapters for the [`typed-index-collections`](typed_index_collections) crate.
+
+use crate::{swap3_bca_slice, swap3_cab_slice, Swap3};
+use typed_index_collections::TiSlice;
+
+/// Rotates three values of a [`TiVec`](typed_index_collections::TiVec) to the left.
+///
+/// ## Arguments
+///
+/// * `vec` - The vector whose elements to swap.
+/// * `a` - The first key, to be assigned with the value of `vec[b]`.
+/// * `b` - The second key, to be assigned with the value of `vec[c]`.
+/// * `c` - The third key, to be assigned with the value of `vec[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the keys is out of bounds or if the keys are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use typed_index_collections::TiVec;
+///
+/// #[derive(Clone, Copy)]
+/// struct Id(usize);
+///
+/// impl From<usize> for Id {
+///     fn from(value: usize) -> Self {
+///         Id(value)
+///     }
+/// }
+///
+/// impl From<Id> for usize {
+///     fn from(value: Id) -> Self {
+///         value.0
+///     }
+/// }
+///
+/// let mut vec: TiVec<Id, _> = vec![50, 10, 90, 25, 30, 75].into();
+/// swap3::swap3_bca_tivec(&mut vec, Id(0), Id(1), Id(4));
+/// assert_eq!(vec.raw, &[10, 30, 90, 25, 50, 75]);
+/// ```
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "typed-index-collections", feature = "alloc")))
+)]
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn swap3_bca_tivec<K: Into<usize>, V>(
+    vec: &mut typed_index_collections::TiVec<K, V>,
+    a: K,
+    b: K,
+    c: K,
+) {
+    swap3_bca_slice(&mut vec.raw, a.into(), b.into(), c.into());
+}
+
+/// Rotates three values of a [`TiVec`](typed_index_collections::TiVec) to the right.
+///
+/// ## Arguments
+///
+/// * `vec` - The vector whose elements to swap.
+/// * `a` - The first key, to be assigned with the value of `vec[c]`.
+/// * `b` - The second key, to be assigned with the value of `vec[a]`.
+/// * `c` - The third key, to be assigned with the value of `vec[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the keys is out of bounds or if the keys are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use typed_index_collections::TiVec;
+///
+/// let mut vec: TiVec<usize, _> = vec![50, 10, 90, 25, 30, 75].into();
+/// swap3::swap3_cab_tivec(&mut vec, 0, 1, 4);
+/// assert_eq!(vec.raw, &[30, 50, 90, 25, 10, 75]);
+/// ```
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "typed-index-collections", feature = "alloc")))
+)]
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn swap3_cab_tivec<K: Into<usize>, V>(
+    vec: &mut typed_index_collections::TiVec<K, V>,
+    a: K,
+    b: K,
+    c: K,
+) {
+    swap3_cab_slice(&mut vec.raw, a.into(), b.into(), c.into());
+}
+
+/// Provides the rotations on typed-index slices, using the slice's key type as the index.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::prelude::*;
+/// use typed_index_collections::TiSlice;
+///
+/// let mut data = [50, 10, 90, 25, 30, 75];
+/// let slice: &mut TiSlice<usize, _> = TiSlice::from_mut(&mut data);
+/// slice.swap3_bca(0, 1, 4);
+/// assert_eq!(data, [10, 30, 90, 25, 50, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "typed-index-collections")))]
+impl<K: Into<usize>, V> Swap3<K> for TiSlice<K, V> {
+    fn swap3_bca(&mut self, a: K, b: K, c: K) {
+        swap3_bca_slice(&mut self.raw, a.into(), b.into(), c.into())
+    }
+
+    fn swap3_cab(&mut self, a: K, b: K, c: K) {
+        swap3_cab_slice(&mut self.raw, a.into(), b.into(), c.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use typed_index_collections::TiVec;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Key(usize);
+
+    impl From<usize> for Key {
+        fn from(value: usize) -> Self {
+            Key(value)
+        }
+    }
+
+    impl From<Key> for usize {
+        fn from(value: Key) -> Self {
+            value.0
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_swap3_bca_tivec() {
+        let mut vec: TiVec<Key, _> = vec![1, 2, 3, 4].into();
+        swap3_bca_tivec(&mut vec, Key(0), Key(1), Key(3));
+        assert_eq!(vec.raw, &[2, 4, 3, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_swap3_cab_tivec() {
+        let mut vec: TiVec<Key, _> = vec![1, 2, 3, 4].into();
+        swap3_cab_tivec(&mut vec, Key(0), Key(1), Key(3));
+        assert_eq!(vec.raw, &[4, 1, 3, 2]);
+    }
+
+    #[test]
+    fn test_tislice_trait() {
+        let mut data = [1, 2, 3, 4];
+        let slice: &mut TiSlice<Key, _> = TiSlice::from_mut(&mut data);
+        slice.swap3_bca(Key(0), Key(1), Key(3));
+        assert_eq!(slice.raw, [2, 4, 3, 1]);
+        slice.swap3_cab(Key(0), Key(1), Key(3));
+        assert_eq!(slice.raw, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic]
+    fn test_out_of_bounds_key() {
+        let mut vec: TiVec<Key, _> = vec![1, 2, 3, 4].into();
+        swap3_bca_tivec(&mut vec, Key(0), Key(1), Key(4));
+    }
+}
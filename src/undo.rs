@@ -0,0 +1,142 @@
+use crate::{swap3_bca_slice, swap3_cab_slice, Rotation, Rotation3};
+
+/// A token reversing a single rotation, as returned by [`swap3_bca_slice_undoable`] and
+/// [`swap3_cab_slice_undoable`].
+///
+/// Applying the token performs the inverse rotation of the same positions. It is consumed
+/// in the process, so that a rotation cannot accidentally be undone twice. Undoing a sequence
+/// of rotations requires applying their tokens in reverse order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use = "the rotation is only undone by `apply`"]
+pub struct Undo {
+    inverse: Rotation3,
+}
+
+impl Undo {
+    /// Undoes the rotation.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - The slice the rotation was applied to.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the indices of the rotation are out of bounds of `data`, which can only
+    /// happen if `data` is not the slice the rotation was applied to.
+    #[inline(always)]
+    pub fn apply<T>(self, data: &mut [T]) {
+        self.inverse.apply(data);
+    }
+
+    /// Returns the rotation undoing the original one.
+    #[inline(always)]
+    pub const fn inverse(&self) -> Rotation3 {
+        self.inverse
+    }
+}
+
+/// Rotates three values of a slice to the left, returning a token undoing the rotation.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[b]`.
+/// * `b` - The second index, to be assigned with the value of `data[c]`.
+/// * `c` - The third index, to be assigned with the value of `data[a]`.
+///
+/// ## Returns
+///
+/// The [`Undo`] token performing the inverse rotation, see [`swap3_cab_slice`].
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// let undo = swap3::swap3_bca_slice_undoable(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[10, 30, 90, 25, 50, 75]);
+///
+/// undo.apply(&mut vec);
+/// assert_eq!(vec, &[50, 10, 90, 25, 30, 75]);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_slice_undoable<T>(data: &mut [T], a: usize, b: usize, c: usize) -> Undo {
+    swap3_bca_slice(data, a, b, c);
+    Undo {
+        inverse: Rotation3::new(Rotation::Right, a, b, c),
+    }
+}
+
+/// Rotates three values of a slice to the right, returning a token undoing the rotation.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `data[c]`.
+/// * `b` - The second index, to be assigned with the value of `data[a]`.
+/// * `c` - The third index, to be assigned with the value of `data[b]`.
+///
+/// ## Returns
+///
+/// The [`Undo`] token performing the inverse rotation, see [`swap3_bca_slice`].
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// let mut vec = vec![50, 10, 90, 25, 30, 75];
+/// let undo = swap3::swap3_cab_slice_undoable(&mut vec, 0, 1, 4);
+/// assert_eq!(vec, &[30, 50, 90, 25, 10, 75]);
+///
+/// undo.apply(&mut vec);
+/// assert_eq!(vec, &[50, 10, 90, 25, 30, 75]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_slice_undoable<T>(data: &mut [T], a: usize, b: usize, c: usize) -> Undo {
+    swap3_cab_slice(data, a, b, c);
+    Undo {
+        inverse: Rotation3::new(Rotation::Left, a, b, c),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_in_reverse_order() {
+        let mut data = [1, 2, 3, 4, 5];
+        let first = swap3_bca_slice_undoable(&mut data, 4, 0, 2);
+        let second = swap3_cab_slice_undoable(&mut data, 1, 2, 4);
+        let third = swap3_bca_slice_undoable(&mut data, 0, 3, 2);
+        assert_ne!(data, [1, 2, 3, 4, 5]);
+
+        for undo in [third, second, first] {
+            undo.apply(&mut data);
+        }
+        assert_eq!(data, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut data = [1, 2, 3];
+        let undo = swap3_cab_slice_undoable(&mut data, 2, 0, 1);
+        assert_eq!(undo.inverse(), Rotation3::new(Rotation::Left, 2, 0, 1));
+        undo.apply(&mut data);
+        assert_eq!(data, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_apply_to_shorter_slice() {
+        let mut data = [1, 2, 3, 4];
+        let undo = swap3_bca_slice_undoable(&mut data, 0, 1, 3);
+        undo.apply(&mut data[..3]);
+    }
+}
@@ -0,0 +1,169 @@
+use core::cell::UnsafeCell;
+use core::ptr;
+
+/// Rotates the contents of three [`UnsafeCell`]s to the left.
+///
+/// This is intended for authors of synchronization primitives storing their data in
+/// `UnsafeCell`s, who establish exclusive access by other means, e.g. by holding a lock
+/// covering all three cells.
+///
+/// ## Arguments
+///
+/// * `a` - The first cell, to be assigned with the value of `b`.
+/// * `b` - The second cell, to be assigned with the value of `c`.
+/// * `c` - The third cell, to be assigned with the value of `a`.
+///
+/// ## Safety
+///
+/// For the duration of the call, the caller must have exclusive access to the contents of
+/// all three cells: no references to the contents may exist, and no other thread may access
+/// them. The cells must be pairwise distinct; this is verified by debug assertions only,
+/// and not at all for zero-sized types.
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::UnsafeCell;
+///
+/// let a = UnsafeCell::new(10);
+/// let b = UnsafeCell::new(20);
+/// let c = UnsafeCell::new(30);
+/// // SAFETY: The cells are distinct, and their contents are not borrowed or shared.
+/// unsafe { swap3::swap3_bca_unsafecells(&a, &b, &c) };
+/// assert_eq!([a.into_inner(), b.into_inner(), c.into_inner()], [20, 30, 10]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "unsafe")))]
+#[inline(always)]
+pub unsafe fn swap3_bca_unsafecells<T>(a: &UnsafeCell<T>, b: &UnsafeCell<T>, c: &UnsafeCell<T>) {
+    debug_assert_distinct(a, b, c);
+    let (pa, pb, pc) = (a.get(), b.get(), c.get());
+    // SAFETY: The pointers of `UnsafeCell::get` are valid and aligned. The caller guarantees
+    // that the cells are distinct, hence the pointers do not overlap, and that nothing else
+    // accesses the contents.
+    unsafe {
+        ptr::swap_nonoverlapping(pa, pb, 1);
+        ptr::swap_nonoverlapping(pb, pc, 1);
+    }
+}
+
+/// Rotates the contents of three [`UnsafeCell`]s to the right.
+///
+/// This is intended for authors of synchronization primitives storing their data in
+/// `UnsafeCell`s, who establish exclusive access by other means, e.g. by holding a lock
+/// covering all three cells.
+///
+/// ## Arguments
+///
+/// * `a` - The first cell, to be assigned with the value of `c`.
+/// * `b` - The second cell, to be assigned with the value of `a`.
+/// * `c` - The third cell, to be assigned with the value of `b`.
+///
+/// ## Safety
+///
+/// For the duration of the call, the caller must have exclusive access to the contents of
+/// all three cells: no references to the contents may exist, and no other thread may access
+/// them. The cells must be pairwise distinct; this is verified by debug assertions only,
+/// and not at all for zero-sized types.
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::UnsafeCell;
+///
+/// let a = UnsafeCell::new(10);
+/// let b = UnsafeCell::new(20);
+/// let c = UnsafeCell::new(30);
+/// // SAFETY: The cells are distinct, and their contents are not borrowed or shared.
+/// unsafe { swap3::swap3_cab_unsafecells(&a, &b, &c) };
+/// assert_eq!([a.into_inner(), b.into_inner(), c.into_inner()], [30, 10, 20]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "unsafe")))]
+#[inline(always)]
+pub unsafe fn swap3_cab_unsafecells<T>(a: &UnsafeCell<T>, b: &UnsafeCell<T>, c: &UnsafeCell<T>) {
+    debug_assert_distinct(a, b, c);
+    let (pa, pb, pc) = (a.get(), b.get(), c.get());
+    // SAFETY: See `swap3_bca_unsafecells`.
+    unsafe {
+        ptr::swap_nonoverlapping(pa, pc, 1);
+        ptr::swap_nonoverlapping(pb, pc, 1);
+    }
+}
+
+/// Asserts in debug builds that the cells are pairwise distinct.
+///
+/// Distinct cells of a zero-sized type may share an address, so these are not checked; moving
+/// zero-sized values is a no-op anyway.
+#[inline(always)]
+fn debug_assert_distinct<T>(a: &UnsafeCell<T>, b: &UnsafeCell<T>, c: &UnsafeCell<T>) {
+    debug_assert!(
+        core::mem::size_of::<T>() == 0 || (!ptr::eq(a, b) && !ptr::eq(b, c) && !ptr::eq(a, c)),
+        "the cells must be pairwise distinct"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap3_unsafecells() {
+        let cells = [1, 2, 3, 4, 5].map(UnsafeCell::new);
+        // SAFETY: The cells are distinct, and their contents are not borrowed or shared.
+        unsafe {
+            swap3_bca_unsafecells(&cells[4], &cells[0], &cells[2]);
+            swap3_cab_unsafecells(&cells[4], &cells[0], &cells[2]);
+            swap3_bca_unsafecells(&cells[0], &cells[2], &cells[4]);
+        }
+        assert_eq!(cells.map(UnsafeCell::into_inner), [3, 2, 5, 4, 1]);
+    }
+
+    #[test]
+    fn test_shared_across_threads() {
+        // A minimal lock-protected container rotating its cells under the lock.
+        struct Locked {
+            lock: std::sync::Mutex<()>,
+            cells: [UnsafeCell<String>; 3],
+        }
+
+        // SAFETY: The cells are only accessed while holding the lock.
+        unsafe impl Sync for Locked {}
+
+        let locked = Locked {
+            lock: std::sync::Mutex::new(()),
+            cells: ["a", "b", "c"].map(|s| UnsafeCell::new(String::from(s))),
+        };
+        // Capturing the fields individually would require them to be `Sync` themselves.
+        let shared = &locked;
+        std::thread::scope(|scope| {
+            for _ in 0..3 {
+                scope.spawn(move || {
+                    let _guard = shared.lock.lock().unwrap();
+                    let [a, b, c] = &shared.cells;
+                    // SAFETY: The lock grants exclusive access to the distinct cells.
+                    unsafe { swap3_bca_unsafecells(a, b, c) };
+                });
+            }
+        });
+        assert_eq!(locked.cells.map(UnsafeCell::into_inner), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_zero_sized() {
+        let cells = [(), (), ()].map(UnsafeCell::new);
+        // SAFETY: The cells are distinct, and their contents are not borrowed or shared.
+        unsafe {
+            swap3_bca_unsafecells(&cells[0], &cells[1], &cells[2]);
+            swap3_cab_unsafecells(&cells[0], &cells[1], &cells[2]);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "pairwise distinct")]
+    fn test_same_cell() {
+        let a = UnsafeCell::new(1);
+        let b = UnsafeCell::new(2);
+        // SAFETY: Not upheld; caught by the debug assertion before any access.
+        unsafe { swap3_cab_unsafecells(&a, &b, &a) };
+    }
+}
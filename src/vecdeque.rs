@@ -0,0 +1,214 @@
+use alloc::collections::VecDeque;
+
+/// A position in a [`VecDeque`], counted from either of its ends.
+///
+/// Positions are resolved against the length of the deque at the time of the rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub enum DequePosition {
+    /// The position counted from the front, i.e. `FromFront(0)` is the first element.
+    FromFront(usize),
+    /// The position counted from the back, i.e. `FromBack(0)` is the last element.
+    FromBack(usize),
+}
+
+impl DequePosition {
+    /// Resolves the position to an index into a deque of length `len`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the position is out of bounds.
+    fn resolve(self, len: usize) -> usize {
+        match self {
+            DequePosition::FromFront(index) => index,
+            DequePosition::FromBack(back) => crate::symmetric::from_back(len, back),
+        }
+    }
+}
+
+/// Rotates three values of a [`VecDeque`] to the left.
+///
+/// ## Arguments
+///
+/// * `deque` - The deque whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `deque[b]`.
+/// * `b` - The second index, to be assigned with the value of `deque[c]`.
+/// * `c` - The third index, to be assigned with the value of `deque[a]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::VecDeque;
+///
+/// let mut deque = VecDeque::from([50, 10, 90, 25, 30, 75]);
+/// swap3::swap3_bca_vecdeque(&mut deque, 0, 1, 4);
+/// assert_eq!(deque, [10, 30, 90, 25, 50, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_bca_vecdeque<T>(deque: &mut VecDeque<T>, a: usize, b: usize, c: usize) {
+    crate::disjoint::assert_indices(deque.len(), a, b, c);
+    // As the indices are distinct, this matches the pairwise swaps of `swap3_bca`.
+    deque.swap(a, b);
+    deque.swap(b, c);
+}
+
+/// Rotates three values of a [`VecDeque`] to the right.
+///
+/// ## Arguments
+///
+/// * `deque` - The deque whose elements to swap.
+/// * `a` - The first index, to be assigned with the value of `deque[c]`.
+/// * `b` - The second index, to be assigned with the value of `deque[a]`.
+/// * `c` - The third index, to be assigned with the value of `deque[b]`.
+///
+/// ## Panics
+///
+/// Panics if any of the indices is out of bounds or if the indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::VecDeque;
+///
+/// let mut deque = VecDeque::from([50, 10, 90, 25, 30, 75]);
+/// swap3::swap3_cab_vecdeque(&mut deque, 0, 1, 4);
+/// assert_eq!(deque, [30, 50, 90, 25, 10, 75]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_cab_vecdeque<T>(deque: &mut VecDeque<T>, a: usize, b: usize, c: usize) {
+    crate::disjoint::assert_indices(deque.len(), a, b, c);
+    // As the indices are distinct, this matches the pairwise swaps of `swap3_cab`.
+    deque.swap(a, c);
+    deque.swap(b, c);
+}
+
+/// Rotates three values of a [`VecDeque`] to the left, addressed relative to its ends.
+///
+/// ## Arguments
+///
+/// * `deque` - The deque whose elements to swap.
+/// * `a` - The first position, to be assigned with the value at `b`.
+/// * `b` - The second position, to be assigned with the value at `c`.
+/// * `c` - The third position, to be assigned with the value at `a`.
+///
+/// ## Panics
+///
+/// Panics if any of the positions is out of bounds, which is always the case for an
+/// empty deque, or if the resolved indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use swap3::DequePosition::{FromBack, FromFront};
+///
+/// let mut deque = VecDeque::from([1, 2, 3, 4, 5, 6]);
+/// swap3::swap3_bca_vecdeque_ends(&mut deque, FromFront(0), FromBack(0), FromFront(2));
+/// assert_eq!(deque, [6, 2, 1, 4, 5, 3]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_bca_vecdeque_ends<T>(
+    deque: &mut VecDeque<T>,
+    a: DequePosition,
+    b: DequePosition,
+    c: DequePosition,
+) {
+    let len = deque.len();
+    swap3_bca_vecdeque(deque, a.resolve(len), b.resolve(len), c.resolve(len));
+}
+
+/// Rotates three values of a [`VecDeque`] to the right, addressed relative to its ends.
+///
+/// ## Arguments
+///
+/// * `deque` - The deque whose elements to swap.
+/// * `a` - The first position, to be assigned with the value at `c`.
+/// * `b` - The second position, to be assigned with the value at `a`.
+/// * `c` - The third position, to be assigned with the value at `b`.
+///
+/// ## Panics
+///
+/// Panics if any of the positions is out of bounds, which is always the case for an
+/// empty deque, or if the resolved indices are not pairwise distinct.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use swap3::DequePosition::{FromBack, FromFront};
+///
+/// let mut deque = VecDeque::from([1, 2, 3, 4, 5, 6]);
+/// swap3::swap3_cab_vecdeque_ends(&mut deque, FromFront(0), FromBack(0), FromFront(2));
+/// assert_eq!(deque, [3, 2, 6, 4, 5, 1]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn swap3_cab_vecdeque_ends<T>(
+    deque: &mut VecDeque<T>,
+    a: DequePosition,
+    b: DequePosition,
+    c: DequePosition,
+) {
+    let len = deque.len();
+    swap3_cab_vecdeque(deque, a.resolve(len), b.resolve(len), c.resolve(len));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DequePosition::{FromBack, FromFront};
+
+    /// Creates a deque whose elements wrap around the end of its buffer.
+    fn wrapped_deque() -> VecDeque<i32> {
+        let mut deque = VecDeque::with_capacity(6);
+        deque.extend([0, 0, 0, 3, 4, 5]);
+        deque.drain(..3);
+        deque.extend([6, 7, 8]);
+        for x in (0..3).rev() {
+            deque.push_front(x);
+        }
+        deque.truncate(8);
+        deque
+    }
+
+    #[test]
+    fn test_swap3_vecdeque_wrapped() {
+        let mut deque = wrapped_deque();
+        assert!(
+            !deque.as_slices().1.is_empty(),
+            "the deque must wrap around"
+        );
+        assert_eq!(deque, [0, 1, 2, 3, 4, 5, 6, 7]);
+        swap3_bca_vecdeque(&mut deque, 7, 0, 4);
+        assert_eq!(deque, [4, 1, 2, 3, 7, 5, 6, 0]);
+        swap3_cab_vecdeque(&mut deque, 7, 0, 4);
+        assert_eq!(deque, [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_swap3_vecdeque_ends() {
+        let mut deque = wrapped_deque();
+        swap3_bca_vecdeque_ends(&mut deque, FromBack(1), FromFront(1), FromBack(7));
+        assert_eq!(deque, [6, 0, 2, 3, 4, 5, 1, 7]);
+        swap3_cab_vecdeque_ends(&mut deque, FromBack(1), FromFront(1), FromBack(7));
+        assert_eq!(deque, [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 0 but the index from the back is 0")]
+    fn test_empty_deque() {
+        let mut deque = VecDeque::<i32>::new();
+        swap3_bca_vecdeque_ends(&mut deque, FromFront(0), FromBack(0), FromFront(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "pairwise distinct, but got 0, 1 and 0")]
+    fn test_short_deque_aliasing() {
+        // In a deque of two elements, the front and the second to last element coincide.
+        let mut deque = VecDeque::from([1, 2]);
+        swap3_cab_vecdeque_ends(&mut deque, FromFront(0), FromBack(0), FromBack(1));
+    }
+}
@@ -0,0 +1,162 @@
+//! Proof harnesses for the [Kani](https://model-checking.github.io/kani/) model checker.
+//!
+//! The harnesses prove that the rotations move exactly the three addressed elements as
+//! described, for all values and all valid indices of a slice of [`LEN`] elements, and that
+//! invalid indices are rejected. For the `unsafe` implementations, Kani additionally proves
+//! the absence of undefined behavior. Run them using `cargo kani`, and `cargo kani --features
+//! unsafe` for the unsafe implementations.
+
+use crate::{swap3_bca, swap3_bca_slice, swap3_cab, try_swap3_bca_slice, try_swap3_cab_slice};
+
+/// The length of the slices the harnesses rotate.
+const LEN: usize = 5;
+
+/// A rotation of three positions of a slice.
+type SliceRotation = fn(&mut [u8], usize, usize, usize);
+
+/// Creates symbolic indices that are in bounds and pairwise distinct.
+fn valid_indices() -> (usize, usize, usize) {
+    let (a, b, c) = (kani::any(), kani::any(), kani::any());
+    kani::assume(crate::error::check_indices(LEN, a, b, c).is_ok());
+    (a, b, c)
+}
+
+/// Returns the original index of the element expected at `index` after rotating `a`, `b`
+/// and `c` to the left.
+fn origin_bca(index: usize, a: usize, b: usize, c: usize) -> usize {
+    match index {
+        i if i == a => b,
+        i if i == b => c,
+        i if i == c => a,
+        i => i,
+    }
+}
+
+/// Returns the original index of the element expected at `index` after rotating `a`, `b`
+/// and `c` to the right.
+fn origin_cab(index: usize, a: usize, b: usize, c: usize) -> usize {
+    match index {
+        i if i == a => c,
+        i if i == b => a,
+        i if i == c => b,
+        i => i,
+    }
+}
+
+/// Checks that `rotate` moves the element at `origin(i)` to every index `i`.
+fn check_rotation(rotate: SliceRotation, origin: fn(usize, usize, usize, usize) -> usize) {
+    let before: [u8; LEN] = kani::any();
+    let (a, b, c) = valid_indices();
+    let mut after = before;
+    rotate(&mut after, a, b, c);
+    for i in 0..LEN {
+        assert_eq!(after[i], before[origin(i, a, b, c)]);
+    }
+}
+
+#[kani::proof]
+fn proof_swap3_bca() {
+    let (x, y, z): (u8, u8, u8) = kani::any();
+    let (mut a, mut b, mut c) = (x, y, z);
+    swap3_bca(&mut a, &mut b, &mut c);
+    assert_eq!([a, b, c], [y, z, x]);
+}
+
+#[kani::proof]
+fn proof_swap3_cab() {
+    let (x, y, z): (u8, u8, u8) = kani::any();
+    let (mut a, mut b, mut c) = (x, y, z);
+    swap3_cab(&mut a, &mut b, &mut c);
+    assert_eq!([a, b, c], [z, x, y]);
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn proof_bca_safe() {
+    check_rotation(crate::slice::bca_safe, origin_bca);
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn proof_cab_safe() {
+    check_rotation(crate::slice::cab_safe, origin_cab);
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+#[cfg(feature = "unsafe")]
+fn proof_bca_unsafe() {
+    check_rotation(crate::slice::bca_unsafe, origin_bca);
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+#[cfg(feature = "unsafe")]
+fn proof_cab_unsafe() {
+    check_rotation(crate::slice::cab_unsafe, origin_cab);
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+#[cfg(feature = "unsafe")]
+fn proof_bca_unchecked() {
+    // SAFETY: `check_rotation` only passes valid indices.
+    check_rotation(
+        |data, a, b, c| unsafe { crate::slice::bca_unchecked(data, a, b, c) },
+        origin_bca,
+    );
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+#[cfg(feature = "unsafe")]
+fn proof_cab_unchecked() {
+    // SAFETY: `check_rotation` only passes valid indices.
+    check_rotation(
+        |data, a, b, c| unsafe { crate::slice::cab_unchecked(data, a, b, c) },
+        origin_cab,
+    );
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn proof_cab_inverts_bca() {
+    let before: [u8; LEN] = kani::any();
+    let (a, b, c) = valid_indices();
+    let mut data = before;
+    swap3_bca_slice(&mut data, a, b, c);
+    crate::swap3_cab_slice(&mut data, a, b, c);
+    assert_eq!(data, before);
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn proof_try_rotations() {
+    let before: [u8; LEN] = kani::any();
+    let (a, b, c): (usize, usize, usize) = kani::any();
+    let valid = a < LEN && b < LEN && c < LEN && a != b && b != c && a != c;
+
+    let mut left = before;
+    let mut right = before;
+    assert_eq!(try_swap3_bca_slice(&mut left, a, b, c).is_ok(), valid);
+    assert_eq!(try_swap3_cab_slice(&mut right, a, b, c).is_ok(), valid);
+    for i in 0..LEN {
+        if valid {
+            assert_eq!(left[i], before[origin_bca(i, a, b, c)]);
+            assert_eq!(right[i], before[origin_cab(i, a, b, c)]);
+        } else {
+            assert_eq!(left[i], before[i]);
+            assert_eq!(right[i], before[i]);
+        }
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+#[kani::should_panic]
+fn proof_invalid_indices_panic() {
+    let mut data: [u8; LEN] = kani::any();
+    let (a, b, c) = kani::any();
+    kani::assume(crate::error::check_indices(LEN, a, b, c).is_err());
+    swap3_bca_slice(&mut data, a, b, c);
+}
@@ -0,0 +1,228 @@
+use crate::{swap3_bca_slice, swap3_cab_slice};
+use rand::Rng;
+
+/// Rotates three values to the left, chosen at random with probabilities proportional to
+/// their weights.
+///
+/// The indices are sampled without replacement: `a` is drawn with a probability of
+/// `weights[a]` divided by the sum of all weights, then `b` in the same way among the
+/// remaining indices, and finally `c`. Indices with a weight of zero are never chosen. This is
+/// intended for metaheuristics such as simulated annealing, which focus their moves on the
+/// positions contributing most to the cost.
+///
+/// Each draw takes `O(n)` time for a slice of `n` elements.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `weights` - The non-negative weights of the positions of `data`.
+/// * `rng` - The random number generator to sample the indices with.
+///
+/// ## Returns
+///
+/// The indices `(a, b, c)` of the rotation, where `a` was assigned with the value of `data[b]`,
+/// `b` with the value of `data[c]` and `c` with the value of `data[a]`, or `None` if fewer
+/// than three weights are positive. The slice is left unchanged in the latter case.
+///
+/// ## Panics
+///
+/// Panics if `weights` and `data` differ in length, if any weight is negative or not finite,
+/// or if the sum of the weights is not finite.
+///
+/// ## Example
+///
+/// ```
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha8Rng;
+///
+/// let mut rng = ChaCha8Rng::seed_from_u64(42);
+/// let mut vec = vec![10, 20, 30, 40, 50];
+/// let weights = [1.0, 0.0, 2.0, 0.5, 4.0];
+///
+/// let (a, b, c) = swap3::swap3_bca_weighted(&mut vec, &weights, &mut rng).unwrap();
+/// assert!(![a, b, c].contains(&1));
+/// assert_eq!(vec[1], 20);
+/// assert_eq!([vec[a], vec[b], vec[c]], [(b + 1) * 10, (c + 1) * 10, (a + 1) * 10]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn swap3_bca_weighted<T>(
+    data: &mut [T],
+    weights: &[f64],
+    rng: &mut impl Rng,
+) -> Option<(usize, usize, usize)> {
+    let (a, b, c) = weighted_triple(data.len(), weights, rng)?;
+    swap3_bca_slice(data, a, b, c);
+    Some((a, b, c))
+}
+
+/// Rotates three values to the right, chosen at random with probabilities proportional to
+/// their weights.
+///
+/// The indices are sampled without replacement: `a` is drawn with a probability of
+/// `weights[a]` divided by the sum of all weights, then `b` in the same way among the
+/// remaining indices, and finally `c`. Indices with a weight of zero are never chosen. This is
+/// intended for metaheuristics such as simulated annealing, which focus their moves on the
+/// positions contributing most to the cost.
+///
+/// Each draw takes `O(n)` time for a slice of `n` elements.
+///
+/// ## Arguments
+///
+/// * `data` - The slice whose elements to swap.
+/// * `weights` - The non-negative weights of the positions of `data`.
+/// * `rng` - The random number generator to sample the indices with.
+///
+/// ## Returns
+///
+/// The indices `(a, b, c)` of the rotation, where `a` was assigned with the value of `data[c]`,
+/// `b` with the value of `data[a]` and `c` with the value of `data[b]`, or `None` if fewer
+/// than three weights are positive. The slice is left unchanged in the latter case.
+///
+/// ## Panics
+///
+/// Panics if `weights` and `data` differ in length, if any weight is negative or not finite,
+/// or if the sum of the weights is not finite.
+///
+/// ## Example
+///
+/// ```
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha8Rng;
+///
+/// let mut rng = ChaCha8Rng::seed_from_u64(7);
+/// let mut vec = vec![10, 20, 30, 40];
+/// assert_eq!(swap3::swap3_cab_weighted(&mut vec, &[1.0, 0.0, 0.0, 1.0], &mut rng), None);
+/// assert_eq!(vec, &[10, 20, 30, 40]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn swap3_cab_weighted<T>(
+    data: &mut [T],
+    weights: &[f64],
+    rng: &mut impl Rng,
+) -> Option<(usize, usize, usize)> {
+    let (a, b, c) = weighted_triple(data.len(), weights, rng)?;
+    swap3_cab_slice(data, a, b, c);
+    Some((a, b, c))
+}
+
+/// Samples three distinct indices by weight without replacement, or returns `None` if fewer
+/// than three weights are positive.
+fn weighted_triple(
+    len: usize,
+    weights: &[f64],
+    rng: &mut impl Rng,
+) -> Option<(usize, usize, usize)> {
+    assert_eq!(
+        weights.len(),
+        len,
+        "the weights must have the same len as the slice"
+    );
+    for (index, &weight) in weights.iter().enumerate() {
+        assert!(
+            weight >= 0.0 && weight.is_finite(),
+            "the weight at index {index} is {weight}, but must be non-negative and finite"
+        );
+    }
+    assert!(
+        weights.iter().sum::<f64>().is_finite(),
+        "the sum of the weights must be finite"
+    );
+    if weights.iter().filter(|&&weight| weight > 0.0).count() < 3 {
+        return None;
+    }
+
+    let a = weighted_index(weights, rng, &[]);
+    let b = weighted_index(weights, rng, &[a]);
+    let c = weighted_index(weights, rng, &[a, b]);
+    Some((a, b, c))
+}
+
+/// Samples an index by weight among those with a positive weight not contained in `chosen`,
+/// at least one of which must exist.
+fn weighted_index(weights: &[f64], rng: &mut impl Rng, chosen: &[usize]) -> usize {
+    let candidates = || {
+        weights
+            .iter()
+            .enumerate()
+            .filter(|&(index, &weight)| weight > 0.0 && !chosen.contains(&index))
+    };
+
+    // Summing the remaining weights instead of subtracting the chosen ones from the total
+    // avoids accumulating rounding errors.
+    let total: f64 = candidates().map(|(_, weight)| weight).sum();
+    let mut target = rng.gen::<f64>() * total;
+    let mut last = None;
+    for (index, &weight) in candidates() {
+        if target < weight {
+            return index;
+        }
+        target -= weight;
+        last = Some(index);
+    }
+    // Rounding may leave a tiny remainder past the last candidate.
+    last.expect("a candidate with a positive weight exists")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_rotations_follow_indices() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let original: Vec<usize> = (0..10).collect();
+        let weights = [1.0; 10];
+        for _ in 0..100 {
+            let mut data = original.clone();
+            let (a, b, c) = swap3_bca_weighted(&mut data, &weights, &mut rng).unwrap();
+            let mut expected = original.clone();
+            swap3_bca_slice(&mut expected, a, b, c);
+            assert_eq!(data, expected);
+
+            let (a, b, c) = swap3_cab_weighted(&mut data, &weights, &mut rng).unwrap();
+            swap3_cab_slice(&mut expected, a, b, c);
+            assert_eq!(data, expected);
+        }
+    }
+
+    #[test]
+    fn test_proportional_to_weights() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        let mut data = [0; 6];
+        let weights = [8.0, 1.0, 1.0, 0.0, 1.0, 1.0];
+        let mut firsts = [0; 6];
+        let mut chosen = [0; 6];
+        for _ in 0..6000 {
+            let (a, b, c) = swap3_bca_weighted(&mut data, &weights, &mut rng).unwrap();
+            firsts[a] += 1;
+            for index in [a, b, c] {
+                chosen[index] += 1;
+            }
+        }
+        // The first index is drawn with a probability of 8/12 for index 0.
+        assert!((3800..4200).contains(&firsts[0]), "{firsts:?}");
+        assert_eq!(chosen[3], 0);
+        assert!(chosen[0] > 5800, "{chosen:?}");
+    }
+
+    #[test]
+    fn test_too_few_positive_weights() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let mut data = [1, 2, 3];
+        assert_eq!(
+            swap3_bca_weighted(&mut data, &[1.0, 0.0, 1.0], &mut rng),
+            None
+        );
+        assert_eq!(swap3_cab_weighted(&mut [0; 0], &[], &mut rng), None);
+        assert_eq!(data, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the weight at index 1 is NaN")]
+    fn test_invalid_weight() {
+        let mut rng = ChaCha8Rng::seed_from_u64(4);
+        swap3_bca_weighted(&mut [1, 2, 3], &[1.0, f64::NAN, 1.0], &mut rng);
+    }
+}
@@ -0,0 +1,144 @@
+/// Rotates three values to the left using a caller-provided pairwise swap.
+///
+/// The rotation is performed by exactly two calls to `swap`, namely `swap(a, b)`
+/// followed by `swap(b, c)`. This allows types with additional bookkeeping, such as
+/// back-references in intrusive data structures, to fix up their state on each swap.
+///
+/// ## Arguments
+///
+/// * `a` - The first value, to be assigned with the value of `b`.
+/// * `b` - The second value, to be assigned with the value of `c`.
+/// * `c` - The third value, to be assigned with the value of `a`.
+/// * `swap` - The function exchanging two values.
+///
+/// ## Example
+///
+/// ```
+/// let mut a = 10;
+/// let mut b = 20;
+/// let mut c = 30;
+/// let mut swaps = 0;
+/// swap3::swap3_bca_with_swap(&mut a, &mut b, &mut c, |x, y| {
+///     std::mem::swap(x, y);
+///     swaps += 1;
+/// });
+/// assert_eq!([a, b, c], [20, 30, 10]);
+/// assert_eq!(swaps, 2);
+/// ```
+#[inline(always)]
+pub fn swap3_bca_with_swap<T, F>(a: &mut T, b: &mut T, c: &mut T, mut swap: F)
+where
+    F: FnMut(&mut T, &mut T),
+{
+    swap(a, b);
+    swap(b, c);
+}
+
+/// Rotates three values to the right using a caller-provided pairwise swap.
+///
+/// The rotation is performed by exactly two calls to `swap`, namely `swap(a, c)`
+/// followed by `swap(b, c)`. This allows types with additional bookkeeping, such as
+/// back-references in intrusive data structures, to fix up their state on each swap.
+///
+/// ## Arguments
+///
+/// * `a` - The first value, to be assigned with the value of `c`.
+/// * `b` - The second value, to be assigned with the value of `a`.
+/// * `c` - The third value, to be assigned with the value of `b`.
+/// * `swap` - The function exchanging two values.
+///
+/// ## Example
+///
+/// ```
+/// let mut a = 10;
+/// let mut b = 20;
+/// let mut c = 30;
+/// swap3::swap3_cab_with_swap(&mut a, &mut b, &mut c, std::mem::swap);
+/// assert_eq!([a, b, c], [30, 10, 20]);
+/// ```
+#[inline(always)]
+pub fn swap3_cab_with_swap<T, F>(a: &mut T, b: &mut T, c: &mut T, mut swap: F)
+where
+    F: FnMut(&mut T, &mut T),
+{
+    swap(a, c);
+    swap(b, c);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A value remembering the slot it is stored in.
+    #[derive(Debug, PartialEq)]
+    struct Node {
+        value: char,
+        slot: usize,
+    }
+
+    fn swap_nodes(x: &mut Node, y: &mut Node) {
+        core::mem::swap(&mut x.value, &mut y.value);
+    }
+
+    #[test]
+    fn test_swap3_bca_with_swap_order() {
+        let mut calls = Vec::new();
+        let (mut a, mut b, mut c) = ('a', 'b', 'c');
+        swap3_bca_with_swap(&mut a, &mut b, &mut c, |x, y| {
+            calls.push((*x, *y));
+            core::mem::swap(x, y);
+        });
+        assert_eq!([a, b, c], ['b', 'c', 'a']);
+        assert_eq!(calls, [('a', 'b'), ('a', 'c')]);
+    }
+
+    #[test]
+    fn test_swap3_cab_with_swap_order() {
+        let mut calls = Vec::new();
+        let (mut a, mut b, mut c) = ('a', 'b', 'c');
+        swap3_cab_with_swap(&mut a, &mut b, &mut c, |x, y| {
+            calls.push((*x, *y));
+            core::mem::swap(x, y);
+        });
+        assert_eq!([a, b, c], ['c', 'a', 'b']);
+        assert_eq!(calls, [('a', 'c'), ('b', 'a')]);
+    }
+
+    #[test]
+    fn test_fixup_keeps_slots() {
+        let mut a = Node {
+            value: 'a',
+            slot: 0,
+        };
+        let mut b = Node {
+            value: 'b',
+            slot: 1,
+        };
+        let mut c = Node {
+            value: 'c',
+            slot: 2,
+        };
+        swap3_bca_with_swap(&mut a, &mut b, &mut c, swap_nodes);
+        assert_eq!(
+            a,
+            Node {
+                value: 'b',
+                slot: 0
+            }
+        );
+        assert_eq!(
+            b,
+            Node {
+                value: 'c',
+                slot: 1
+            }
+        );
+        assert_eq!(
+            c,
+            Node {
+                value: 'a',
+                slot: 2
+            }
+        );
+    }
+}
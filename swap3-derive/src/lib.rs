@@ -0,0 +1,168 @@
+//! Provides the `Swap3Fields` derive macro of the [`swap3`](https://docs.rs/swap3) crate.
+//!
+//! This crate is not intended to be used directly; enable the `derive` feature of `swap3`
+//! and use the macro re-exported as `swap3::Swap3Fields` instead.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Field, Index, Member};
+
+/// Derives the inherent methods `rotate_left` and `rotate_right`, rotating three fields of
+/// a struct.
+///
+/// If the struct has exactly three fields, these are rotated in declaration order.
+/// Otherwise, exactly three fields must be marked with `#[swap3]`. Either way, the rotated
+/// fields must have the same type. Named and tuple structs are supported, including generic
+/// ones.
+///
+/// For the rotated fields `a`, `b` and `c`, in declaration order, `rotate_left` assigns `a`
+/// with the value of `b`, `b` with that of `c` and `c` with that of `a`, like
+/// `swap3::swap3_bca`. `rotate_right` rotates the other way, like `swap3::swap3_cab`.
+///
+/// ## Example
+///
+/// ```
+/// use swap3::Swap3Fields;
+///
+/// #[derive(Debug, PartialEq, Swap3Fields)]
+/// struct Shift {
+///     early: &'static str,
+///     late: &'static str,
+///     night: &'static str,
+/// }
+///
+/// let mut shift = Shift { early: "ann", late: "bob", night: "cid" };
+/// shift.rotate_left();
+/// assert_eq!(shift, Shift { early: "bob", late: "cid", night: "ann" });
+///
+/// #[derive(Swap3Fields)]
+/// struct Tagged<T>(#[swap3] T, u32, #[swap3] T, #[swap3] T);
+///
+/// let mut tagged = Tagged(1.0, 7, 2.0, 3.0);
+/// tagged.rotate_right();
+/// assert_eq!([tagged.0, tagged.2, tagged.3], [3.0, 1.0, 2.0]);
+/// ```
+///
+/// Fields of different types cannot be rotated:
+///
+/// ```compile_fail
+/// #[derive(swap3::Swap3Fields)]
+/// struct Mixed {
+///     a: u32,
+///     b: u32,
+///     c: u64,
+/// }
+/// ```
+///
+/// Neither can structs without exactly three eligible fields:
+///
+/// ```compile_fail
+/// #[derive(swap3::Swap3Fields)]
+/// struct Pair(u32, u32);
+/// ```
+#[proc_macro_derive(Swap3Fields, attributes(swap3))]
+pub fn derive_swap3_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`Swap3Fields` can only be derived for structs",
+        ));
+    };
+
+    let mut fields = Vec::new();
+    let mut marked = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        if is_marked(field)? {
+            marked.push((member.clone(), field));
+        }
+        fields.push((member, field));
+    }
+
+    let selected = match marked.len() {
+        0 if fields.len() == 3 => fields,
+        0 => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                format!(
+                    "`Swap3Fields` requires a struct with exactly three fields, or three fields \
+                     marked with `#[swap3]`, but `{}` has {} fields",
+                    input.ident,
+                    fields.len()
+                ),
+            ))
+        }
+        3 => marked,
+        count => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                format!("exactly three fields must be marked with `#[swap3]`, but {count} are"),
+            ))
+        }
+    };
+
+    let ty = selected[0].1.ty.to_token_stream().to_string();
+    for (_, field) in &selected[1..] {
+        if field.ty.to_token_stream().to_string() != ty {
+            return Err(Error::new_spanned(
+                &field.ty,
+                format!("the rotated fields must have the same type `{ty}`"),
+            ));
+        }
+    }
+
+    let [a, b, c] = [0, 1, 2].map(|i| &selected[i].0);
+    let [na, nb, nc] = [a, b, c].map(|member| match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    });
+    let doc_left = format!(
+        "Rotates the fields `{na}`, `{nb}` and `{nc}` to the left, assigning `{na}` with the \
+         value of `{nb}`, `{nb}` with the value of `{nc}` and `{nc}` with the value of `{na}`."
+    );
+    let doc_right = format!(
+        "Rotates the fields `{na}`, `{nb}` and `{nc}` to the right, assigning `{na}` with the \
+         value of `{nc}`, `{nb}` with the value of `{na}` and `{nc}` with the value of `{nb}`."
+    );
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #doc_left]
+            #[inline]
+            pub fn rotate_left(&mut self) {
+                ::swap3::swap3_bca(&mut self.#a, &mut self.#b, &mut self.#c);
+            }
+
+            #[doc = #doc_right]
+            #[inline]
+            pub fn rotate_right(&mut self) {
+                ::swap3::swap3_cab(&mut self.#a, &mut self.#b, &mut self.#c);
+            }
+        }
+    })
+}
+
+/// Checks whether the field is marked with `#[swap3]`, rejecting arguments to the attribute.
+fn is_marked(field: &Field) -> syn::Result<bool> {
+    let mut marked = false;
+    for attr in &field.attrs {
+        if attr.path().is_ident("swap3") {
+            attr.meta.require_path_only()?;
+            marked = true;
+        }
+    }
+    Ok(marked)
+}